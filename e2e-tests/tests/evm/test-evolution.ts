@@ -1,7 +1,10 @@
 import {
 	SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+	SELECTOR_LOG_METADATA_UPDATE,
 	SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI,
 	SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+	SELECTOR_LOG_OWNERSHIP_TRANSFER_STARTED,
+	SELECTOR_LOG_TRANSFER,
 } from "@utils/constants";
 import { describeWithExistingNode } from "@utils/setups";
 import { createCollection, slotAndOwnerToTokenId } from "@utils/helpers";
@@ -72,7 +75,7 @@ describeWithExistingNode("Frontier RPC (Mint and Evolve Assets)", function () {
 		await waitFinalizedEthereumTx(this.web3, this.chains.laos, result.transactionHash);
 		expect(result.status).to.be.eq(true);
 
-		expect(Object.keys(result.events).length).to.be.eq(1);
+		expect(Object.keys(result.events).length).to.be.eq(2);
 
 		// data returned within the event
 		expect(result.events.MintedWithExternalURI.returnValues._to).to.be.eq(to);
@@ -93,6 +96,14 @@ describeWithExistingNode("Frontier RPC (Mint and Evolve Assets)", function () {
 		expect(result.events.MintedWithExternalURI.raw.data).to.be.eq(
 			this.web3.eth.abi.encodeParameters(["uint96", "uint256", "string"], [slot, tokenIdDecimal, tokenURI])
 		);
+
+		// ERC-721 transfer from the zero address
+		expect(result.events.Transfer.raw.topics).to.be.deep.eq([
+			SELECTOR_LOG_TRANSFER,
+			this.web3.utils.padLeft("0x0", 64),
+			this.web3.utils.padLeft(to.toLowerCase(), 64),
+			"0x" + tokenId,
+		]);
 	});
 
 	step("when asset is evolved it should change token uri", async function () {
@@ -152,7 +163,7 @@ describeWithExistingNode("Frontier RPC (Mint and Evolve Assets)", function () {
 		await waitFinalizedEthereumTx(this.web3, this.chains.laos, evolvingResult.transactionHash);
 		expect(evolvingResult.status).to.be.eq(true);
 
-		expect(Object.keys(evolvingResult.events).length).to.be.eq(1);
+		expect(Object.keys(evolvingResult.events).length).to.be.eq(2);
 
 		// data returned within the event
 		expect(evolvingResult.events.EvolvedWithExternalURI.returnValues._tokenId).to.be.eq(tokenIdDecimal);
@@ -169,6 +180,12 @@ describeWithExistingNode("Frontier RPC (Mint and Evolve Assets)", function () {
 		expect(evolvingResult.events.EvolvedWithExternalURI.raw.data).to.be.eq(
 			this.web3.eth.abi.encodeParameters(["string"], [newTokenURI])
 		);
+
+		// EIP-4906 metadata update
+		expect(evolvingResult.events.MetadataUpdate.raw.topics).to.be.deep.eq([SELECTOR_LOG_METADATA_UPDATE]);
+		expect(evolvingResult.events.MetadataUpdate.raw.data).to.be.eq(
+			this.web3.eth.abi.encodeParameters(["uint256"], [tokenIdDecimal])
+		);
 	});
 });
 
@@ -179,8 +196,8 @@ describeWithExistingNode("Frontier RPC (Transfer Ownership)", function () {
 		collectionContract = await createCollection(this.web3, this.chains.laos, this.ethereumPairs.faith.address);
 	});
 
-	step("when is transferred owner should change and emit an event", async function () {
-		const newOwner = "0xf24FF3a9CF04c71Dbc94D0b566f7A27B94566cac";
+	step("when is transferred pending owner should change and emit an event", async function () {
+		const newOwner = this.ethereumPairs.baltathar.address;
 
 		expect(await collectionContract.methods.owner().call()).to.be.eq(this.ethereumPairs.faith.address);
 		const estimatedGas = await collectionContract.methods.transferOwnership(newOwner).estimateGas();
@@ -190,29 +207,62 @@ describeWithExistingNode("Frontier RPC (Transfer Ownership)", function () {
 
 		await waitFinalizedEthereumTx(this.web3, this.chains.laos, tranferringResult.transactionHash);
 		expect(tranferringResult.status).to.be.eq(true);
-		expect(await collectionContract.methods.owner().call()).to.be.eq(newOwner);
+		expect(await collectionContract.methods.owner().call()).to.be.eq(this.ethereumPairs.faith.address);
+		expect(await collectionContract.methods.pendingOwner().call()).to.be.eq(newOwner);
 
 		expect(Object.keys(tranferringResult.events).length).to.be.eq(1);
 
 		// data returned within the event
-		expect(tranferringResult.events.OwnershipTransferred.returnValues._previousOwner).to.be.eq(
+		expect(tranferringResult.events.OwnershipTransferStarted.returnValues._previousOwner).to.be.eq(
+			this.ethereumPairs.faith.address
+		);
+		expect(tranferringResult.events.OwnershipTransferStarted.returnValues._newOwner).to.be.eq(newOwner);
+
+		// event topics
+		expect(tranferringResult.events.OwnershipTransferStarted.raw.topics.length).to.be.eq(3);
+		expect(tranferringResult.events.OwnershipTransferStarted.raw.topics[0]).to.be.eq(
+			SELECTOR_LOG_OWNERSHIP_TRANSFER_STARTED
+		);
+		// event data
+		expect(tranferringResult.events.OwnershipTransferStarted.raw.data).to.be.eq("0x");
+	});
+
+	step("when pending owner accepts owner should change and emit an event", async function () {
+		const newOwner = this.ethereumPairs.baltathar.address;
+
+		const estimatedGas = await collectionContract.methods.acceptOwnership().estimateGas({ from: newOwner });
+		const acceptingResult = await collectionContract.methods
+			.acceptOwnership()
+			.send({ from: newOwner, gas: estimatedGas });
+
+		await waitFinalizedEthereumTx(this.web3, this.chains.laos, acceptingResult.transactionHash);
+		expect(acceptingResult.status).to.be.eq(true);
+		expect(await collectionContract.methods.owner().call()).to.be.eq(newOwner);
+		expect(await collectionContract.methods.pendingOwner().call()).to.be.eq(
+			"0x0000000000000000000000000000000000000000"
+		);
+
+		expect(Object.keys(acceptingResult.events).length).to.be.eq(1);
+
+		// data returned within the event
+		expect(acceptingResult.events.OwnershipTransferred.returnValues._previousOwner).to.be.eq(
 			this.ethereumPairs.faith.address
 		);
-		expect(tranferringResult.events.OwnershipTransferred.returnValues._newOwner).to.be.eq(newOwner);
+		expect(acceptingResult.events.OwnershipTransferred.returnValues._newOwner).to.be.eq(newOwner);
 
 		// event topics
-		expect(tranferringResult.events.OwnershipTransferred.raw.topics.length).to.be.eq(3);
-		expect(tranferringResult.events.OwnershipTransferred.raw.topics[0]).to.be.eq(
+		expect(acceptingResult.events.OwnershipTransferred.raw.topics.length).to.be.eq(3);
+		expect(acceptingResult.events.OwnershipTransferred.raw.topics[0]).to.be.eq(
 			SELECTOR_LOG_OWNERSHIP_TRANSFERRED
 		);
-		expect(tranferringResult.events.OwnershipTransferred.raw.topics[1]).to.be.eq(
+		expect(acceptingResult.events.OwnershipTransferred.raw.topics[1]).to.be.eq(
 			this.web3.utils.padLeft(this.ethereumPairs.faith.address.toLowerCase(), 64)
 		);
-		expect(tranferringResult.events.OwnershipTransferred.raw.topics[2]).to.be.eq(
+		expect(acceptingResult.events.OwnershipTransferred.raw.topics[2]).to.be.eq(
 			this.web3.utils.padLeft(newOwner.toLowerCase(), 64)
 		);
 		// event data
-		expect(tranferringResult.events.OwnershipTransferred.raw.data).to.be.eq("0x");
+		expect(acceptingResult.events.OwnershipTransferred.raw.data).to.be.eq("0x");
 
 		try {
 			const estimatedGas = await collectionContract.methods
@@ -3,6 +3,7 @@ import path from "path";
 import { AbiItem } from "web3-utils";
 import AssetMetadataExtender from "../build/contracts/AssetMetadataExtender.sol/AssetMetadataExtender.json";
 import EvolutionCollection from "../build/contracts/EvolutionCollection.sol/EvolutionCollection.json";
+import EvolutionCollectionOwnership from "../build/contracts/EvolutionCollection.sol/EvolutionCollectionOwnership.json";
 import EvolutionCollectionFactory from "../build/contracts/EvolutionCollectionFactory.sol/EvolutionCollectionFactory.json";
 import Vesting from "../build/contracts/Vesting.sol/Vesting.json";
 import ParachainStaking from "../build/contracts/ParachainStaking.sol/ParachainStaking.json";
@@ -49,14 +50,19 @@ export const FAITH_PRIVATE_KEY = "0xb9d2ea9a615f3165812e8d44de0d24da9bbd164b65c4
 
 // LAOS Evolution Contract
 export const EVOLUTION_COLLECTION_FACTORY_ABI = EvolutionCollectionFactory.abi as AbiItem[];
-export const EVOLUTION_COLLECTION_ABI = EvolutionCollection.abi as AbiItem[];
+// the core interface of collections and the extensions used by the tests
+export const EVOLUTION_COLLECTION_ABI = [...EvolutionCollection.abi, ...EvolutionCollectionOwnership.abi] as AbiItem[];
 export const EVOLUTION_COLLECTION_FACTORY_CONTRACT_ADDRESS = "0x0000000000000000000000000000000000000403";
 export const SELECTOR_LOG_NEW_COLLECTION = "0x5b84d9550adb7000df7bee717735ecd3af48ea3f66c6886d52e8227548fb228c";
 export const SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI =
 	"0xa7135052b348b0b4e9943bae82d8ef1c5ac225e594ef4271d12f0744cfc98348";
 export const SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI =
 	"0xdde18ad2fe10c12a694de65b920c02b851c382cf63115967ea6f7098902fa1c8";
+export const SELECTOR_LOG_TRANSFER = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+export const SELECTOR_LOG_METADATA_UPDATE = "0xf8e1a15aba9398e019f0b49df1a4fde98ee17ae345cb5f6b5e2c27f5033e8ce7";
 export const SELECTOR_LOG_OWNERSHIP_TRANSFERRED = "0x8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0";
+export const SELECTOR_LOG_OWNERSHIP_TRANSFER_STARTED =
+	"0x38d16b8cac22d99fc7c124b9cd0de2d3fa1faef420bfe791d8c362d765e22700";
 export const SELECTOR_LOG_PUBLIC_MINTING_ENABLED = "0x8ff3deee4c40ab085dd8d7d0c848cb5295e4ab5faa32e5b60e3936cf1bdc77bf";
 export const SELECTOR_LOG_PUBLIC_MINTING_DISABLED =
 	"0xebe230014056e5cb4ca6d8e534189bf5bfb0759489f16170654dce7c014b6699";
@@ -0,0 +1,183 @@
+// Copyright 2023-2024 Freeverse.io
+// This file is part of LAOS.
+
+// LAOS is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// LAOS is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with LAOS.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `laos_*` RPC methods to query LAOS Evolution collections and tokens.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_laos_evolution::{
+	runtime_api::LaosEvolutionApi as LaosEvolutionRuntimeApi,
+	types::{CollectionId, TokenId},
+};
+use parity_scale_codec::Codec;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Maximum number of tokens returned by a single `laos_tokensOfCollection` or
+/// `laos_tokensByIndex` call.
+pub const MAX_TOKENS_PER_PAGE: u32 = 1000;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// LAOS Evolution RPC methods.
+#[rpc(server)]
+pub trait LaosEvolutionApi<BlockHash, AccountId> {
+	/// Returns the owner of the collection, if it exists.
+	#[method(name = "laos_collectionOwner")]
+	fn collection_owner(
+		&self,
+		collection_id: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AccountId>>;
+
+	/// Returns the token URI of `token_id` in the collection, if it exists.
+	#[method(name = "laos_tokenURI")]
+	fn token_uri(
+		&self,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<String>>;
+
+	/// Returns the id the next created collection will get.
+	#[method(name = "laos_collectionCounter")]
+	fn collection_counter(&self, at: Option<BlockHash>) -> RpcResult<CollectionId>;
+
+	/// Returns up to `limit` tokens of the collection along with their token URI, starting right
+	/// after `start_after` if given. `limit` is capped to [`MAX_TOKENS_PER_PAGE`].
+	#[method(name = "laos_tokensOfCollection")]
+	fn tokens_of_collection(
+		&self,
+		collection_id: CollectionId,
+		start_after: Option<TokenId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(TokenId, String)>>;
+
+	/// Returns up to `limit` tokens of the collection, starting at index `offset`. `limit` is
+	/// capped to [`MAX_TOKENS_PER_PAGE`].
+	#[method(name = "laos_tokensByIndex")]
+	fn tokens_by_index(
+		&self,
+		collection_id: CollectionId,
+		offset: u64,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TokenId>>;
+}
+
+/// Implementation of [`LaosEvolutionApiServer`] backed by the runtime API.
+pub struct LaosEvolution<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> LaosEvolution<C, Block> {
+	/// Creates a new instance of the LAOS Evolution RPC.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, "Unable to query LAOS Evolution", Some(format!("{:?}", err)))
+}
+
+fn token_uri_to_string(token_uri: Vec<u8>) -> String {
+	String::from_utf8_lossy(&token_uri).into_owned()
+}
+
+impl<C, Block, AccountId> LaosEvolutionApiServer<<Block as BlockT>::Hash, AccountId>
+	for LaosEvolution<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: LaosEvolutionRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Serialize + Send + Sync + 'static,
+{
+	fn collection_owner(
+		&self,
+		collection_id: CollectionId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AccountId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.collection_owner(at, collection_id)
+			.map_err(runtime_error)
+	}
+
+	fn token_uri(
+		&self,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<String>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let token_uri = self
+			.client
+			.runtime_api()
+			.token_uri(at, collection_id, token_id)
+			.map_err(runtime_error)?;
+		Ok(token_uri.map(token_uri_to_string))
+	}
+
+	fn collection_counter(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<CollectionId> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().collection_counter(at).map_err(runtime_error)
+	}
+
+	fn tokens_of_collection(
+		&self,
+		collection_id: CollectionId,
+		start_after: Option<TokenId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(TokenId, String)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let tokens = self
+			.client
+			.runtime_api()
+			.tokens_of_collection(at, collection_id, start_after, limit.min(MAX_TOKENS_PER_PAGE))
+			.map_err(runtime_error)?;
+		Ok(tokens
+			.into_iter()
+			.map(|(token_id, token_uri)| (token_id, token_uri_to_string(token_uri)))
+			.collect())
+	}
+
+	fn tokens_by_index(
+		&self,
+		collection_id: CollectionId,
+		offset: u64,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<TokenId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.tokens_by_index(at, collection_id, offset, limit.min(MAX_TOKENS_PER_PAGE))
+			.map_err(runtime_error)
+	}
+}
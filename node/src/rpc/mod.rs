@@ -40,6 +40,7 @@ use sp_runtime::traits::Block as BlockT;
 use substrate_frame_rpc_system::SystemApiServer;
 
 mod eth;
+mod laos_evolution;
 pub use self::eth::{create_eth, EthDeps};
 
 /// A type representing all RPC extensions.
@@ -93,12 +94,14 @@ where
 	C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
 	C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
 	C::Api: AuraApi<Block, AuraId>,
+	C::Api: pallet_laos_evolution::runtime_api::LaosEvolutionApi<Block, AccountId>,
 	BE: Backend<Block> + 'static,
 	P: TransactionPool<Block = Block> + 'static,
 	A: ChainApi<Block = Block> + 'static,
 	CIDP: sp_inherents::CreateInherentDataProviders<Block, ()> + Send + 'static,
 	CT: fp_rpc::ConvertTransaction<<Block as BlockT>::Extrinsic> + Send + Sync + 'static,
 {
+	use laos_evolution::{LaosEvolution, LaosEvolutionApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::System;
 
@@ -106,7 +109,8 @@ where
 	let FullDeps { client, pool, eth } = deps;
 
 	io.merge(System::new(client.clone(), pool).into_rpc())?;
-	io.merge(TransactionPayment::new(client).into_rpc())?;
+	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(LaosEvolution::new(client).into_rpc())?;
 
 	// Ethereum compatibility RPCs
 	let io = create_eth::<Block, C, P, CT, BE, A, CIDP, DefaultEthConfig<C, BE>>(
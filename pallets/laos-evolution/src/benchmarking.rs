@@ -19,18 +19,115 @@
 use super::*;
 
 use crate::precompiles::{
-	evolution_collection::EvolutionCollectionPrecompileSet,
+	evolution_collection::{
+		evolution_authorization_digest, mint_authorization_digest,
+		EvolutionCollectionPrecompileSet, MINTER_ROLE,
+	},
 	evolution_collection_factory::EvolutionCollectionFactoryPrecompile,
 };
 #[allow(unused)]
 use crate::Pallet as LaosEvolution;
 use fp_evm::Transfer;
 use frame_benchmarking::v2::*;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_evm::{Context, ExitError, ExitReason, Log, PrecompileHandle};
-use precompile_utils::{prelude::Address, solidity::codec::UnboundedString};
-use sp_core::{H160, H256, U256};
+use precompile_utils::{
+	prelude::{Address, DiscriminantResult},
+	solidity::codec::{UnboundedBytes, UnboundedString},
+};
+use sp_core::{crypto::KeyTypeId, ecdsa, H160, H256, U256};
+use sp_runtime::traits::Bounded;
 use sp_std::{vec, vec::Vec};
 
+/// Collection metadata with every field at its maximum length
+fn max_collection_metadata<T: Config>() -> CollectionMetadata<T> {
+	CollectionMetadata {
+		name: vec![b'a'; T::MaxCollectionNameLength::get() as usize].try_into().unwrap(),
+		symbol: vec![b'a'; T::MaxCollectionSymbolLength::get() as usize].try_into().unwrap(),
+		contract_uri: vec![b'a'; T::MaxTokenUriLength::get() as usize].try_into().unwrap(),
+	}
+}
+
+/// Gives `who` enough balance to pay the storage deposits of the benchmarks
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+/// Creates a collection owned by `owner`, funding it first to pay the storage deposits
+fn create_funded_collection<T: Config>(owner: T::AccountId) -> Result<CollectionId, DispatchError> {
+	fund::<T>(&owner);
+	<LaosEvolution<T> as EvolutionCollectionFactory<_>>::create_collection(owner.clone(), owner)
+}
+
+/// Fills the history of a token with `versions` of the longest token URIs
+fn set_history<T: Config>(collection_id: CollectionId, token_id: TokenId, versions: u32) {
+	for version in 0..versions {
+		let token_uri: TokenUriOf<T> =
+			vec![1u8; T::MaxTokenUriLength::get() as usize].try_into().unwrap();
+		TokenURIHistory::<T>::insert((collection_id, token_id, version), token_uri);
+	}
+	TokenURIVersion::<T>::insert(collection_id, token_id, versions);
+}
+
+/// Schedules the evolution of a token to the longest token URI at block 10
+fn schedule_longest_evolution<T: Config>(
+	owner: T::AccountId,
+	collection_id: CollectionId,
+	token_id: TokenId,
+) {
+	<LaosEvolution<T> as EvolutionCollectionScheduling<_, _>>::schedule_evolution(
+		owner,
+		collection_id,
+		token_id,
+		vec![1u8; T::MaxTokenUriLength::get() as usize].try_into().unwrap(),
+		10,
+	)
+	.unwrap();
+}
+
+/// Sets `count` attributes of a token, all of them with the longest value
+fn set_attributes<T: Config>(
+	owner: T::AccountId,
+	collection_id: CollectionId,
+	token_id: TokenId,
+	count: u32,
+) {
+	for i in 0..count {
+		<LaosEvolution<T> as EvolutionCollectionAttributes<_, _>>::set_attribute(
+			owner.clone(),
+			collection_id,
+			token_id,
+			H256::from_low_u64_be(i.into()),
+			vec![1u8; T::MaxAttributeValueLength::get() as usize].try_into().unwrap(),
+		)
+		.unwrap();
+	}
+}
+
+/// Sets the base URI of the collection, expanding to the longest token URI
+fn set_longest_base_uri<T: Config>(owner: T::AccountId, collection_id: CollectionId) {
+	let mut base_uri = vec![1u8; <T as Config>::MaxTokenUriLength::get() as usize - 64];
+	base_uri.extend_from_slice(b"{id}");
+	<LaosEvolution<T> as EvolutionCollectionBaseUri<_, _>>::set_base_uri(
+		owner,
+		collection_id,
+		base_uri.try_into().unwrap(),
+	)
+	.unwrap();
+}
+
+/// Key type of the keys generated to sign mint authorizations
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"bnch");
+
+/// Generates an ECDSA key in the keystore, returning it along with its Ethereum address
+fn ecdsa_key() -> (ecdsa::Public, H160) {
+	let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+	let message = [0u8; 32];
+	let signature = sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &message).unwrap();
+	let public_key = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message).unwrap();
+	(public, H160::from_slice(&sp_io::hashing::keccak_256(&public_key)[12..]))
+}
+
 pub struct MockHandle {
 	pub input: Vec<u8>,
 	pub gas_limit: Option<u64>,
@@ -123,17 +220,20 @@ mod benchmarks {
 
 	#[benchmark]
 	fn precompile_discriminant() {
-		let address = H160::zero();
+		// worst case: the owner of the collection is not found and the collection counter is read
+		let address: H160 = collection_id_to_address(0);
 		let gas = 100000000;
 		#[block]
 		{
-			let _ = EvolutionCollectionPrecompileSet::<T>::discriminant(address, gas);
+			let res = EvolutionCollectionPrecompileSet::<T>::discriminant(address, gas);
+			assert!(matches!(res, DiscriminantResult::None(_)));
 		}
 	}
 
 	#[benchmark]
 	fn precompile_create_collection() {
 		let owner = Address::from(H160::zero());
+		fund::<T>(&T::AccountIdToH160::convert_back(owner.into()));
 		let mut handle = MockHandle::new(owner.into());
 
 		#[block]
@@ -144,6 +244,26 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn precompile_create_collection_with_metadata() {
+		let owner = Address::from(H160::zero());
+		fund::<T>(&T::AccountIdToH160::convert_back(owner.into()));
+		let mut handle = MockHandle::new(owner.into());
+		let metadata = max_collection_metadata::<T>();
+
+		#[block]
+		{
+			let res = EvolutionCollectionFactoryPrecompile::<T>::create_collection_with_metadata(
+				&mut handle,
+				owner,
+				metadata.name.to_vec().into(),
+				metadata.symbol.to_vec().into(),
+				metadata.contract_uri.to_vec().into(),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
 	#[benchmark]
 	fn precompile_mint(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
 		let caller: T::AccountId = whitelisted_caller();
@@ -151,7 +271,7 @@ mod benchmarks {
 		let to = Address::from(H160::from_low_u64_be(1));
 		let slot = Slot::try_from(2).unwrap();
 		let token_uri = vec![1u8; s.try_into().unwrap()].try_into().unwrap();
-		let collection_id = LaosEvolution::<T>::create_collection(owner).unwrap();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
 		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
 
 		#[block]
@@ -167,12 +287,56 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn precompile_mint_with_signature(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (public, minter) = ecdsa_key();
+		let to = H160::from_low_u64_be(1);
+		let slot = Slot::try_from(2).unwrap();
+		let token_uri = vec![1u8; s.try_into().unwrap()];
+		let collection_id = create_funded_collection::<T>(caller.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollectionRoles<_>>::grant_role(
+			caller.clone(),
+			collection_id,
+			Role::Minter,
+			T::AccountIdToH160::convert_back(minter),
+		)
+		.unwrap();
+		let collection_address = collection_id_to_address(collection_id);
+		let digest = mint_authorization_digest::<T>(
+			collection_address,
+			to,
+			slot,
+			&token_uri,
+			U256::zero(),
+			U256::MAX,
+		);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &digest).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		handle.context.address = collection_address;
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::mint_with_signature(
+				collection_id,
+				&mut handle,
+				Address(to),
+				slot,
+				token_uri.into(),
+				U256::zero(),
+				U256::MAX,
+				UnboundedBytes::from(&signature.0[..]),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
 	#[benchmark]
 	fn precompile_evolve(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
 		let caller: T::AccountId = whitelisted_caller();
 		let owner = caller.clone();
 		let token_uri: UnboundedString = vec![1u8; s.try_into().unwrap()].try_into().unwrap();
-		let collection_id = LaosEvolution::<T>::create_collection(owner).unwrap();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
 		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
 		let to = Address::from(H160::from_low_u64_be(1));
 		let slot = Slot::try_from(2).unwrap();
@@ -184,6 +348,15 @@ mod benchmarks {
 			token_uri.clone(),
 		)
 		.unwrap();
+		// worst case: the oldest version of the history is pruned
+		TokenURIVersion::<T>::insert(collection_id, token_id, T::MaxUriHistory::get());
+		// worst case: the evolution must have been approved by the initial owner
+		EvolutionConsentRequired::<T>::insert(collection_id, ());
+		EvolutionApproval::<T>::insert(
+			collection_id,
+			token_id,
+			H256(keccak_256(token_uri.as_bytes())),
+		);
 
 		#[block]
 		{
@@ -201,7 +374,7 @@ mod benchmarks {
 	fn precompile_transfer_ownership() {
 		let caller: T::AccountId = whitelisted_caller();
 		let owner = caller.clone();
-		let collection_id = LaosEvolution::<T>::create_collection(owner).unwrap();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
 		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
 		let to = Address::from(H160::from_low_u64_be(1));
 
@@ -220,7 +393,7 @@ mod benchmarks {
 	fn precompile_owner() {
 		let caller: T::AccountId = whitelisted_caller();
 		let owner = caller.clone();
-		let collection_id = LaosEvolution::<T>::create_collection(owner).unwrap();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
 		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
 
 		#[block]
@@ -230,12 +403,71 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn precompile_supports_interface() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_funded_collection::<T>(caller.clone()).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			// worst case: the id is compared with every supported interface
+			let res = EvolutionCollectionPrecompileSet::<T>::supports_interface(
+				collection_id,
+				&mut handle,
+				InterfaceId([0xff; 4]),
+			);
+			assert!(matches!(res, Ok(false)));
+		}
+	}
+
+	#[benchmark]
+	fn precompile_initial_owner_of() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_funded_collection::<T>(caller.clone()).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::initial_owner_of(
+				collection_id,
+				&mut handle,
+				U256::MAX,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_slot_of() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_funded_collection::<T>(caller.clone()).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::slot_of(
+				collection_id,
+				&mut handle,
+				U256::MAX,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
 	#[benchmark]
 	fn precompile_token_uri() {
 		let caller: T::AccountId = whitelisted_caller();
 		let owner = caller.clone();
-		let token_uri: UnboundedString = vec![1u8; 100].try_into().unwrap();
-		let collection_id = LaosEvolution::<T>::create_collection(owner).unwrap();
+		// the token URI is resolved through a base URI as long as it can be once expanded
+		let token_uri: UnboundedString = Vec::<u8>::new().into();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollectionBaseUri<_, _>>::set_base_uri(
+			owner,
+			collection_id,
+			b"{id}".repeat(8).try_into().unwrap(),
+		)
+		.unwrap();
 		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
 		let to = Address::from(H160::from_low_u64_be(1));
 		let slot = Slot::try_from(2).unwrap();
@@ -260,14 +492,88 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn create_collection() {
+	fn precompile_batch_mint(
+		n: Linear<1, 100>,
+		s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let to = vec![Address::from(H160::from_low_u64_be(1)); n as usize];
+		let slots = (0..n).map(|i| Slot::try_from(i as u128).unwrap()).collect::<Vec<_>>();
+		// every token URI is `s` bytes long, as the weight charges `s` for each of the `n` items
+		let token_uris: Vec<UnboundedString> =
+			vec![vec![1u8; s.try_into().unwrap()].into(); n as usize];
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::batch_mint(
+				collection_id,
+				&mut handle,
+				to,
+				slots,
+				token_uris,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_batch_evolve(
+		n: Linear<1, 100>,
+		s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>,
+	) {
 		let caller: T::AccountId = whitelisted_caller();
 		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let to = vec![Address::from(H160::from_low_u64_be(1)); n as usize];
+		let slots = (0..n).map(|i| Slot::try_from(i as u128).unwrap()).collect::<Vec<_>>();
+		// every token URI is `s` bytes long, as the weight charges `s` for each of the `n` items
+		let token_uris: Vec<UnboundedString> =
+			vec![vec![1u8; s.try_into().unwrap()].into(); n as usize];
+		let token_ids = EvolutionCollectionPrecompileSet::<T>::batch_mint(
+			collection_id,
+			&mut handle,
+			to,
+			slots,
+			token_uris.clone(),
+		)
+		.unwrap();
+		// worst case: the oldest version of the history of every token is pruned, and every
+		// evolution must have been approved by the initial owner
+		EvolutionConsentRequired::<T>::insert(collection_id, ());
+		for (token_id, token_uri) in token_ids.iter().zip(token_uris.iter()) {
+			TokenURIVersion::<T>::insert(collection_id, token_id, T::MaxUriHistory::get());
+			EvolutionApproval::<T>::insert(
+				collection_id,
+				token_id,
+				H256(keccak_256(token_uri.as_bytes())),
+			);
+		}
 
 		#[block]
 		{
-			LaosEvolution::<T>::create_collection(owner.clone()).unwrap();
+			let res = EvolutionCollectionPrecompileSet::<T>::batch_evolve(
+				collection_id,
+				&mut handle,
+				token_ids,
+				token_uris,
+			);
+			assert!(res.is_ok());
 		}
+	}
+
+	#[benchmark]
+	fn create_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		fund::<T>(&owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), owner);
+
 		assert_eq!(CollectionOwner::<T>::get(0), Some(caller));
 	}
 
@@ -277,70 +583,1511 @@ mod benchmarks {
 	fn mint_with_external_uri(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
 		let caller: T::AccountId = whitelisted_caller();
 		let owner = caller.clone();
-		let collection_id = LaosEvolution::<T>::create_collection(owner.clone()).unwrap();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		// worst case: the slot is past the next slot of the owner, which is advanced
+		let slot: Slot = (s as u128).try_into().unwrap();
+		let token_uri: TokenUriOf<T> = vec![1u8; s as usize].try_into().unwrap();
 
-		#[block]
-		{
-			let token_id = LaosEvolution::<T>::mint_with_external_uri(
-				owner.clone(),
-				collection_id,
-				(s as u128).try_into().unwrap(),
-				owner.clone(),
-				vec![1u8; s as usize].try_into().unwrap(),
-			)
-			.unwrap();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, slot, owner.clone(), token_uri.clone());
 
-			assert_eq!(
-				LaosEvolution::<T>::token_uri(collection_id, token_id),
-				Some(vec![1u8; s as usize].try_into().unwrap())
-			);
-		};
+		assert_eq!(
+			NextSlot::<T>::get(collection_id, &owner),
+			Slot::try_from(u128::from(slot) + 1).unwrap()
+		);
+		let token_id = slot_and_owner_to_token_id(slot, T::AccountIdToH160::convert(owner));
+		assert_eq!(LaosEvolution::<T>::token_uri(collection_id, token_id), Some(token_uri));
 	}
 
 	#[benchmark]
 	fn evolve_with_external_uri(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
 		let caller: T::AccountId = whitelisted_caller();
 		let owner = caller.clone();
-		let collection_id = LaosEvolution::<T>::create_collection(owner.clone()).unwrap();
-		let token_id = LaosEvolution::<T>::mint_with_external_uri(
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
 			owner.clone(),
-			0,
+			collection_id,
 			(s as u128).try_into().unwrap(),
 			owner.clone(),
 			vec![0u8; s as usize].try_into().unwrap(),
 		)
 		.unwrap();
+		// worst case: the oldest version of the history is pruned
+		TokenURIVersion::<T>::insert(collection_id, token_id, T::MaxUriHistory::get());
+		let token_uri: TokenUriOf<T> = vec![1u8; s as usize].try_into().unwrap();
+		// worst case: the evolution must have been approved by the initial owner
+		EvolutionConsentRequired::<T>::insert(collection_id, ());
+		EvolutionApproval::<T>::insert(collection_id, token_id, H256(keccak_256(&token_uri)));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, token_id, token_uri.clone());
+
+		assert_eq!(LaosEvolution::<T>::token_uri(collection_id, token_id), Some(token_uri));
+	}
+
+	#[benchmark]
+	fn transfer_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, new_owner.clone());
+
+		assert_eq!(PendingOwner::<T>::get(collection_id), Some(new_owner));
+	}
+
+	#[benchmark]
+	fn precompile_burn(
+		a: Linear<0, { <T as Config>::MaxAttributesPerToken::get() }>,
+		h: Linear<0, { <T as Config>::MaxUriHistory::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let token_uri: UnboundedString = vec![1u8; 100].try_into().unwrap();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let to = Address::from(H160::from_low_u64_be(1));
+		let slot = Slot::try_from(2).unwrap();
+		let token_id = EvolutionCollectionPrecompileSet::<T>::mint(
+			collection_id,
+			&mut handle,
+			to,
+			slot,
+			token_uri.clone(),
+		)
+		.unwrap();
+		// the last token is moved to the index of the burned one
+		EvolutionCollectionPrecompileSet::<T>::mint(
+			collection_id,
+			&mut handle,
+			to,
+			Slot::try_from(3).unwrap(),
+			token_uri,
+		)
+		.unwrap();
+		set_attributes::<T>(owner.clone(), collection_id, token_id, a);
+		set_history::<T>(collection_id, token_id, h);
+		// worst case: the scheduled evolution of the token is canceled
+		schedule_longest_evolution::<T>(owner, collection_id, token_id);
 
 		#[block]
 		{
-			LaosEvolution::<T>::evolve_with_external_uri(
-				owner.clone(),
+			let res =
+				EvolutionCollectionPrecompileSet::<T>::burn(collection_id, &mut handle, token_id);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn burn(
+		a: Linear<0, { <T as Config>::MaxAttributesPerToken::get() }>,
+		h: Linear<0, { <T as Config>::MaxUriHistory::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			vec![1u8; 100].try_into().unwrap(),
+		)
+		.unwrap();
+		// the last token is moved to the index of the burned one
+		<LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(1).unwrap(),
+			owner.clone(),
+			vec![1u8; 100].try_into().unwrap(),
+		)
+		.unwrap();
+		set_attributes::<T>(owner.clone(), collection_id, token_id, a);
+		set_history::<T>(collection_id, token_id, h);
+		// worst case: the scheduled evolution of the token is canceled
+		schedule_longest_evolution::<T>(owner.clone(), collection_id, token_id);
+
+		#[block]
+		{
+			LaosEvolution::<T>::burn(owner.clone(), collection_id, token_id).unwrap();
+		}
+
+		assert_eq!(LaosEvolution::<T>::token_uri(collection_id, token_id), None);
+	}
+
+	#[benchmark]
+	fn precompile_freeze_token() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let token_uri: UnboundedString = Vec::<u8>::new().into();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		set_longest_base_uri::<T>(owner, collection_id);
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let to = Address::from(H160::from_low_u64_be(1));
+		let slot = Slot::try_from(2).unwrap();
+		let token_id = EvolutionCollectionPrecompileSet::<T>::mint(
+			collection_id,
+			&mut handle,
+			to,
+			slot,
+			token_uri,
+		)
+		.unwrap();
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::freeze_token(
 				collection_id,
+				&mut handle,
 				token_id,
-				vec![1u8; s as usize].try_into().unwrap(),
-			)
-			.unwrap();
+			);
+			assert!(res.is_ok());
 		}
+	}
 
-		assert_eq!(
-			LaosEvolution::<T>::token_uri(collection_id, token_id),
-			Some(vec![1u8; s as usize].try_into().unwrap())
-		);
+	#[benchmark]
+	fn precompile_freeze_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::freeze_collection(
+				collection_id,
+				&mut handle,
+			);
+			assert!(res.is_ok());
+		}
 	}
 
 	#[benchmark]
-	fn transfer_ownership() {
+	fn precompile_is_frozen() {
 		let caller: T::AccountId = whitelisted_caller();
 		let owner = caller.clone();
-		let new_owner: T::AccountId = account("new_owner", 0, 0);
-		let collection_id = LaosEvolution::<T>::create_collection(owner.clone()).unwrap();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
 
 		#[block]
 		{
-			LaosEvolution::<T>::transfer_ownership(owner.clone(), new_owner.clone(), collection_id)
-				.unwrap();
+			let res = EvolutionCollectionPrecompileSet::<T>::is_frozen(
+				collection_id,
+				&mut handle,
+				TokenId::zero(),
+			);
+			assert_eq!(res.ok(), Some(false));
 		}
+	}
 
-		assert_eq!(CollectionOwner::<T>::get(collection_id), Some(new_owner));
+	#[benchmark]
+	fn freeze_token() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		set_longest_base_uri::<T>(owner.clone(), collection_id);
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, token_id);
+
+		assert!(<LaosEvolution<T> as EvolutionCollectionFreezing<_>>::is_frozen(
+			collection_id,
+			token_id
+		));
+	}
+
+	#[benchmark]
+	fn freeze_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert!(FrozenCollection::<T>::contains_key(collection_id));
+	}
+
+	#[benchmark]
+	fn precompile_grant_role() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let account = Address::from(H160::from_low_u64_be(1));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::grant_role(
+				collection_id,
+				&mut handle,
+				H256(MINTER_ROLE),
+				account,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_revoke_role() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let account = Address::from(H160::from_low_u64_be(1));
+		EvolutionCollectionPrecompileSet::<T>::grant_role(
+			collection_id,
+			&mut handle,
+			H256(MINTER_ROLE),
+			account,
+		)
+		.unwrap();
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::revoke_role(
+				collection_id,
+				&mut handle,
+				H256(MINTER_ROLE),
+				account,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_has_role() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let account = Address::from(H160::from_low_u64_be(1));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::has_role(
+				collection_id,
+				&mut handle,
+				H256(MINTER_ROLE),
+				account,
+			);
+			assert_eq!(res.ok(), Some(false));
+		}
+	}
+
+	#[benchmark]
+	fn grant_role() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let account: T::AccountId = account("account", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, Role::Minter, account.clone());
+
+		assert!(<LaosEvolution<T> as EvolutionCollectionRoles<_>>::has_role(
+			collection_id,
+			Role::Minter,
+			account
+		));
+	}
+
+	#[benchmark]
+	fn revoke_role() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let account: T::AccountId = account("account", 0, 0);
+		<LaosEvolution<T> as EvolutionCollectionRoles<_>>::grant_role(
+			owner,
+			collection_id,
+			Role::Minter,
+			account.clone(),
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, Role::Minter, account.clone());
+
+		assert!(!<LaosEvolution<T> as EvolutionCollectionRoles<_>>::has_role(
+			collection_id,
+			Role::Minter,
+			account
+		));
+	}
+
+	#[benchmark]
+	fn precompile_accept_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&caller);
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollection<_, _>>::transfer_ownership(
+			owner,
+			caller.clone(),
+			collection_id,
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res =
+				EvolutionCollectionPrecompileSet::<T>::accept_ownership(collection_id, &mut handle);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_cancel_ownership_transfer() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollection<_, _>>::transfer_ownership(
+			owner,
+			account("new_owner", 0, 0),
+			collection_id,
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::cancel_ownership_transfer(
+				collection_id,
+				&mut handle,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_pending_owner() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res =
+				EvolutionCollectionPrecompileSet::<T>::pending_owner(collection_id, &mut handle);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn accept_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&caller);
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollection<_, _>>::transfer_ownership(
+			owner,
+			caller.clone(),
+			collection_id,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collection_id);
+
+		assert_eq!(CollectionOwner::<T>::get(collection_id), Some(caller));
+	}
+
+	#[benchmark]
+	fn cancel_ownership_transfer() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollection<_, _>>::transfer_ownership(
+			owner,
+			account("new_owner", 0, 0),
+			collection_id,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert_eq!(PendingOwner::<T>::get(collection_id), None);
+	}
+
+	#[benchmark]
+	fn precompile_renounce_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::renounce_ownership(
+				collection_id,
+				&mut handle,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn renounce_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert_eq!(CollectionOwner::<T>::get(collection_id), None);
+	}
+
+	#[benchmark]
+	fn precompile_set_collection_metadata() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let metadata = max_collection_metadata::<T>();
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::set_collection_metadata(
+				collection_id,
+				&mut handle,
+				metadata.name.to_vec().into(),
+				metadata.symbol.to_vec().into(),
+				metadata.contract_uri.to_vec().into(),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_collection_metadata() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let collection_id = <LaosEvolution<T> as EvolutionCollectionMetadata<_, _>>::create_collection_with_metadata(
+			caller.clone(),
+			caller.clone(),
+			max_collection_metadata::<T>(),
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res =
+				EvolutionCollectionPrecompileSet::<T>::contract_uri(collection_id, &mut handle);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn set_collection_metadata() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let metadata = max_collection_metadata::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, metadata.clone());
+
+		assert_eq!(CollectionMetadataOf::<T>::get(collection_id), Some(metadata));
+	}
+
+	#[benchmark]
+	fn precompile_set_default_royalty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let receiver = Address::from(H160::from_low_u64_be(1));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::set_default_royalty(
+				collection_id,
+				&mut handle,
+				receiver,
+				MAX_ROYALTY_BASIS_POINTS,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_set_token_royalty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			vec![1u8; 100].try_into().unwrap(),
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let receiver = Address::from(H160::from_low_u64_be(1));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::set_token_royalty(
+				collection_id,
+				&mut handle,
+				token_id,
+				receiver,
+				MAX_ROYALTY_BASIS_POINTS,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_royalty_info() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		// worst case: the token has no royalty of its own and falls back to the default one
+		<LaosEvolution<T> as EvolutionCollectionRoyalties<_>>::set_default_royalty(
+			owner.clone(),
+			collection_id,
+			owner,
+			MAX_ROYALTY_BASIS_POINTS,
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::royalty_info(
+				collection_id,
+				&mut handle,
+				TokenId::zero(),
+				U256::MAX,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn set_default_royalty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, owner, MAX_ROYALTY_BASIS_POINTS);
+
+		assert!(DefaultRoyalty::<T>::contains_key(collection_id));
+	}
+
+	#[benchmark]
+	fn set_token_royalty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			vec![1u8; 100].try_into().unwrap(),
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, token_id, owner, MAX_ROYALTY_BASIS_POINTS);
+
+		assert!(TokenRoyalty::<T>::contains_key(collection_id, token_id));
+	}
+
+	#[benchmark]
+	fn precompile_token_uri_version() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			vec![1u8; T::MaxTokenUriLength::get() as usize].try_into().unwrap(),
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::token_uri_version(
+				collection_id,
+				&mut handle,
+				token_id,
+			);
+			assert_eq!(res.ok(), Some(0));
+		}
+	}
+
+	#[benchmark]
+	fn precompile_token_uri_at() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_uri: TokenUriOf<T> =
+			vec![1u8; T::MaxTokenUriLength::get() as usize].try_into().unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			token_uri.clone(),
+		)
+		.unwrap();
+		// worst case: the version is read from the history
+		<LaosEvolution<T> as EvolutionCollection<_, _>>::evolve_with_external_uri(
+			owner,
+			collection_id,
+			token_id,
+			token_uri,
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::token_uri_at(
+				collection_id,
+				&mut handle,
+				token_id,
+				0,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_is_mint_nonce_used() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_funded_collection::<T>(caller.clone()).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::is_mint_nonce_used(
+				collection_id,
+				&mut handle,
+				Address(H160::from_low_u64_be(1)),
+				U256::zero(),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_invalidate_mint_nonce() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_funded_collection::<T>(caller.clone()).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller.clone()));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::invalidate_mint_nonce(
+				collection_id,
+				&mut handle,
+				U256::zero(),
+			);
+			assert!(res.is_ok());
+		}
+
+		assert!(UsedNonces::<T>::contains_key((collection_id, caller, U256::zero())));
+	}
+
+	#[benchmark]
+	fn invalidate_mint_nonce() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_funded_collection::<T>(caller.clone()).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collection_id, U256::zero());
+
+		assert!(UsedNonces::<T>::contains_key((collection_id, caller, U256::zero())));
+	}
+
+	#[benchmark]
+	fn precompile_claim_from_drop(
+		p: Linear<0, 32>,
+		s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let to = H160::from_low_u64_be(1);
+		let slot = Slot::try_from(2).unwrap();
+		let token_uri = vec![1u8; s.try_into().unwrap()];
+		let proof: Vec<H256> = (0..p).map(|i| H256::from_low_u64_be(i.into())).collect();
+		let root = merkle_root(drop_leaf(slot, to, &token_uri), &proof);
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollectionDrop<_, _>>::set_drop_root(
+			owner,
+			collection_id,
+			root,
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::claim_from_drop(
+				collection_id,
+				&mut handle,
+				proof,
+				Address(to),
+				slot,
+				token_uri.into(),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_set_drop_root() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::set_drop_root(
+				collection_id,
+				&mut handle,
+				H256::repeat_byte(1),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_drop_root() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollectionDrop<_, _>>::set_drop_root(
+			owner,
+			collection_id,
+			H256::repeat_byte(1),
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::drop_root(collection_id, &mut handle);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn set_drop_root() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, H256::repeat_byte(1));
+
+		assert!(DropRoot::<T>::contains_key(collection_id));
+	}
+
+	#[benchmark]
+	fn create_collection_with_max_supply() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		fund::<T>(&owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), owner, u64::MAX);
+
+		assert_eq!(MaxSupply::<T>::get(0), Some(u64::MAX));
+	}
+
+	#[benchmark]
+	fn precompile_create_collection_with_max_supply() {
+		let owner = Address::from(H160::zero());
+		fund::<T>(&T::AccountIdToH160::convert_back(owner.into()));
+		let mut handle = MockHandle::new(owner.into());
+
+		#[block]
+		{
+			let res = EvolutionCollectionFactoryPrecompile::<T>::create_collection_with_max_supply(
+				&mut handle,
+				owner,
+				u64::MAX,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn create_collection_with_metadata_and_max_supply() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		fund::<T>(&owner);
+		let metadata = max_collection_metadata::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), owner, metadata, u64::MAX);
+
+		assert_eq!(MaxSupply::<T>::get(0), Some(u64::MAX));
+	}
+
+	#[benchmark]
+	fn precompile_create_collection_with_metadata_and_max_supply() {
+		let owner = Address::from(H160::zero());
+		fund::<T>(&T::AccountIdToH160::convert_back(owner.into()));
+		let mut handle = MockHandle::new(owner.into());
+		let metadata = max_collection_metadata::<T>();
+
+		#[block]
+		{
+			let res = EvolutionCollectionFactoryPrecompile::<T>::create_collection_with_metadata_and_max_supply(
+				&mut handle,
+				owner,
+				metadata.name.to_vec().into(),
+				metadata.symbol.to_vec().into(),
+				metadata.contract_uri.to_vec().into(),
+				u64::MAX,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_total_supply() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res =
+				EvolutionCollectionPrecompileSet::<T>::total_supply(collection_id, &mut handle);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_max_supply() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		fund::<T>(&owner);
+		let collection_id =
+			<LaosEvolution<T> as EvolutionCollectionFactory<_>>::create_collection_with_max_supply(
+				owner.clone(),
+				owner,
+				u64::MAX,
+			)
+			.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::max_supply(collection_id, &mut handle);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_token_by_index() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			vec![1u8; 100].try_into().unwrap(),
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::token_by_index(
+				collection_id,
+				&mut handle,
+				U256::zero(),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn set_base_uri(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let base_uri: TokenUriOf<T> = vec![1u8; s as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, base_uri);
+	}
+
+	#[benchmark]
+	fn precompile_set_base_uri(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		let base_uri: UnboundedString = vec![1u8; s as usize].into();
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::set_base_uri(
+				collection_id,
+				&mut handle,
+				base_uri,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_base_uri() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		<LaosEvolution<T> as EvolutionCollectionBaseUri<_, _>>::set_base_uri(
+			owner,
+			collection_id,
+			vec![1u8; <T as Config>::MaxTokenUriLength::get() as usize].try_into().unwrap(),
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::base_uri(collection_id, &mut handle);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn mint_next(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_uri: TokenUriOf<T> = vec![1u8; s as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, owner.clone(), token_uri);
+
+		assert_eq!(NextSlot::<T>::get(collection_id, owner), Slot::try_from(1).unwrap());
+	}
+
+	#[benchmark]
+	fn precompile_mint_next(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let to = Address::from(H160::from_low_u64_be(1));
+		let token_uri = vec![1u8; s.try_into().unwrap()].try_into().unwrap();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::mint_next(
+				collection_id,
+				&mut handle,
+				to,
+				token_uri,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn schedule_evolution(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			vec![0u8; s as usize].try_into().unwrap(),
+		)
+		.unwrap();
+		// worst case: the previous scheduled evolution is replaced, releasing its deposit
+		schedule_longest_evolution::<T>(owner, collection_id, token_id);
+		let token_uri: TokenUriOf<T> = vec![1u8; s as usize].try_into().unwrap();
+		let block: BlockNumberFor<T> = 11u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, token_id, token_uri.clone(), block);
+
+		assert_eq!(
+			LaosEvolution::<T>::scheduled_evolution(collection_id, token_id),
+			Some((block, token_uri))
+		);
+	}
+
+	#[benchmark]
+	fn cancel_scheduled_evolution() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_uri: TokenUriOf<T> =
+			vec![1u8; T::MaxTokenUriLength::get() as usize].try_into().unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			token_uri,
+		)
+		.unwrap();
+		schedule_longest_evolution::<T>(owner, collection_id, token_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, token_id);
+
+		assert_eq!(LaosEvolution::<T>::scheduled_evolution(collection_id, token_id), None);
+	}
+
+	#[benchmark]
+	fn apply_scheduled_evolutions(n: Linear<0, { T::MaxScheduledEvolutionsPerBlock::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_uri: TokenUriOf<T> =
+			vec![1u8; T::MaxTokenUriLength::get() as usize].try_into().unwrap();
+		let block: BlockNumberFor<T> = 10u32.into();
+		// worst case: every evolution must have been approved by the initial owner
+		EvolutionConsentRequired::<T>::insert(collection_id, ());
+		for i in 0..n {
+			let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+				owner.clone(),
+				collection_id,
+				Slot::try_from(i as u128).unwrap(),
+				owner.clone(),
+				// worst case: the deposit of the scheduled token URI is topped up
+				TokenUriOf::<T>::default(),
+			)
+			.unwrap();
+			// worst case: the oldest version of the history is pruned
+			TokenURIVersion::<T>::insert(collection_id, token_id, T::MaxUriHistory::get());
+			EvolutionApproval::<T>::insert(collection_id, token_id, H256(keccak_256(&token_uri)));
+			LaosEvolution::<T>::schedule_evolution(
+				RawOrigin::Signed(owner.clone()).into(),
+				collection_id,
+				token_id,
+				token_uri.clone(),
+				block,
+			)
+			.unwrap();
+		}
+
+		#[block]
+		{
+			LaosEvolution::<T>::apply_scheduled_evolutions(block);
+		}
+
+		assert!(EvolutionAgenda::<T>::get(block).is_empty());
+	}
+
+	#[benchmark]
+	fn precompile_scheduled_evolution() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_uri: TokenUriOf<T> =
+			vec![1u8; T::MaxTokenUriLength::get() as usize].try_into().unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			token_uri.clone(),
+		)
+		.unwrap();
+		LaosEvolution::<T>::schedule_evolution(
+			RawOrigin::Signed(owner).into(),
+			collection_id,
+			token_id,
+			token_uri,
+			10u32.into(),
+		)
+		.unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::scheduled_evolution(
+				collection_id,
+				&mut handle,
+				token_id,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_schedule_evolution(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			vec![0u8; s as usize].try_into().unwrap(),
+		)
+		.unwrap();
+		// worst case: the previous scheduled evolution is replaced, releasing its deposit
+		schedule_longest_evolution::<T>(owner, collection_id, token_id);
+		let token_uri: UnboundedString = vec![1u8; s as usize].into();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::schedule_evolution(
+				collection_id,
+				&mut handle,
+				token_id,
+				token_uri,
+				U256::from(11),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_cancel_scheduled_evolution() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_uri: TokenUriOf<T> =
+			vec![1u8; T::MaxTokenUriLength::get() as usize].try_into().unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			token_uri,
+		)
+		.unwrap();
+		schedule_longest_evolution::<T>(owner, collection_id, token_id);
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::cancel_scheduled_evolution(
+				collection_id,
+				&mut handle,
+				token_id,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn set_attribute(s: Linear<0, { <T as Config>::MaxAttributeValueLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+		let key = H256::repeat_byte(1);
+		let value: AttributeValueOf<T> = vec![1u8; s as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, token_id, key, value.clone());
+
+		assert_eq!(
+			<LaosEvolution<T> as EvolutionCollectionAttributes<_, _>>::attribute(
+				collection_id,
+				token_id,
+				key
+			),
+			Some(value)
+		);
+	}
+
+	#[benchmark]
+	fn remove_attribute() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+		set_attributes::<T>(owner, collection_id, token_id, T::MaxAttributesPerToken::get());
+		let key = H256::from_low_u64_be(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, token_id, key);
+
+		assert_eq!(
+			<LaosEvolution<T> as EvolutionCollectionAttributes<_, _>>::attribute(
+				collection_id,
+				token_id,
+				key
+			),
+			None
+		);
+	}
+
+	#[benchmark]
+	fn precompile_set_attribute(s: Linear<0, { <T as Config>::MaxAttributeValueLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+		let value: UnboundedBytes = vec![1u8; s as usize].into();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::set_attribute(
+				collection_id,
+				&mut handle,
+				token_id,
+				H256::repeat_byte(1),
+				value,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_remove_attribute() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+		set_attributes::<T>(owner, collection_id, token_id, T::MaxAttributesPerToken::get());
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::remove_attribute(
+				collection_id,
+				&mut handle,
+				token_id,
+				H256::from_low_u64_be(0),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_get_attribute() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner.clone(),
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+		set_attributes::<T>(owner, collection_id, token_id, T::MaxAttributesPerToken::get());
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::get_attribute(
+				collection_id,
+				&mut handle,
+				token_id,
+				H256::from_low_u64_be(0),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn set_evolution_consent_required() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, true);
+
+		assert!(EvolutionConsentRequired::<T>::contains_key(collection_id));
+	}
+
+	#[benchmark]
+	fn approve_evolution() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+		EvolutionConsentRequired::<T>::insert(collection_id, ());
+		let uri_hash = H256::from_low_u64_be(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, token_id, uri_hash);
+
+		assert_eq!(EvolutionApproval::<T>::get(collection_id, token_id), Some(uri_hash));
+	}
+
+	#[benchmark]
+	fn precompile_set_evolution_consent_required() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::set_evolution_consent_required(
+				collection_id,
+				&mut handle,
+				true,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_evolution_consent_required() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner).unwrap();
+		EvolutionConsentRequired::<T>::insert(collection_id, ());
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::evolution_consent_required(
+				collection_id,
+				&mut handle,
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_approve_evolution() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner.clone(),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+		EvolutionConsentRequired::<T>::insert(collection_id, ());
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::approve_evolution(
+				collection_id,
+				&mut handle,
+				token_id,
+				H256::from_low_u64_be(1),
+			);
+			assert!(res.is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn precompile_evolve_with_signature(s: Linear<0, { <T as Config>::MaxTokenUriLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = caller.clone();
+		let (public, holder) = ecdsa_key();
+		let token_uri = vec![1u8; s.try_into().unwrap()];
+		let collection_id = create_funded_collection::<T>(owner.clone()).unwrap();
+		let token_id = <LaosEvolution<T> as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			T::AccountIdToH160::convert_back(holder),
+			TokenUriOf::<T>::default(),
+		)
+		.unwrap();
+		// worst case: the oldest version of the history is pruned
+		TokenURIVersion::<T>::insert(collection_id, token_id, T::MaxUriHistory::get());
+		EvolutionConsentRequired::<T>::insert(collection_id, ());
+		let collection_address = collection_id_to_address(collection_id);
+		let digest = evolution_authorization_digest::<T>(
+			collection_address,
+			token_id,
+			&token_uri,
+			T::MaxUriHistory::get(),
+			U256::MAX,
+		);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &digest).unwrap();
+		let mut handle = MockHandle::new(T::AccountIdToH160::convert(caller));
+		handle.context.address = collection_address;
+
+		#[block]
+		{
+			let res = EvolutionCollectionPrecompileSet::<T>::evolve_with_signature(
+				collection_id,
+				&mut handle,
+				token_id,
+				token_uri.into(),
+				U256::MAX,
+				UnboundedBytes::from(&signature.0[..]),
+			);
+			assert!(res.is_ok());
+		}
 	}
 }
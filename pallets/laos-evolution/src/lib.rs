@@ -30,29 +30,50 @@ mod mock;
 mod tests;
 
 mod benchmarking;
+pub mod migrations;
 pub mod precompiles;
+pub mod runtime_api;
 pub mod traits;
 pub mod types;
 pub mod weights;
 
-use frame_support::pallet_prelude::*;
-use sp_core::H160;
+use frame_support::{
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{Currency, ReservableCurrency, UnixTime},
+};
+use precompile_utils::{prelude::Address, solidity};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
 use sp_runtime::{
-	traits::{Convert, ConvertBack, One},
+	traits::{Convert, ConvertBack, One, Saturating, Zero},
 	ArithmeticError, DispatchError,
 };
+use sp_std::vec::Vec;
 
 use pallet_evm::GasWeightMapping;
-pub use traits::{EvolutionCollection, EvolutionCollectionFactory, OnCreateCollection};
+pub use traits::{
+	EvolutionCollection, EvolutionCollectionAttributes, EvolutionCollectionBaseUri,
+	EvolutionCollectionBatch, EvolutionCollectionBurn, EvolutionCollectionConsent,
+	EvolutionCollectionDrop, EvolutionCollectionFactory, EvolutionCollectionFreezing,
+	EvolutionCollectionHistory, EvolutionCollectionMetadata, EvolutionCollectionMintNext,
+	EvolutionCollectionNonces, EvolutionCollectionOwnership, EvolutionCollectionRoles,
+	EvolutionCollectionRoyalties, EvolutionCollectionScheduling, EvolutionCollectionSupply,
+	OnCreateCollection, OnEvolve, OnMint, OnOwnershipTransferred,
+};
 pub use types::*;
 pub use weights::WeightInfo;
 
+/// Target of the logs of this pallet
+const LOG_TARGET: &str = "runtime::laos-evolution";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -71,9 +92,51 @@ pub mod pallet {
 		type GasWeightMapping: GasWeightMapping;
 		/// Callback for creating a new collection
 		type OnCreateCollection: OnCreateCollection;
+		/// Hook called when a token is minted, whose upper bound of weight is part of the weight of
+		/// the calls that mint
+		type OnMint: OnMint<Self::AccountId>;
+		/// Hook called when a token is evolved, whose upper bound of weight is part of the weight
+		/// of the calls that evolve
+		type OnEvolve: OnEvolve<TokenUriOf<Self>>;
+		/// Hook called when the ownership of a collection is transferred or renounced, whose upper
+		/// bound of weight is part of the weight of the calls that do it
+		type OnOwnershipTransferred: OnOwnershipTransferred<Self::AccountId>;
+		/// Currency in which the storage deposits are reserved
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Deposit reserved from the account that creates a collection, released when its ownership
+		/// is renounced
+		#[pallet::constant]
+		type CollectionDeposit: Get<BalanceOf<Self>>;
+		/// Deposit reserved from the owner of a collection for every byte of the token URIs of
+		/// its tokens, including the previous ones kept in their history
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
 		/// Limit for the length of `token_uri`
 		#[pallet::constant]
 		type MaxTokenUriLength: Get<u32>;
+		/// Limit for the length of the name of a collection
+		#[pallet::constant]
+		type MaxCollectionNameLength: Get<u32>;
+		/// Limit for the length of the symbol of a collection
+		#[pallet::constant]
+		type MaxCollectionSymbolLength: Get<u32>;
+		/// Limit for the number of previous token URIs kept for every token, `0` disables the
+		/// history
+		#[pallet::constant]
+		type MaxUriHistory: Get<u32>;
+		/// Limit for the number of evolutions scheduled for the same block
+		#[pallet::constant]
+		type MaxScheduledEvolutionsPerBlock: Get<u32>;
+		/// Limit for the length of the value of a token attribute
+		#[pallet::constant]
+		type MaxAttributeValueLength: Get<u32>;
+		/// Limit for the number of attributes of every token
+		#[pallet::constant]
+		type MaxAttributesPerToken: Get<u32>;
+		/// Chain id of the EVM, which binds signed mint authorizations to this chain
+		type ChainId: Get<u64>;
+		/// Time provider used to check the deadline of signed mint authorizations
+		type Timestamp: UnixTime;
 	}
 
 	/// Collection counter
@@ -87,10 +150,99 @@ pub mod pallet {
 	pub type CollectionOwner<T: Config> =
 		StorageMap<_, Blake2_128Concat, CollectionId, AccountIdOf<T>, OptionQuery>;
 
+	/// Account that has been offered the ownership of the collection and has not accepted it yet
+	#[pallet::storage]
+	#[pallet::getter(fn pending_owner)]
+	pub type PendingOwner<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, AccountIdOf<T>, OptionQuery>;
+
+	/// Name, symbol and contract URI of collections
+	#[pallet::storage]
+	#[pallet::getter(fn collection_metadata)]
+	pub type CollectionMetadataOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, CollectionMetadata<T>, OptionQuery>;
+
+	/// Total storage deposit of the tokens of collections, reserved from their owner
+	#[pallet::storage]
+	#[pallet::getter(fn collection_deposit)]
+	pub type CollectionDepositOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, BalanceOf<T>, ValueQuery>;
+
+	/// Account that created collections and the [`Config::CollectionDeposit`] reserved from it
+	#[pallet::storage]
+	#[pallet::getter(fn creation_deposit)]
+	pub type CreationDepositOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, (AccountIdOf<T>, BalanceOf<T>), OptionQuery>;
+
+	/// Number of tokens of collections, which decreases when tokens are burned
+	#[pallet::storage]
+	#[pallet::getter(fn token_count)]
+	pub type TokenCount<T: Config> = StorageMap<_, Blake2_128Concat, CollectionId, u64, ValueQuery>;
+
+	/// Number of tokens ever minted in collections, which does not decrease when tokens are
+	/// burned
+	#[pallet::storage]
+	#[pallet::getter(fn minted_count)]
+	pub type MintedCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, u64, ValueQuery>;
+
+	/// Maximum number of tokens ever minted in collections, set when they are created and never
+	/// changed
+	///
+	/// It bounds [`MintedCount`], so burning tokens does not make room for new ones.
+	#[pallet::storage]
+	#[pallet::getter(fn max_supply)]
+	pub type MaxSupply<T: Config> = StorageMap<_, Blake2_128Concat, CollectionId, u64, OptionQuery>;
+
+	/// Tokens of collections by their index, which goes from zero to the token count
+	///
+	/// When a token is burned, the last token of the collection is moved to its index. It needs
+	/// no bound of its own, as it holds exactly one entry for every token of [`TokenURI`].
+	#[pallet::storage]
+	#[pallet::getter(fn token_by_index)]
+	pub type TokenByIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		u64,
+		TokenId,
+		OptionQuery,
+	>;
+
+	/// Index of tokens in [`TokenByIndex`]
+	#[pallet::storage]
+	pub type TokenIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		u64,
+		OptionQuery,
+	>;
+
+	/// Last token of [`TokenURI`] visited by the lazy migration that builds the token index, which
+	/// is in progress while it is set
+	///
+	/// Until the migration ends, [`TokenCount`] and [`TokenByIndex`] only include the tokens
+	/// indexed so far.
+	#[pallet::storage]
+	pub type TokenIndexMigrationCursor<T: Config> =
+		StorageValue<_, (CollectionId, TokenId), OptionQuery>;
+
+	/// Base URI of collections, whose `{id}` placeholders are replaced by the token id to resolve
+	/// the token URI of tokens minted with an empty URI
+	#[pallet::storage]
+	#[pallet::getter(fn base_uri)]
+	pub type BaseURI<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, TokenUriOf<T>, OptionQuery>;
+
 	/// Token URI which can override the default URI scheme and set explicitly
 	/// This will contain external URI in a raw form
+	///
+	/// Use [`Pallet::token_uri`] to resolve empty token URIs through the base URI.
 	#[pallet::storage]
-	#[pallet::getter(fn token_uri)]
 	pub type TokenURI<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
@@ -101,6 +253,228 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Storage deposit of the token URI of tokens and of the previous token URIs kept in their
+	/// history, released when they are burned
+	#[pallet::storage]
+	#[pallet::getter(fn token_deposit)]
+	pub type TokenDeposit<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Number of times the token URI of tokens has been evolved
+	///
+	/// It is removed with the history of the token when the token is burned.
+	#[pallet::storage]
+	#[pallet::getter(fn token_uri_version)]
+	pub type TokenURIVersion<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Previous token URIs of tokens, indexed by version
+	///
+	/// Only the last [`Config::MaxUriHistory`] versions of every token are kept.
+	#[pallet::storage]
+	pub type TokenURIHistory<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, TokenId>,
+			NMapKey<Blake2_128Concat, u32>,
+		),
+		TokenUriOf<T>,
+		OptionQuery,
+	>;
+
+	/// Evolution scheduled for tokens, as the block at the beginning of which it is applied and
+	/// the new token URI
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_evolution)]
+	pub type ScheduledEvolution<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		(BlockNumberFor<T>, TokenUriOf<T>),
+		OptionQuery,
+	>;
+
+	/// Account that scheduled the evolution of tokens and the deposit of the new token URI
+	/// reserved from it
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_evolution_deposit)]
+	pub type ScheduledEvolutionDeposit<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		(AccountIdOf<T>, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// Tokens whose scheduled evolution is applied at the beginning of blocks
+	#[pallet::storage]
+	pub type EvolutionAgenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(CollectionId, TokenId), T::MaxScheduledEvolutionsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Tokens whose token URI is frozen and can no longer be evolved
+	#[pallet::storage]
+	pub type FrozenToken<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Collections whose tokens can no longer be evolved
+	#[pallet::storage]
+	pub type FrozenCollection<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, (), OptionQuery>;
+
+	/// Collections whose tokens can only be evolved with the approval of their initial owner
+	#[pallet::storage]
+	pub type EvolutionConsentRequired<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, (), OptionQuery>;
+
+	/// Hash of the token URI that the initial owner of tokens approved as their next evolution
+	///
+	/// It is consumed by the evolution to that token URI, so that it cannot be reused.
+	#[pallet::storage]
+	pub type EvolutionApproval<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		H256,
+		OptionQuery,
+	>;
+
+	/// Royalty of collections, applied to the tokens without their own royalty
+	#[pallet::storage]
+	#[pallet::getter(fn default_royalty)]
+	pub type DefaultRoyalty<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, RoyaltyInfo<AccountIdOf<T>>, OptionQuery>;
+
+	/// Royalty of tokens, overriding the royalty of their collection
+	#[pallet::storage]
+	#[pallet::getter(fn token_royalty)]
+	pub type TokenRoyalty<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		RoyaltyInfo<AccountIdOf<T>>,
+		OptionQuery,
+	>;
+
+	/// On-chain attributes of tokens, indexed by their key
+	#[pallet::storage]
+	pub type TokenAttribute<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, TokenId>,
+			NMapKey<Blake2_128Concat, H256>,
+		),
+		AttributeValueOf<T>,
+		OptionQuery,
+	>;
+
+	/// Number of attributes of tokens, bounded by [`Config::MaxAttributesPerToken`]
+	#[pallet::storage]
+	pub type AttributeCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Nonces of the signed mint authorizations of collections that have already been used or
+	/// invalidated by their signer
+	///
+	/// Authorizations can be used in any order, and every nonce of a signer only once.
+	#[pallet::storage]
+	pub type UsedNonces<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+			NMapKey<Blake2_128Concat, U256>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	/// Slot in which [`EvolutionCollectionMintNext::mint_next`] mints the next token of owners,
+	/// past every slot minted to them
+	#[pallet::storage]
+	#[pallet::getter(fn next_slot)]
+	pub type NextSlot<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Slot,
+		ValueQuery,
+	>;
+
+	/// Merkle root of the drop of collections, whose leaves can be claimed by any account
+	#[pallet::storage]
+	#[pallet::getter(fn drop_root)]
+	pub type DropRoot<T: Config> = StorageMap<_, Blake2_128Concat, CollectionId, H256, OptionQuery>;
+
+	/// Leaves of drops that have already been claimed
+	#[pallet::storage]
+	pub type ClaimedDropLeaf<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		H256,
+		(),
+		OptionQuery,
+	>;
+
+	/// Roles granted by collection owners to other accounts
+	#[pallet::storage]
+	pub type CollectionRole<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, Role>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+		),
+		(),
+		OptionQuery,
+	>;
+
 	/// Events for this pallet.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -131,6 +505,102 @@ pub mod pallet {
 			from: AccountIdOf<T>,
 			to: AccountIdOf<T>,
 		},
+		/// Asset burned
+		/// [collection_id, token_id]
+		Burned { collection_id: CollectionId, token_id: TokenId },
+		/// Token URI frozen
+		/// [collection_id, token_id]
+		TokenFrozen { collection_id: CollectionId, token_id: TokenId },
+		/// All token URIs of the collection frozen
+		/// [collection_id]
+		CollectionFrozen { collection_id: CollectionId },
+		/// Role granted to an account in a collection
+		/// [collection_id, role, account]
+		RoleGranted { collection_id: CollectionId, role: Role, account: AccountIdOf<T> },
+		/// Role revoked from an account in a collection
+		/// [collection_id, role, account]
+		RoleRevoked { collection_id: CollectionId, role: Role, account: AccountIdOf<T> },
+		/// Transfer of the ownership of collection started, pending acceptance by `to`
+		/// [collection_id, from, to]
+		OwnershipTransferStarted {
+			collection_id: CollectionId,
+			from: AccountIdOf<T>,
+			to: AccountIdOf<T>,
+		},
+		/// Pending transfer of the ownership of collection canceled
+		/// [collection_id, pending_owner]
+		OwnershipTransferCanceled { collection_id: CollectionId, pending_owner: AccountIdOf<T> },
+		/// Ownership of collection renounced, leaving the collection without owner
+		/// [collection_id, owner]
+		OwnershipRenounced { collection_id: CollectionId, owner: AccountIdOf<T> },
+		/// Metadata of collection updated
+		/// [collection_id]
+		CollectionMetadataUpdated { collection_id: CollectionId },
+		/// Default royalty of collection set
+		/// [collection_id, receiver, basis_points]
+		DefaultRoyaltySet {
+			collection_id: CollectionId,
+			receiver: AccountIdOf<T>,
+			basis_points: BasisPoints,
+		},
+		/// Royalty of token set
+		/// [collection_id, token_id, receiver, basis_points]
+		TokenRoyaltySet {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			receiver: AccountIdOf<T>,
+			basis_points: BasisPoints,
+		},
+		/// Merkle root of the drop of the collection set
+		/// [collection_id, root]
+		DropRootSet { collection_id: CollectionId, root: H256 },
+		/// Base URI of the collection set
+		/// [collection_id, base_uri]
+		BaseURISet { collection_id: CollectionId, base_uri: TokenUriOf<T> },
+		/// Storage deposit of collection reserved from `who`
+		/// [collection_id, who, amount]
+		DepositReserved { collection_id: CollectionId, who: AccountIdOf<T>, amount: BalanceOf<T> },
+		/// Storage deposit of collection released to `who`
+		/// [collection_id, who, amount]
+		DepositReleased { collection_id: CollectionId, who: AccountIdOf<T>, amount: BalanceOf<T> },
+		/// Evolution of token scheduled at the beginning of `block`
+		/// [collection_id, token_id, token_uri, block]
+		EvolutionScheduled {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			token_uri: TokenUriOf<T>,
+			block: BlockNumberFor<T>,
+		},
+		/// Scheduled evolution of token canceled
+		/// [collection_id, token_id]
+		ScheduledEvolutionCanceled { collection_id: CollectionId, token_id: TokenId },
+		/// Scheduled evolution of token could not be applied
+		/// [collection_id, token_id, error]
+		ScheduledEvolutionFailed {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			error: DispatchError,
+		},
+		/// Attribute of token set
+		/// [collection_id, token_id, key, value]
+		AttributeSet {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			key: H256,
+			value: AttributeValueOf<T>,
+		},
+		/// Attribute of token removed
+		/// [collection_id, token_id, key]
+		AttributeRemoved { collection_id: CollectionId, token_id: TokenId, key: H256 },
+		/// Whether evolutions of collection require the approval of the initial owner of tokens
+		/// [collection_id, required]
+		EvolutionConsentRequiredSet { collection_id: CollectionId, required: bool },
+		/// Evolution of token to the token URI whose hash is `uri_hash` approved
+		/// [collection_id, token_id, uri_hash]
+		EvolutionApproved { collection_id: CollectionId, token_id: TokenId, uri_hash: H256 },
+		/// Nonce of the signed mint authorizations of `signer` invalidated before being used
+		/// [collection_id, signer, nonce]
+		MintNonceInvalidated { collection_id: CollectionId, signer: AccountIdOf<T>, nonce: U256 },
 	}
 
 	// Errors inform users that something went wrong.
@@ -145,118 +615,1906 @@ pub mod pallet {
 		AlreadyMinted,
 		/// Asset does not exist
 		AssetDoesNotExist,
+		/// Token URI is frozen and can no longer be evolved nor burned
+		TokenFrozen,
+		/// Not the pending owner of the collection
+		NotPendingOwner,
+		/// There is no pending transfer of the ownership of the collection
+		NoPendingOwnershipTransfer,
+		/// Royalty exceeds [`MAX_ROYALTY_BASIS_POINTS`]
+		RoyaltyTooHigh,
+		/// Nonce of the signed mint authorization has already been used or invalidated
+		MintNonceAlreadyUsed,
+		/// Collection has no drop
+		DropDoesNotExist,
+		/// Merkle proof does not match the root of the drop
+		InvalidMerkleProof,
+		/// Leaf of the drop has already been claimed
+		AlreadyClaimed,
+		/// Collection has minted its maximum number of tokens
+		MaxSupplyReached,
+		/// Base URI exceeds the maximum token URI length once `{id}` is replaced by a token id
+		BaseUriTooLong,
+		/// Block of the scheduled evolution is not in the future
+		ScheduledInThePast,
+		/// Block has reached its maximum number of scheduled evolutions
+		TooManyScheduledEvolutions,
+		/// Token has no scheduled evolution
+		NoScheduledEvolution,
+		/// Token has reached its maximum number of attributes
+		TooManyAttributes,
+		/// Token has no attribute with the given key
+		AttributeDoesNotExist,
+		/// Not the initial owner of the token
+		NotInitialOwner,
+		/// Evolution has not been approved by the initial owner of the token
+		EvolutionNotApproved,
+		/// Collection does not require the consent of initial owners to evolve their tokens
+		EvolutionConsentNotRequired,
+		/// Ownership of the collection has been renounced
+		OwnershipRenounced,
+		/// Every slot of the owner has been minted, so there is no next slot to mint
+		SlotsExhausted,
+		/// Block is not a valid block number of the chain
+		InvalidBlock,
+		/// Collection is frozen, so its base URI can no longer be changed
+		CollectionFrozen,
 	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::apply_scheduled_evolutions(now)
+		}
 
-	impl<T: Config> Pallet<T> {
-		pub fn is_owner(collection_id: CollectionId, who: T::AccountId) -> bool {
-			CollectionOwner::<T>::get(collection_id) == Some(who)
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			migrations::v1::migrate_token_index::<T>(remaining_weight)
 		}
 	}
-}
 
-impl<T: Config> EvolutionCollectionFactory<AccountIdOf<T>> for Pallet<T> {
-	fn create_collection(owner: AccountIdOf<T>) -> Result<CollectionId, DispatchError> {
-		let collection_id = Self::collection_counter();
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Creates a new collection owned by `owner`
+		///
+		/// [`Config::CollectionDeposit`] is reserved from the origin until the ownership of the
+		/// collection is renounced.
+		///
+		/// The origin must be signed, and it does not need to be the `owner` of the collection.
+		///
+		/// Emits [`Event::CollectionCreated`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_collection())]
+		pub fn create_collection(origin: OriginFor<T>, owner: AccountIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-		CollectionOwner::<T>::insert(collection_id, owner.clone());
+			<Self as EvolutionCollectionFactory<_>>::create_collection(who, owner)?;
 
-		// Attempt to increment the collection counter by 1. If this operation
-		// would result in an overflow, return early with an error
-		let counter = collection_id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
-		CollectionCounter::<T>::put(counter);
+			Ok(())
+		}
 
-		// Emit an event.
-		Self::deposit_event(Event::CollectionCreated { collection_id, owner });
+		/// Mints a new token with external URI in `slot` for `to`
+		///
+		/// [`Config::DepositPerByte`] is reserved from the owner of the collection for every byte
+		/// of `token_uri`.
+		///
+		/// The origin must be signed by the owner of the collection or an account with the minter
+		/// role.
+		///
+		/// Emits [`Event::MintedWithExternalURI`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			T::WeightInfo::mint_with_external_uri(token_uri.len() as u32)
+				.saturating_add(T::OnMint::weight())
+		)]
+		pub fn mint_with_external_uri(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			slot: Slot,
+			to: AccountIdOf<T>,
+			token_uri: TokenUriOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-		Ok(collection_id)
-	}
-}
+			<Self as EvolutionCollection<_, _>>::mint_with_external_uri(
+				who,
+				collection_id,
+				slot,
+				to,
+				token_uri,
+			)?;
 
-impl<T: Config> EvolutionCollection<AccountIdOf<T>, TokenUriOf<T>> for Pallet<T> {
-	fn mint_with_external_uri(
-		who: AccountIdOf<T>,
-		collection_id: CollectionId,
-		slot: Slot,
-		to: AccountIdOf<T>,
-		token_uri: TokenUriOf<T>,
-	) -> Result<TokenId, DispatchError> {
-		ensure!(
-			CollectionOwner::<T>::contains_key(collection_id),
-			Error::<T>::CollectionDoesNotExist
-		);
-		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+			Ok(())
+		}
 
-		let to_as_h160 = T::AccountIdToH160::convert(to.clone());
-		// compose asset_id	from slot and owner
-		let token_id = slot_and_owner_to_token_id(slot, to_as_h160);
+		/// Changes the external URI of an existing token
+		///
+		/// The previous token URI is kept in the history of the token, so its deposit stays
+		/// reserved. [`Config::DepositPerByte`] is reserved from the owner of the collection for
+		/// every byte of `token_uri`, and the deposit of the version pruned from the history, if
+		/// any, is released.
+		///
+		/// If the collection requires the consent of the initial owner of the token, the evolution
+		/// must have been approved through [`Pallet::approve_evolution`].
+		///
+		/// The origin must be signed by the owner of the collection or an account with the evolver
+		/// role.
+		///
+		/// Emits [`Event::EvolvedWithExternalURI`].
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			T::WeightInfo::evolve_with_external_uri(token_uri.len() as u32)
+				.saturating_add(T::OnEvolve::weight())
+		)]
+		pub fn evolve_with_external_uri(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			token_uri: TokenUriOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-		ensure!(TokenURI::<T>::get(collection_id, token_id).is_none(), Error::<T>::AlreadyMinted);
+			<Self as EvolutionCollection<_, _>>::evolve_with_external_uri(
+				who,
+				collection_id,
+				token_id,
+				token_uri,
+			)
+		}
 
-		TokenURI::<T>::insert(collection_id, token_id, token_uri.clone());
+		/// Starts the transfer of the ownership of the collection to `to`
+		///
+		/// The transfer is completed once `to` calls [`Pallet::accept_ownership`]. Any previous
+		/// pending transfer is replaced.
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::OwnershipTransferStarted`].
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			to: AccountIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-		Self::deposit_event(Event::MintedWithExternalURI {
-			collection_id,
-			slot,
-			to,
-			token_id,
-			token_uri,
-		});
+			<Self as EvolutionCollection<_, _>>::transfer_ownership(who, to, collection_id)
+		}
 
-		Ok(token_id)
-	}
+		/// Freezes the token URI of an existing token, so that it can no longer be evolved
+		///
+		/// A token resolved through the base URI of the collection keeps its resolved token URI,
+		/// whose deposit is reserved from the owner of the collection.
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::TokenFrozen`].
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::freeze_token())]
+		pub fn freeze_token(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-	fn collection_owner(collection_id: CollectionId) -> Option<AccountIdOf<T>> {
-		CollectionOwner::<T>::get(collection_id)
-	}
+			<Self as EvolutionCollectionFreezing<_>>::freeze_token(who, collection_id, token_id)
+		}
 
-	fn token_uri(collection_id: CollectionId, token_id: TokenId) -> Option<TokenUriOf<T>> {
-		TokenURI::<T>::get(collection_id, token_id)
-	}
+		/// Freezes the token URIs of all the tokens of the collection, so that none of them can be
+		/// evolved anymore
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::CollectionFrozen`].
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::freeze_collection())]
+		pub fn freeze_collection(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-	fn evolve_with_external_uri(
-		who: AccountIdOf<T>,
+			<Self as EvolutionCollectionFreezing<_>>::freeze_collection(who, collection_id)
+		}
+
+		/// Grants `role` in the collection to `account`
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::RoleGranted`] if `account` did not have the role yet.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			role: Role,
+			account: AccountIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionRoles<_>>::grant_role(who, collection_id, role, account)
+		}
+
+		/// Revokes `role` in the collection from `account`
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::RoleRevoked`] if `account` had the role.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			role: Role,
+			account: AccountIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionRoles<_>>::revoke_role(who, collection_id, role, account)
+		}
+
+		/// Accepts the pending transfer of the ownership of the collection
+		///
+		/// The storage deposit of the tokens of the collection is released to the previous owner
+		/// and reserved from the pending owner.
+		///
+		/// The origin must be signed by the pending owner of the collection.
+		///
+		/// Emits [`Event::CollectionTransferred`].
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::accept_ownership().saturating_add(T::OnOwnershipTransferred::weight())
+		)]
+		pub fn accept_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionOwnership<_>>::accept_ownership(who, collection_id)
+		}
+
+		/// Cancels the pending transfer of the ownership of the collection
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::OwnershipTransferCanceled`].
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::cancel_ownership_transfer())]
+		pub fn cancel_ownership_transfer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionOwnership<_>>::cancel_ownership_transfer(who, collection_id)
+		}
+
+		/// Renounces the ownership of the collection
+		///
+		/// Once renounced, no account can mint or evolve tokens of the collection anymore, its drop
+		/// is removed and the ownership cannot be recovered. [`Config::CollectionDeposit`] is
+		/// released to the account that created the collection, while the storage deposit of its
+		/// tokens stays reserved.
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::OwnershipRenounced`].
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			T::WeightInfo::renounce_ownership().saturating_add(T::OnOwnershipTransferred::weight())
+		)]
+		pub fn renounce_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionOwnership<_>>::renounce_ownership(who, collection_id)
+		}
+
+		/// Sets the name, symbol and contract URI of the collection
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::CollectionMetadataUpdated`].
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_collection_metadata())]
+		pub fn set_collection_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			metadata: CollectionMetadata<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionMetadata<_, _>>::set_collection_metadata(
+				who,
+				collection_id,
+				metadata,
+			)
+		}
+
+		/// Sets the royalty of every token of the collection without its own royalty
+		///
+		/// `basis_points` can not exceed [`MAX_ROYALTY_BASIS_POINTS`].
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::DefaultRoyaltySet`].
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_default_royalty())]
+		pub fn set_default_royalty(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			receiver: AccountIdOf<T>,
+			basis_points: BasisPoints,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionRoyalties<_>>::set_default_royalty(
+				who,
+				collection_id,
+				receiver,
+				basis_points,
+			)
+		}
+
+		/// Sets the royalty of an existing token, overriding the default royalty of the collection
+		///
+		/// `basis_points` can not exceed [`MAX_ROYALTY_BASIS_POINTS`].
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::TokenRoyaltySet`].
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_token_royalty())]
+		pub fn set_token_royalty(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			receiver: AccountIdOf<T>,
+			basis_points: BasisPoints,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionRoyalties<_>>::set_token_royalty(
+				who,
+				collection_id,
+				token_id,
+				receiver,
+				basis_points,
+			)
+		}
+
+		/// Sets the Merkle root of the drop of the collection, replacing the previous one
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::DropRootSet`].
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_drop_root())]
+		pub fn set_drop_root(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			root: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionDrop<_, _>>::set_drop_root(who, collection_id, root)
+		}
+
+		/// Creates a new collection owned by `owner` that can never mint more than `max_supply`
+		/// tokens, even if some of them are burned
+		///
+		/// [`Config::CollectionDeposit`] is reserved from the origin until the ownership of the
+		/// collection is renounced.
+		///
+		/// The origin must be signed, and it does not need to be the `owner` of the collection.
+		///
+		/// Emits [`Event::CollectionCreated`].
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::create_collection_with_max_supply())]
+		pub fn create_collection_with_max_supply(
+			origin: OriginFor<T>,
+			owner: AccountIdOf<T>,
+			max_supply: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionFactory<_>>::create_collection_with_max_supply(
+				who, owner, max_supply,
+			)?;
+
+			Ok(())
+		}
+
+		/// Sets the base URI of the collection, or removes it if `base_uri` is empty
+		///
+		/// Tokens minted with an empty token URI resolve to the base URI, with every `{id}`
+		/// replaced by their token id as 64 lowercase hex digits. It fails if the collection is
+		/// frozen.
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::BaseURISet`].
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_base_uri(base_uri.len() as u32))]
+		pub fn set_base_uri(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			base_uri: TokenUriOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionBaseUri<_, _>>::set_base_uri(who, collection_id, base_uri)
+		}
+
+		/// Mints a new token with external URI for `to` in its next free slot
+		///
+		/// Slots are allocated in increasing order, right after the highest slot minted to `to`,
+		/// including the ones minted explicitly with `mint_with_external_uri`, failing with
+		/// [`Error::SlotsExhausted`] once the maximum slot of `to` has been minted.
+		///
+		/// The origin must be signed by the owner of the collection or an account with the minter
+		/// role.
+		///
+		/// Emits [`Event::MintedWithExternalURI`].
+		#[pallet::call_index(17)]
+		#[pallet::weight(
+			T::WeightInfo::mint_next(token_uri.len() as u32).saturating_add(T::OnMint::weight())
+		)]
+		pub fn mint_next(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			to: AccountIdOf<T>,
+			token_uri: TokenUriOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionMintNext<_, _>>::mint_next(
+				who,
+				collection_id,
+				to,
+				token_uri,
+			)?;
+
+			Ok(())
+		}
+
+		/// Schedules the evolution of an existing token to `token_uri` at the beginning of `block`
+		///
+		/// Any previous scheduled evolution of the token is replaced. The evolution is applied on
+		/// behalf of the owner of the collection at that block, and it fails if the token has been
+		/// frozen or the ownership of the collection has been renounced. Burning the token cancels
+		/// it.
+		///
+		/// [`Config::DepositPerByte`] is reserved from the origin for every byte of `token_uri`
+		/// until the evolution is applied or canceled.
+		///
+		/// The origin must be signed by the owner of the collection or an account with the evolver
+		/// role.
+		///
+		/// Emits [`Event::EvolutionScheduled`].
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::schedule_evolution(token_uri.len() as u32))]
+		pub fn schedule_evolution(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			token_uri: TokenUriOf<T>,
+			block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let block: u64 = block.try_into().map_err(|_| Error::<T>::InvalidBlock)?;
+
+			<Self as EvolutionCollectionScheduling<_, _>>::schedule_evolution(
+				who,
+				collection_id,
+				token_id,
+				token_uri,
+				block,
+			)
+		}
+
+		/// Cancels the scheduled evolution of a token, releasing its deposit
+		///
+		/// The origin must be signed by the owner of the collection or an account with the evolver
+		/// role.
+		///
+		/// Emits [`Event::ScheduledEvolutionCanceled`].
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::cancel_scheduled_evolution())]
+		pub fn cancel_scheduled_evolution(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionScheduling<_, _>>::cancel_scheduled_evolution(
+				who,
+				collection_id,
+				token_id,
+			)
+		}
+
+		/// Sets the value of the attribute `key` of an existing token
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::AttributeSet`].
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_attribute(value.len() as u32))]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			key: H256,
+			value: AttributeValueOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionAttributes<_, _>>::set_attribute(
+				who,
+				collection_id,
+				token_id,
+				key,
+				value,
+			)
+		}
+
+		/// Removes the attribute `key` of a token
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::AttributeRemoved`].
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::remove_attribute())]
+		pub fn remove_attribute(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			key: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionAttributes<_, _>>::remove_attribute(
+				who,
+				collection_id,
+				token_id,
+				key,
+			)
+		}
+
+		/// Sets whether evolutions of the collection require the approval of the initial owner of
+		/// the token
+		///
+		/// The initial owner approves an evolution through [`Pallet::approve_evolution`].
+		///
+		/// The origin must be signed by the owner of the collection.
+		///
+		/// Emits [`Event::EvolutionConsentRequiredSet`].
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::set_evolution_consent_required())]
+		pub fn set_evolution_consent_required(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			required: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionConsent<_>>::set_evolution_consent_required(
+				who,
+				collection_id,
+				required,
+			)
+		}
+
+		/// Approves the next evolution of a token to the token URI whose Keccak hash is
+		/// `uri_hash`, replacing any previous approval
+		///
+		/// It fails if the collection does not require the consent of initial owners.
+		///
+		/// The origin must be signed by the initial owner of the token, as encoded in its id.
+		///
+		/// Emits [`Event::EvolutionApproved`].
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::approve_evolution())]
+		pub fn approve_evolution(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			uri_hash: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionConsent<_>>::approve_evolution(
+				who,
+				collection_id,
+				token_id,
+				uri_hash,
+			)
+		}
+
+		/// Creates a new collection owned by `owner` with `metadata` that can never mint more
+		/// than `max_supply` tokens, even if some of them are burned
+		///
+		/// [`Config::CollectionDeposit`] is reserved from the origin until the ownership of the
+		/// collection is renounced.
+		///
+		/// The origin must be signed, and it does not need to be the `owner` of the collection.
+		///
+		/// Emits [`Event::CollectionCreated`].
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::create_collection_with_metadata_and_max_supply())]
+		pub fn create_collection_with_metadata_and_max_supply(
+			origin: OriginFor<T>,
+			owner: AccountIdOf<T>,
+			metadata: CollectionMetadata<T>,
+			max_supply: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionMetadata<_, _>>::create_collection_with_metadata_and_max_supply(
+				who, owner, metadata, max_supply,
+			)?;
+
+			Ok(())
+		}
+
+		/// Invalidates `nonce` of the signed mint authorizations of the origin, so that the
+		/// authorizations signed with it can no longer be used
+		///
+		/// The origin must be signed by the signer of the authorizations.
+		///
+		/// Emits [`Event::MintNonceInvalidated`].
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::invalidate_mint_nonce())]
+		pub fn invalidate_mint_nonce(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nonce: U256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as EvolutionCollectionNonces<_, _>>::invalidate_mint_nonce(
+				who,
+				collection_id,
+				nonce,
+			)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Token URI of the token, if it exists
+		///
+		/// Tokens with an empty token URI resolve through the base URI of the collection, if set.
+		pub fn token_uri(collection_id: CollectionId, token_id: TokenId) -> Option<TokenUriOf<T>> {
+			TokenURI::<T>::get(collection_id, token_id)
+				.map(|token_uri| Self::resolve_token_uri(collection_id, token_id, token_uri))
+		}
+
+		fn resolve_token_uri(
+			collection_id: CollectionId,
+			token_id: TokenId,
+			token_uri: TokenUriOf<T>,
+		) -> TokenUriOf<T> {
+			if !token_uri.is_empty() {
+				return token_uri;
+			}
+
+			BaseURI::<T>::get(collection_id)
+				.and_then(|base_uri| expand_base_uri(&base_uri, token_id).try_into().ok())
+				.unwrap_or(token_uri)
+		}
+
+		pub fn is_owner(collection_id: CollectionId, who: T::AccountId) -> bool {
+			CollectionOwner::<T>::get(collection_id) == Some(who)
+		}
+
+		/// Whether the collection has been created, even if its ownership has been renounced
+		pub fn collection_exists(collection_id: CollectionId) -> bool {
+			CollectionOwner::<T>::contains_key(collection_id) ||
+				collection_id < CollectionCounter::<T>::get()
+		}
+
+		/// Whether `who` is the owner of the collection or has been granted `role` in it
+		///
+		/// Roles are ignored once the ownership of the collection has been renounced.
+		pub fn is_owner_or_has_role(
+			collection_id: CollectionId,
+			who: T::AccountId,
+			role: Role,
+		) -> bool {
+			match CollectionOwner::<T>::get(collection_id) {
+				Some(owner) =>
+					owner == who || CollectionRole::<T>::contains_key((collection_id, role, who)),
+				None => false,
+			}
+		}
+
+		/// Returns up to `limit` tokens of `collection_id` with their token URI
+		///
+		/// Tokens are iterated in storage order, starting right after `start_after` if given.
+		pub fn tokens_of_collection(
+			collection_id: CollectionId,
+			start_after: Option<TokenId>,
+			limit: u32,
+		) -> Vec<(TokenId, TokenUriOf<T>)> {
+			let tokens = match start_after {
+				Some(token_id) => TokenURI::<T>::iter_prefix_from(
+					collection_id,
+					TokenURI::<T>::hashed_key_for(collection_id, token_id),
+				),
+				None => TokenURI::<T>::iter_prefix(collection_id),
+			};
+			tokens
+				.take(limit as usize)
+				.map(|(token_id, token_uri)| {
+					(token_id, Self::resolve_token_uri(collection_id, token_id, token_uri))
+				})
+				.collect()
+		}
+
+		/// Returns up to `limit` tokens of `collection_id`, starting at index `offset`
+		pub fn tokens_by_index(
+			collection_id: CollectionId,
+			offset: u64,
+			limit: u32,
+		) -> Vec<TokenId> {
+			(offset..TokenCount::<T>::get(collection_id))
+				.take(limit as usize)
+				.filter_map(|index| TokenByIndex::<T>::get(collection_id, index))
+				.collect()
+		}
+
+		/// Removes the token from the index of the collection and decreases its token count
+		///
+		/// The last token of the collection is moved to the index of the removed one, so that the
+		/// index has no gaps.
+		fn remove_from_token_index(collection_id: CollectionId, token_id: TokenId) {
+			// tokens not indexed yet by the migration of the token index are not counted either
+			let Some(index) = TokenIndex::<T>::take(collection_id, token_id) else {
+				return;
+			};
+			let last_index = TokenCount::<T>::get(collection_id).saturating_sub(1);
+
+			if index != last_index {
+				if let Some(last_token_id) = TokenByIndex::<T>::get(collection_id, last_index) {
+					TokenByIndex::<T>::insert(collection_id, index, last_token_id);
+					TokenIndex::<T>::insert(collection_id, last_token_id, index);
+				}
+			}
+			TokenByIndex::<T>::remove(collection_id, last_index);
+			TokenCount::<T>::insert(collection_id, last_index);
+		}
+
+		/// Removes the scheduled evolution of the token, releasing its deposit, and returns
+		/// whether it had one
+		fn unschedule_evolution(collection_id: CollectionId, token_id: TokenId) -> bool {
+			match ScheduledEvolution::<T>::take(collection_id, token_id) {
+				Some((block, _)) => {
+					EvolutionAgenda::<T>::mutate(block, |agenda| {
+						agenda.retain(|scheduled| *scheduled != (collection_id, token_id))
+					});
+					Self::release_scheduled_evolution_deposit(collection_id, token_id);
+					true
+				},
+				None => false,
+			}
+		}
+
+		/// Releases the deposit of the scheduled evolution of the token to the account that
+		/// scheduled it
+		fn release_scheduled_evolution_deposit(collection_id: CollectionId, token_id: TokenId) {
+			if let Some((who, amount)) =
+				ScheduledEvolutionDeposit::<T>::take(collection_id, token_id)
+			{
+				T::Currency::unreserve(&who, amount);
+				Self::deposit_event(Event::DepositReleased { collection_id, who, amount });
+			}
+		}
+
+		/// Applies the evolutions scheduled at the beginning of block `now`
+		///
+		/// The number of evolutions is bounded by [`Config::MaxScheduledEvolutionsPerBlock`].
+		/// Evolutions that fail, including the ones of collections whose ownership has been
+		/// renounced, are skipped, emitting [`Event::ScheduledEvolutionFailed`]. Their deposit is
+		/// released in any case.
+		pub(crate) fn apply_scheduled_evolutions(now: BlockNumberFor<T>) -> Weight {
+			let agenda = EvolutionAgenda::<T>::take(now);
+			let weight = T::WeightInfo::apply_scheduled_evolutions(agenda.len() as u32)
+				.saturating_add(T::OnEvolve::weight().saturating_mul(agenda.len() as u64));
+
+			for (collection_id, token_id) in agenda {
+				let token_uri = match ScheduledEvolution::<T>::get(collection_id, token_id) {
+					Some((block, token_uri)) if block == now => token_uri,
+					_ => continue,
+				};
+				ScheduledEvolution::<T>::remove(collection_id, token_id);
+				Self::release_scheduled_evolution_deposit(collection_id, token_id);
+
+				let result = match CollectionOwner::<T>::get(collection_id) {
+					Some(owner) => with_storage_layer(|| {
+						<Self as EvolutionCollection<_, _>>::evolve_with_external_uri(
+							owner,
+							collection_id,
+							token_id,
+							token_uri,
+						)
+					}),
+					None => Err(Error::<T>::OwnershipRenounced.into()),
+				};
+				if let Err(error) = result {
+					log::warn!(
+						target: LOG_TARGET,
+						"scheduled evolution of token {:?} of collection {} failed: {:?}",
+						token_id,
+						collection_id,
+						error,
+					);
+					Self::deposit_event(Event::ScheduledEvolutionFailed {
+						collection_id,
+						token_id,
+						error,
+					});
+				}
+			}
+
+			weight
+		}
+
+		/// Registers as extra block weight the part of the weight consumed by a hook above its
+		/// upper bound, which is the only part not already charged by the call
+		fn register_hook_weight(consumed: Weight, bound: Weight) {
+			let excess = consumed.saturating_sub(bound);
+			if !excess.is_zero() {
+				frame_system::Pallet::<T>::register_extra_weight_unchecked(
+					excess,
+					DispatchClass::Normal,
+				);
+			}
+		}
+
+		/// Storage deposit of a token URI, proportional to its length
+		fn token_uri_deposit(token_uri: &TokenUriOf<T>) -> BalanceOf<T> {
+			T::DepositPerByte::get().saturating_mul((token_uri.len() as u32).into())
+		}
+
+		/// Deposit for an attribute, which covers its key and its value
+		fn attribute_deposit(value: &AttributeValueOf<T>) -> BalanceOf<T> {
+			T::DepositPerByte::get()
+				.saturating_mul(((H256::len_bytes() + value.len()) as u32).into())
+		}
+
+		/// Reserves `amount` from the owner of the collection and adds it to its deposit
+		fn reserve_deposit(collection_id: CollectionId, amount: BalanceOf<T>) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+
+			let who = CollectionOwner::<T>::get(collection_id)
+				.ok_or(Error::<T>::CollectionDoesNotExist)?;
+			T::Currency::reserve(&who, amount)?;
+			CollectionDepositOf::<T>::mutate(collection_id, |deposit| {
+				*deposit = deposit.saturating_add(amount)
+			});
+
+			Self::deposit_event(Event::DepositReserved { collection_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Releases `amount` of the deposit of the collection to its owner
+		fn release_deposit(collection_id: CollectionId, amount: BalanceOf<T>) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+
+			let who = CollectionOwner::<T>::get(collection_id)
+				.ok_or(Error::<T>::CollectionDoesNotExist)?;
+			T::Currency::unreserve(&who, amount);
+			CollectionDepositOf::<T>::mutate(collection_id, |deposit| {
+				*deposit = deposit.saturating_sub(amount)
+			});
+
+			Self::deposit_event(Event::DepositReleased { collection_id, who, amount });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> EvolutionCollectionFactory<AccountIdOf<T>> for Pallet<T> {
+	fn create_collection(
+		who: AccountIdOf<T>,
+		owner: AccountIdOf<T>,
+	) -> Result<CollectionId, DispatchError> {
+		let collection_id = Self::collection_counter();
+
+		CollectionOwner::<T>::insert(collection_id, owner.clone());
+
+		// Attempt to increment the collection counter by 1. If this operation
+		// would result in an overflow, return early with an error
+		let counter = collection_id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+		CollectionCounter::<T>::put(counter);
+
+		// the deposit of the collection is paid by its creator, which is not always its owner
+		let amount = T::CollectionDeposit::get();
+		if !amount.is_zero() {
+			T::Currency::reserve(&who, amount)?;
+			CreationDepositOf::<T>::insert(collection_id, (who.clone(), amount));
+			Self::deposit_event(Event::DepositReserved { collection_id, who, amount });
+		}
+
+		T::OnCreateCollection::on_create_collection(collection_id_to_address(collection_id));
+
+		// Emit an event.
+		Self::deposit_event(Event::CollectionCreated { collection_id, owner });
+
+		Ok(collection_id)
+	}
+
+	fn create_collection_with_max_supply(
+		who: AccountIdOf<T>,
+		owner: AccountIdOf<T>,
+		max_supply: u64,
+	) -> Result<CollectionId, DispatchError> {
+		let collection_id = <Self as EvolutionCollectionFactory<_>>::create_collection(who, owner)?;
+
+		MaxSupply::<T>::insert(collection_id, max_supply);
+
+		Ok(collection_id)
+	}
+}
+
+impl<T: Config> EvolutionCollectionMetadata<AccountIdOf<T>, CollectionMetadata<T>> for Pallet<T> {
+	fn create_collection_with_metadata(
+		who: AccountIdOf<T>,
+		owner: AccountIdOf<T>,
+		metadata: CollectionMetadata<T>,
+	) -> Result<CollectionId, DispatchError> {
+		let collection_id = <Self as EvolutionCollectionFactory<_>>::create_collection(who, owner)?;
+
+		CollectionMetadataOf::<T>::insert(collection_id, metadata);
+
+		Ok(collection_id)
+	}
+
+	fn create_collection_with_metadata_and_max_supply(
+		who: AccountIdOf<T>,
+		owner: AccountIdOf<T>,
+		metadata: CollectionMetadata<T>,
+		max_supply: u64,
+	) -> Result<CollectionId, DispatchError> {
+		let collection_id =
+			<Self as EvolutionCollectionFactory<_>>::create_collection_with_max_supply(
+				who, owner, max_supply,
+			)?;
+
+		CollectionMetadataOf::<T>::insert(collection_id, metadata);
+
+		Ok(collection_id)
+	}
+
+	fn set_collection_metadata(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		metadata: CollectionMetadata<T>,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+
+		CollectionMetadataOf::<T>::insert(collection_id, metadata);
+
+		Self::deposit_event(Event::CollectionMetadataUpdated { collection_id });
+
+		Ok(())
+	}
+
+	fn collection_metadata(collection_id: CollectionId) -> Option<CollectionMetadata<T>> {
+		CollectionMetadataOf::<T>::get(collection_id)
+	}
+}
+
+impl<T: Config> EvolutionCollectionAttributes<AccountIdOf<T>, AttributeValueOf<T>> for Pallet<T> {
+	fn set_attribute(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		key: H256,
+		value: AttributeValueOf<T>,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+		ensure!(
+			TokenURI::<T>::contains_key(collection_id, token_id),
+			Error::<T>::AssetDoesNotExist
+		);
+		ensure!(
+			!<Self as EvolutionCollectionFreezing<_>>::is_frozen(collection_id, token_id),
+			Error::<T>::TokenFrozen
+		);
+
+		let deposit = Self::attribute_deposit(&value);
+		match TokenAttribute::<T>::get((collection_id, token_id, key)) {
+			Some(previous_value) => {
+				let previous_deposit = Self::attribute_deposit(&previous_value);
+				if deposit > previous_deposit {
+					Self::reserve_deposit(collection_id, deposit - previous_deposit)?;
+				} else {
+					Self::release_deposit(collection_id, previous_deposit - deposit)?;
+				}
+			},
+			None => {
+				let count = AttributeCount::<T>::get(collection_id, token_id);
+				ensure!(count < T::MaxAttributesPerToken::get(), Error::<T>::TooManyAttributes);
+				Self::reserve_deposit(collection_id, deposit)?;
+				AttributeCount::<T>::insert(collection_id, token_id, count + 1);
+			},
+		}
+
+		TokenAttribute::<T>::insert((collection_id, token_id, key), value.clone());
+
+		Self::deposit_event(Event::AttributeSet { collection_id, token_id, key, value });
+
+		Ok(())
+	}
+
+	fn remove_attribute(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		key: H256,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+		ensure!(
+			!<Self as EvolutionCollectionFreezing<_>>::is_frozen(collection_id, token_id),
+			Error::<T>::TokenFrozen
+		);
+
+		let value = TokenAttribute::<T>::take((collection_id, token_id, key))
+			.ok_or(Error::<T>::AttributeDoesNotExist)?;
+		AttributeCount::<T>::mutate(collection_id, token_id, |count| {
+			*count = count.saturating_sub(1)
+		});
+		Self::release_deposit(collection_id, Self::attribute_deposit(&value))?;
+
+		Self::deposit_event(Event::AttributeRemoved { collection_id, token_id, key });
+
+		Ok(())
+	}
+
+	fn attribute(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		key: H256,
+	) -> Option<AttributeValueOf<T>> {
+		TokenAttribute::<T>::get((collection_id, token_id, key))
+	}
+}
+
+impl<T: Config> EvolutionCollection<AccountIdOf<T>, TokenUriOf<T>> for Pallet<T> {
+	fn mint_with_external_uri(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		slot: Slot,
+		to: AccountIdOf<T>,
+		token_uri: TokenUriOf<T>,
+	) -> Result<TokenId, DispatchError> {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(
+			Self::is_owner_or_has_role(collection_id, who, Role::Minter),
+			Error::<T>::NoPermission
+		);
+
+		let to_as_h160 = T::AccountIdToH160::convert(to.clone());
+		// compose asset_id	from slot and owner
+		let token_id = slot_and_owner_to_token_id(slot, to_as_h160);
+
+		ensure!(TokenURI::<T>::get(collection_id, token_id).is_none(), Error::<T>::AlreadyMinted);
+
+		// burned tokens keep counting towards the max supply
+		let minted_count = MintedCount::<T>::get(collection_id);
+		if let Some(max_supply) = MaxSupply::<T>::get(collection_id) {
+			ensure!(minted_count < max_supply, Error::<T>::MaxSupplyReached);
+		}
+		let minted_count = minted_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+		// the new token goes right after the last one in the index
+		let index = TokenCount::<T>::get(collection_id);
+		let token_count = index.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+		let deposit = Self::token_uri_deposit(&token_uri);
+		Self::reserve_deposit(collection_id, deposit)?;
+
+		TokenURI::<T>::insert(collection_id, token_id, token_uri.clone());
+		TokenDeposit::<T>::insert(collection_id, token_id, deposit);
+		TokenCount::<T>::insert(collection_id, token_count);
+		MintedCount::<T>::insert(collection_id, minted_count);
+		TokenByIndex::<T>::insert(collection_id, index, token_id);
+		TokenIndex::<T>::insert(collection_id, token_id, index);
+		// `mint_next` keeps minting past every slot of the owner, so that it never collides
+		if slot >= NextSlot::<T>::get(collection_id, &to) {
+			// past the maximum slot, the next slot stays minted and `mint_next` is exhausted
+			let next_slot = Slot::try_from(u128::from(slot) + 1).unwrap_or(Slot::MAX_SLOT);
+			NextSlot::<T>::insert(collection_id, &to, next_slot);
+		}
+
+		Self::register_hook_weight(
+			T::OnMint::on_mint(collection_id, token_id, &to),
+			T::OnMint::weight(),
+		);
+
+		Self::deposit_event(Event::MintedWithExternalURI {
+			collection_id,
+			slot,
+			to,
+			token_id,
+			token_uri,
+		});
+
+		Ok(token_id)
+	}
+
+	fn collection_owner(collection_id: CollectionId) -> Option<AccountIdOf<T>> {
+		CollectionOwner::<T>::get(collection_id)
+	}
+
+	fn token_uri(collection_id: CollectionId, token_id: TokenId) -> Option<TokenUriOf<T>> {
+		Self::token_uri(collection_id, token_id)
+	}
+
+	fn evolve_with_external_uri(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		token_uri: TokenUriOf<T>,
+	) -> Result<(), DispatchError> {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(
+			Self::is_owner_or_has_role(collection_id, who, Role::Evolver),
+			Error::<T>::NoPermission
+		);
+		let previous_token_uri =
+			TokenURI::<T>::get(collection_id, token_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+		ensure!(
+			!<Self as EvolutionCollectionFreezing<_>>::is_frozen(collection_id, token_id),
+			Error::<T>::TokenFrozen
+		);
+		if EvolutionConsentRequired::<T>::contains_key(collection_id) {
+			ensure!(
+				EvolutionApproval::<T>::take(collection_id, token_id) ==
+					Some(H256(keccak_256(&token_uri))),
+				Error::<T>::EvolutionNotApproved
+			);
+		}
+
+		let version = TokenURIVersion::<T>::get(collection_id, token_id);
+		let new_version = version.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+
+		// the deposit of the token URI that leaves the storage: the pruned version of the
+		// history, or the previous token URI when no history is kept
+		let max_history = T::MaxUriHistory::get();
+		let removed_deposit = if max_history > 0 {
+			// prune the oldest version so that at most `max_history` versions are kept
+			let pruned_token_uri = version.checked_sub(max_history).and_then(|pruned_version| {
+				TokenURIHistory::<T>::take((collection_id, token_id, pruned_version))
+			});
+			TokenURIHistory::<T>::insert((collection_id, token_id, version), previous_token_uri);
+			pruned_token_uri.map_or_else(Zero::zero, |uri| Self::token_uri_deposit(&uri))
+		} else {
+			Self::token_uri_deposit(&previous_token_uri)
+		};
+		// tokens minted before the deposits were introduced have less deposit recorded than the
+		// length of their token URIs, and never more than the recorded deposit is released
+		let recorded_deposit = TokenDeposit::<T>::get(collection_id, token_id);
+		let removed_deposit = removed_deposit.min(recorded_deposit);
+
+		// only the difference with the deposit that leaves the storage is reserved or released
+		let added_deposit = Self::token_uri_deposit(&token_uri);
+		if added_deposit > removed_deposit {
+			Self::reserve_deposit(collection_id, added_deposit.saturating_sub(removed_deposit))?;
+		} else {
+			Self::release_deposit(collection_id, removed_deposit.saturating_sub(added_deposit))?;
+		}
+		let deposit =
+			recorded_deposit.saturating_add(added_deposit).saturating_sub(removed_deposit);
+
+		TokenURIVersion::<T>::insert(collection_id, token_id, new_version);
+		TokenURI::<T>::insert(collection_id, token_id, token_uri.clone());
+		TokenDeposit::<T>::insert(collection_id, token_id, deposit);
+
+		Self::register_hook_weight(
+			T::OnEvolve::on_evolve(collection_id, token_id, &token_uri),
+			T::OnEvolve::weight(),
+		);
+
+		Self::deposit_event(Event::EvolvedWithExternalURI { collection_id, token_id, token_uri });
+
+		Ok(())
+	}
+
+	fn transfer_ownership(
+		from: AccountIdOf<T>,
+		to: AccountIdOf<T>,
+		collection_id: CollectionId,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, from.clone()), Error::<T>::NoPermission);
+
+		PendingOwner::<T>::insert(collection_id, to.clone());
+
+		Self::deposit_event(Event::OwnershipTransferStarted { collection_id, from, to });
+
+		Ok(())
+	}
+}
+
+impl<T: Config> EvolutionCollectionOwnership<AccountIdOf<T>> for Pallet<T> {
+	fn accept_ownership(who: AccountIdOf<T>, collection_id: CollectionId) -> DispatchResult {
+		ensure!(
+			PendingOwner::<T>::get(collection_id) == Some(who.clone()),
+			Error::<T>::NotPendingOwner
+		);
+
+		let from =
+			CollectionOwner::<T>::get(collection_id).ok_or(Error::<T>::CollectionDoesNotExist)?;
+
+		// the deposit of the collection moves from the previous owner to the new one
+		let deposit = CollectionDepositOf::<T>::get(collection_id);
+		if !deposit.is_zero() {
+			T::Currency::reserve(&who, deposit)?;
+			T::Currency::unreserve(&from, deposit);
+			Self::deposit_event(Event::DepositReleased {
+				collection_id,
+				who: from.clone(),
+				amount: deposit,
+			});
+			Self::deposit_event(Event::DepositReserved {
+				collection_id,
+				who: who.clone(),
+				amount: deposit,
+			});
+		}
+
+		CollectionOwner::<T>::insert(collection_id, who.clone());
+		PendingOwner::<T>::remove(collection_id);
+
+		Self::register_hook_weight(
+			T::OnOwnershipTransferred::on_ownership_transferred(collection_id, &from, Some(&who)),
+			T::OnOwnershipTransferred::weight(),
+		);
+
+		Self::deposit_event(Event::CollectionTransferred { collection_id, from, to: who });
+
+		Ok(())
+	}
+
+	fn cancel_ownership_transfer(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+
+		let pending_owner =
+			PendingOwner::<T>::take(collection_id).ok_or(Error::<T>::NoPendingOwnershipTransfer)?;
+
+		Self::deposit_event(Event::OwnershipTransferCanceled { collection_id, pending_owner });
+
+		Ok(())
+	}
+
+	fn pending_owner(collection_id: CollectionId) -> Option<AccountIdOf<T>> {
+		PendingOwner::<T>::get(collection_id)
+	}
+
+	fn renounce_ownership(who: AccountIdOf<T>, collection_id: CollectionId) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who.clone()), Error::<T>::NoPermission);
+
+		CollectionOwner::<T>::remove(collection_id);
+		PendingOwner::<T>::remove(collection_id);
+		// drops are minted on behalf of the owner, so they cannot be claimed anymore
+		DropRoot::<T>::remove(collection_id);
+
+		if let Some((creator, amount)) = CreationDepositOf::<T>::take(collection_id) {
+			T::Currency::unreserve(&creator, amount);
+			Self::deposit_event(Event::DepositReleased { collection_id, who: creator, amount });
+		}
+
+		Self::register_hook_weight(
+			T::OnOwnershipTransferred::on_ownership_transferred(collection_id, &who, None),
+			T::OnOwnershipTransferred::weight(),
+		);
+
+		Self::deposit_event(Event::OwnershipRenounced { collection_id, owner: who });
+
+		Ok(())
+	}
+}
+
+impl<T: Config> EvolutionCollectionRoles<AccountIdOf<T>> for Pallet<T> {
+	fn grant_role(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		role: Role,
+		account: AccountIdOf<T>,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+
+		if !CollectionRole::<T>::contains_key((collection_id, role, account.clone())) {
+			CollectionRole::<T>::insert((collection_id, role, account.clone()), ());
+
+			Self::deposit_event(Event::RoleGranted { collection_id, role, account });
+		}
+
+		Ok(())
+	}
+
+	fn revoke_role(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		role: Role,
+		account: AccountIdOf<T>,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+
+		if CollectionRole::<T>::take((collection_id, role, account.clone())).is_some() {
+			Self::deposit_event(Event::RoleRevoked { collection_id, role, account });
+		}
+
+		Ok(())
+	}
+
+	fn has_role(collection_id: CollectionId, role: Role, account: AccountIdOf<T>) -> bool {
+		CollectionRole::<T>::contains_key((collection_id, role, account))
+	}
+}
+
+impl<T: Config> EvolutionCollectionBurn<AccountIdOf<T>> for Pallet<T> {
+	fn burn(who: AccountIdOf<T>, collection_id: CollectionId, token_id: TokenId) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+		ensure!(
+			TokenURI::<T>::contains_key(collection_id, token_id),
+			Error::<T>::AssetDoesNotExist
+		);
+		// otherwise the token could be minted again with another token URI
+		ensure!(
+			!<Self as EvolutionCollectionFreezing<_>>::is_frozen(collection_id, token_id),
+			Error::<T>::TokenFrozen
+		);
+
+		let attributes_deposit = TokenAttribute::<T>::drain_prefix((collection_id, token_id))
+			.fold(BalanceOf::<T>::zero(), |deposit, (_, value)| {
+				deposit.saturating_add(Self::attribute_deposit(&value))
+			});
+		AttributeCount::<T>::remove(collection_id, token_id);
+		Self::release_deposit(
+			collection_id,
+			TokenDeposit::<T>::take(collection_id, token_id).saturating_add(attributes_deposit),
+		)?;
+
+		TokenURI::<T>::remove(collection_id, token_id);
+		// the deposit of the history is part of the deposit of the token released above
+		TokenURIVersion::<T>::remove(collection_id, token_id);
+		let _ = TokenURIHistory::<T>::clear_prefix(
+			(collection_id, token_id),
+			T::MaxUriHistory::get(),
+			None,
+		);
+		Self::unschedule_evolution(collection_id, token_id);
+		EvolutionApproval::<T>::remove(collection_id, token_id);
+		TokenRoyalty::<T>::remove(collection_id, token_id);
+		Self::remove_from_token_index(collection_id, token_id);
+
+		Self::deposit_event(Event::Burned { collection_id, token_id });
+
+		Ok(())
+	}
+}
+
+impl<T: Config> EvolutionCollectionFreezing<AccountIdOf<T>> for Pallet<T> {
+	fn freeze_token(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+		let token_uri =
+			TokenURI::<T>::get(collection_id, token_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+
+		// a token resolved through the base URI keeps its resolved token URI from now on
+		if token_uri.is_empty() {
+			let token_uri = Self::resolve_token_uri(collection_id, token_id, token_uri);
+			let deposit = Self::token_uri_deposit(&token_uri);
+			Self::reserve_deposit(collection_id, deposit)?;
+
+			TokenURI::<T>::insert(collection_id, token_id, token_uri);
+			TokenDeposit::<T>::mutate(collection_id, token_id, |token_deposit| {
+				*token_deposit = token_deposit.saturating_add(deposit)
+			});
+		}
+
+		FrozenToken::<T>::insert(collection_id, token_id, ());
+
+		Self::deposit_event(Event::TokenFrozen { collection_id, token_id });
+
+		Ok(())
+	}
+
+	fn freeze_collection(who: AccountIdOf<T>, collection_id: CollectionId) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+
+		FrozenCollection::<T>::insert(collection_id, ());
+
+		Self::deposit_event(Event::CollectionFrozen { collection_id });
+
+		Ok(())
+	}
+
+	fn is_frozen(collection_id: CollectionId, token_id: TokenId) -> bool {
+		FrozenCollection::<T>::contains_key(collection_id) ||
+			FrozenToken::<T>::contains_key(collection_id, token_id)
+	}
+}
+
+impl<T: Config> EvolutionCollectionBatch<AccountIdOf<T>, TokenUriOf<T>> for Pallet<T> {
+	fn batch_mint_with_external_uri(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		mints: Vec<(Slot, AccountIdOf<T>, TokenUriOf<T>)>,
+	) -> Result<Vec<TokenId>, DispatchError> {
+		with_storage_layer(|| {
+			mints
+				.into_iter()
+				.map(|(slot, to, token_uri)| {
+					<Self as EvolutionCollection<_, _>>::mint_with_external_uri(
+						who.clone(),
+						collection_id,
+						slot,
+						to,
+						token_uri,
+					)
+				})
+				.collect()
+		})
+	}
+
+	fn batch_evolve_with_external_uri(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		evolutions: Vec<(TokenId, TokenUriOf<T>)>,
+	) -> DispatchResult {
+		with_storage_layer(|| {
+			evolutions.into_iter().try_for_each(|(token_id, token_uri)| {
+				<Self as EvolutionCollection<_, _>>::evolve_with_external_uri(
+					who.clone(),
+					collection_id,
+					token_id,
+					token_uri,
+				)
+			})
+		})
+	}
+}
+
+impl<T: Config> EvolutionCollectionRoyalties<AccountIdOf<T>> for Pallet<T> {
+	fn set_default_royalty(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		receiver: AccountIdOf<T>,
+		basis_points: BasisPoints,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+		ensure!(basis_points <= MAX_ROYALTY_BASIS_POINTS, Error::<T>::RoyaltyTooHigh);
+
+		DefaultRoyalty::<T>::insert(
+			collection_id,
+			RoyaltyInfo { receiver: receiver.clone(), basis_points },
+		);
+
+		Self::deposit_event(Event::DefaultRoyaltySet { collection_id, receiver, basis_points });
+
+		Ok(())
+	}
+
+	fn set_token_royalty(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		receiver: AccountIdOf<T>,
+		basis_points: BasisPoints,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+		ensure!(
+			TokenURI::<T>::contains_key(collection_id, token_id),
+			Error::<T>::AssetDoesNotExist
+		);
+		ensure!(basis_points <= MAX_ROYALTY_BASIS_POINTS, Error::<T>::RoyaltyTooHigh);
+
+		TokenRoyalty::<T>::insert(
+			collection_id,
+			token_id,
+			RoyaltyInfo { receiver: receiver.clone(), basis_points },
+		);
+
+		Self::deposit_event(Event::TokenRoyaltySet {
+			collection_id,
+			token_id,
+			receiver,
+			basis_points,
+		});
+
+		Ok(())
+	}
+
+	fn royalty_info(
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> Option<RoyaltyInfo<AccountIdOf<T>>> {
+		TokenRoyalty::<T>::get(collection_id, token_id)
+			.or_else(|| DefaultRoyalty::<T>::get(collection_id))
+	}
+}
+
+impl<T: Config> EvolutionCollectionHistory<TokenUriOf<T>> for Pallet<T> {
+	fn token_uri_version(collection_id: CollectionId, token_id: TokenId) -> u32 {
+		TokenURIVersion::<T>::get(collection_id, token_id)
+	}
+
+	fn token_uri_at(
 		collection_id: CollectionId,
 		token_id: TokenId,
+		version: u32,
+	) -> Option<TokenUriOf<T>> {
+		if version == TokenURIVersion::<T>::get(collection_id, token_id) {
+			Self::token_uri(collection_id, token_id)
+		} else {
+			TokenURIHistory::<T>::get((collection_id, token_id, version))
+		}
+	}
+}
+
+impl<T: Config> EvolutionCollectionNonces<AccountIdOf<T>, TokenUriOf<T>> for Pallet<T> {
+	fn mint_with_nonce(
+		signer: AccountIdOf<T>,
+		collection_id: CollectionId,
+		slot: Slot,
+		to: AccountIdOf<T>,
 		token_uri: TokenUriOf<T>,
-	) -> Result<(), DispatchError> {
+		nonce: U256,
+	) -> Result<TokenId, DispatchError> {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(
+			Self::is_owner_or_has_role(collection_id, signer.clone(), Role::Minter),
+			Error::<T>::NoPermission
+		);
+		ensure!(
+			!UsedNonces::<T>::contains_key((collection_id, &signer, nonce)),
+			Error::<T>::MintNonceAlreadyUsed
+		);
+
+		let token_id = <Self as EvolutionCollection<_, _>>::mint_with_external_uri(
+			signer.clone(),
+			collection_id,
+			slot,
+			to,
+			token_uri,
+		)?;
+		UsedNonces::<T>::insert((collection_id, signer, nonce), ());
+
+		Ok(token_id)
+	}
+
+	fn is_mint_nonce_used(
+		collection_id: CollectionId,
+		signer: AccountIdOf<T>,
+		nonce: U256,
+	) -> bool {
+		UsedNonces::<T>::contains_key((collection_id, signer, nonce))
+	}
+
+	fn invalidate_mint_nonce(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		nonce: U256,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
 		ensure!(
-			CollectionOwner::<T>::contains_key(collection_id),
-			Error::<T>::CollectionDoesNotExist
+			!UsedNonces::<T>::contains_key((collection_id, &who, nonce)),
+			Error::<T>::MintNonceAlreadyUsed
 		);
+
+		UsedNonces::<T>::insert((collection_id, &who, nonce), ());
+
+		Self::deposit_event(Event::MintNonceInvalidated { collection_id, signer: who, nonce });
+
+		Ok(())
+	}
+}
+
+impl<T: Config> EvolutionCollectionMintNext<AccountIdOf<T>, TokenUriOf<T>> for Pallet<T> {
+	fn mint_next(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		to: AccountIdOf<T>,
+		token_uri: TokenUriOf<T>,
+	) -> Result<(Slot, TokenId), DispatchError> {
+		// minting moves the next slot of the owner past the minted one
+		let slot = NextSlot::<T>::get(collection_id, &to);
+		let token_id = <Self as EvolutionCollection<_, _>>::mint_with_external_uri(
+			who,
+			collection_id,
+			slot,
+			to,
+			token_uri,
+		)
+		.map_err(|err| {
+			// the next slot is only minted once the maximum slot of the owner has been minted
+			if err == DispatchError::from(Error::<T>::AlreadyMinted) {
+				Error::<T>::SlotsExhausted.into()
+			} else {
+				err
+			}
+		})?;
+
+		Ok((slot, token_id))
+	}
+}
+
+impl<T: Config> EvolutionCollectionSupply for Pallet<T> {
+	fn token_count(collection_id: CollectionId) -> u64 {
+		TokenCount::<T>::get(collection_id)
+	}
+
+	fn max_supply(collection_id: CollectionId) -> Option<u64> {
+		MaxSupply::<T>::get(collection_id)
+	}
+
+	fn token_by_index(collection_id: CollectionId, index: u64) -> Option<TokenId> {
+		TokenByIndex::<T>::get(collection_id, index)
+	}
+}
+
+impl<T: Config> EvolutionCollectionBaseUri<AccountIdOf<T>, TokenUriOf<T>> for Pallet<T> {
+	fn set_base_uri(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		base_uri: TokenUriOf<T>,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
 		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+		ensure!(!FrozenCollection::<T>::contains_key(collection_id), Error::<T>::CollectionFrozen);
+
+		if base_uri.is_empty() {
+			BaseURI::<T>::remove(collection_id);
+		} else {
+			ensure!(
+				expand_base_uri(&base_uri, TokenId::zero()).len() <=
+					T::MaxTokenUriLength::get() as usize,
+				Error::<T>::BaseUriTooLong
+			);
+			BaseURI::<T>::insert(collection_id, base_uri.clone());
+		}
+
+		Self::deposit_event(Event::BaseURISet { collection_id, base_uri });
+
+		Ok(())
+	}
+
+	fn base_uri(collection_id: CollectionId) -> Option<TokenUriOf<T>> {
+		BaseURI::<T>::get(collection_id)
+	}
+}
+
+impl<T: Config> EvolutionCollectionScheduling<AccountIdOf<T>, TokenUriOf<T>> for Pallet<T> {
+	fn schedule_evolution(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		token_uri: TokenUriOf<T>,
+		block: u64,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(
+			Self::is_owner_or_has_role(collection_id, who.clone(), Role::Evolver),
+			Error::<T>::NoPermission
+		);
 		ensure!(
 			TokenURI::<T>::contains_key(collection_id, token_id),
 			Error::<T>::AssetDoesNotExist
 		);
+		ensure!(
+			!<Self as EvolutionCollectionFreezing<_>>::is_frozen(collection_id, token_id),
+			Error::<T>::TokenFrozen
+		);
+		let block: BlockNumberFor<T> = block.try_into().map_err(|_| Error::<T>::InvalidBlock)?;
+		ensure!(block > frame_system::Pallet::<T>::block_number(), Error::<T>::ScheduledInThePast);
 
-		TokenURI::<T>::insert(collection_id, token_id, token_uri.clone());
+		Self::unschedule_evolution(collection_id, token_id);
+		EvolutionAgenda::<T>::try_mutate(block, |agenda| {
+			agenda.try_push((collection_id, token_id))
+		})
+		.map_err(|_| Error::<T>::TooManyScheduledEvolutions)?;
 
-		Self::deposit_event(Event::EvolvedWithExternalURI { collection_id, token_id, token_uri });
+		let deposit = Self::token_uri_deposit(&token_uri);
+		if !deposit.is_zero() {
+			T::Currency::reserve(&who, deposit)?;
+			ScheduledEvolutionDeposit::<T>::insert(collection_id, token_id, (who.clone(), deposit));
+			Self::deposit_event(Event::DepositReserved { collection_id, who, amount: deposit });
+		}
+		ScheduledEvolution::<T>::insert(collection_id, token_id, (block, token_uri.clone()));
+
+		Self::deposit_event(Event::EvolutionScheduled {
+			collection_id,
+			token_id,
+			token_uri,
+			block,
+		});
 
 		Ok(())
 	}
 
-	fn transfer_ownership(
-		from: AccountIdOf<T>,
+	fn cancel_scheduled_evolution(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(
+			Self::is_owner_or_has_role(collection_id, who, Role::Evolver),
+			Error::<T>::NoPermission
+		);
+		ensure!(
+			Self::unschedule_evolution(collection_id, token_id),
+			Error::<T>::NoScheduledEvolution
+		);
+
+		Self::deposit_event(Event::ScheduledEvolutionCanceled { collection_id, token_id });
+
+		Ok(())
+	}
+}
+
+impl<T: Config> EvolutionCollectionDrop<AccountIdOf<T>, TokenUriOf<T>> for Pallet<T> {
+	fn set_drop_root(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		root: H256,
+	) -> DispatchResult {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
+
+		DropRoot::<T>::insert(collection_id, root);
+
+		Self::deposit_event(Event::DropRootSet { collection_id, root });
+
+		Ok(())
+	}
+
+	fn drop_root(collection_id: CollectionId) -> Option<H256> {
+		DropRoot::<T>::get(collection_id)
+	}
+
+	fn claim_from_drop(
+		collection_id: CollectionId,
+		proof: Vec<H256>,
+		slot: Slot,
 		to: AccountIdOf<T>,
+		token_uri: TokenUriOf<T>,
+	) -> Result<TokenId, DispatchError> {
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		// the drop is minted on behalf of the owner that committed its root
+		let owner =
+			CollectionOwner::<T>::get(collection_id).ok_or(Error::<T>::OwnershipRenounced)?;
+		let root = DropRoot::<T>::get(collection_id).ok_or(Error::<T>::DropDoesNotExist)?;
+		let leaf = drop_leaf(slot, T::AccountIdToH160::convert(to.clone()), &token_uri);
+		ensure!(
+			!ClaimedDropLeaf::<T>::contains_key(collection_id, leaf),
+			Error::<T>::AlreadyClaimed
+		);
+		ensure!(merkle_root(leaf, &proof) == root, Error::<T>::InvalidMerkleProof);
+
+		let token_id = <Self as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			slot,
+			to,
+			token_uri,
+		)?;
+		ClaimedDropLeaf::<T>::insert(collection_id, leaf, ());
+
+		Ok(token_id)
+	}
+}
+
+impl<T: Config> EvolutionCollectionConsent<AccountIdOf<T>> for Pallet<T> {
+	fn set_evolution_consent_required(
+		who: AccountIdOf<T>,
 		collection_id: CollectionId,
+		required: bool,
 	) -> DispatchResult {
-		CollectionOwner::<T>::mutate(collection_id, |owner| -> DispatchResult {
-			ensure!(owner.is_some(), Error::<T>::CollectionDoesNotExist);
-			ensure!(owner.clone() == Some(from.clone()), Error::<T>::NoPermission);
+		ensure!(Self::collection_exists(collection_id), Error::<T>::CollectionDoesNotExist);
+		ensure!(Self::is_owner(collection_id, who), Error::<T>::NoPermission);
 
-			*owner = Some(to.clone());
+		if required {
+			EvolutionConsentRequired::<T>::insert(collection_id, ());
+		} else {
+			EvolutionConsentRequired::<T>::remove(collection_id);
+		}
 
-			Self::deposit_event(Event::CollectionTransferred { collection_id, from, to });
+		Self::deposit_event(Event::EvolutionConsentRequiredSet { collection_id, required });
 
-			Ok(())
-		})
+		Ok(())
+	}
+
+	fn evolution_consent_required(collection_id: CollectionId) -> bool {
+		EvolutionConsentRequired::<T>::contains_key(collection_id)
+	}
+
+	fn approve_evolution(
+		who: AccountIdOf<T>,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		uri_hash: H256,
+	) -> DispatchResult {
+		ensure!(
+			T::AccountIdToH160::convert(who) == token_id_to_initial_owner(token_id),
+			Error::<T>::NotInitialOwner
+		);
+		ensure!(
+			TokenURI::<T>::contains_key(collection_id, token_id),
+			Error::<T>::AssetDoesNotExist
+		);
+		ensure!(
+			EvolutionConsentRequired::<T>::contains_key(collection_id),
+			Error::<T>::EvolutionConsentNotRequired
+		);
+
+		EvolutionApproval::<T>::insert(collection_id, token_id, uri_hash);
+
+		Self::deposit_event(Event::EvolutionApproved { collection_id, token_id, uri_hash });
+
+		Ok(())
 	}
 }
 
+/// Computes the leaf of a drop that mints `slot` to `to` with `token_uri`
+///
+/// Leaves are `keccak256(keccak256(abi.encode(slot, to, tokenURI)))`, like those of the standard
+/// Merkle trees of OpenZeppelin, so that drops can be built with their tooling.
+fn drop_leaf(slot: Slot, to: H160, token_uri: &[u8]) -> H256 {
+	let encoded = solidity::encode_arguments((
+		slot,
+		Address(to),
+		solidity::codec::UnboundedString::from(token_uri),
+	));
+
+	H256(keccak_256(&keccak_256(&encoded)))
+}
+
+/// Computes the root of a Merkle tree from one of its leaves and the proof of the leaf
+///
+/// Pairs of nodes are sorted before being hashed, so the proof does not need to tell the position
+/// of the nodes.
+fn merkle_root(leaf: H256, proof: &[H256]) -> H256 {
+	proof.iter().fold(leaf, |node, sibling| {
+		let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+		H256(keccak_256(&[left.as_bytes(), right.as_bytes()].concat()))
+	})
+}
+
 /// Converts `Slot` and `H160` to `TokenId`
 ///
 /// Every slot is identified by a unique `token_id` where `token_id = concat(slot #,
@@ -276,6 +2534,62 @@ fn slot_and_owner_to_token_id(slot: Slot, owner: H160) -> TokenId {
 	TokenId::from(bytes)
 }
 
+/// Replaces every `{id}` in `base_uri` by `token_id` as 64 lowercase hex digits without prefix,
+/// like ERC-1155 clients do
+fn expand_base_uri(base_uri: &[u8], token_id: TokenId) -> Vec<u8> {
+	const PLACEHOLDER: &[u8] = b"{id}";
+	const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+	let mut bytes = [0u8; 32];
+	token_id.to_big_endian(&mut bytes);
+	let id: Vec<u8> = bytes
+		.iter()
+		.flat_map(|byte| [HEX_DIGITS[(byte >> 4) as usize], HEX_DIGITS[(byte & 0x0f) as usize]])
+		.collect();
+
+	let mut token_uri = Vec::with_capacity(base_uri.len());
+	let mut rest = base_uri;
+	while !rest.is_empty() {
+		if rest.starts_with(PLACEHOLDER) {
+			token_uri.extend_from_slice(&id);
+			rest = &rest[PLACEHOLDER.len()..];
+		} else {
+			token_uri.push(rest[0]);
+			rest = &rest[1..];
+		}
+	}
+	token_uri
+}
+
+/// Converts `TokenId` to the initial owner `H160`
+///
+/// This is the inverse of the owner part of [`slot_and_owner_to_token_id`]: the initial owner is
+/// stored in the last 20 bytes of the `token_id`
+///
+/// Returns `H160`
+fn token_id_to_initial_owner(token_id: TokenId) -> H160 {
+	let mut bytes = [0u8; 32];
+	token_id.to_big_endian(&mut bytes);
+
+	H160::from_slice(&bytes[12..])
+}
+
+/// Converts `TokenId` to its `Slot`
+///
+/// This is the inverse of the slot part of [`slot_and_owner_to_token_id`]: the slot is stored in
+/// the first 12 bytes of the `token_id`
+///
+/// Returns `Slot`
+fn token_id_to_slot(token_id: TokenId) -> Slot {
+	let mut bytes = [0u8; 32];
+	token_id.to_big_endian(&mut bytes);
+
+	let mut slot_bytes = [0u8; 12];
+	slot_bytes.copy_from_slice(&bytes[..12]);
+
+	Slot::new(slot_bytes)
+}
+
 /// `ASSET_PRECOMPILE_ADDRESS_PREFIX` is a predefined prefix used to identify collection addresses.
 ///
 /// All addresses that start with this prefix are considered as collection addresses.
@@ -0,0 +1,217 @@
+// Copyright 2023-2024 Freeverse.io
+// This file is part of LAOS.
+
+// LAOS is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// LAOS is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with LAOS.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the pallet
+use crate::{Config, Pallet};
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+pub mod v1 {
+	use super::*;
+	use crate::{TokenByIndex, TokenCount, TokenIndex, TokenIndexMigrationCursor, TokenURI};
+	use frame_support::{
+		pallet_prelude::*, storage::StoragePrefixedMap, traits::StorageInfoTrait, weights::Weight,
+	};
+	use sp_std::vec::Vec;
+
+	/// Starts the lazy migration that builds the enumerable index of the tokens of every
+	/// collection
+	///
+	/// Only the first token is indexed by the upgrade, the rest are indexed by
+	/// [`migrate_token_index`] in the idle time of the following blocks, so that the upgrade does
+	/// not iterate over every token in a single block. The pallet stays at storage version 1 until
+	/// every token has been indexed, and then it is moved to storage version 2.
+	pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let tokens = index_tokens_after::<T>(TokenURI::<T>::final_prefix().to_vec(), 1);
+
+			T::DbWeight::get()
+				.writes(1)
+				.saturating_add(weight_per_token::<T>().saturating_mul(tokens))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let tokens = TokenURI::<T>::iter_keys().count() as u64;
+
+			Ok(tokens.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let tokens = u64::decode(&mut &state[..])
+				.map_err(|_| "the state of the pre upgrade can not be decoded")?;
+
+			// idle time is not given to the blocks by try-runtime, so the rest of the tokens are
+			// indexed here to check the resulting index
+			while Pallet::<T>::on_chain_storage_version() == 1 {
+				migrate_token_index::<T>(Weight::MAX);
+			}
+
+			ensure!(
+				TokenIndex::<T>::iter_keys().count() as u64 == tokens,
+				"every token must be indexed"
+			);
+			ensure!(
+				TokenCount::<T>::iter_values().sum::<u64>() == tokens,
+				"the token counts must add up to the number of tokens"
+			);
+			ensure!(
+				TokenByIndex::<T>::iter_keys().count() as u64 == tokens,
+				"every index must map to a token"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// Proof size accounted by the benchmarks for every node of the trie proving a storage item
+	const TRIE_NODE_PROOF_SIZE: u64 = 15 * 33;
+
+	/// Number of items assumed by the benchmarks for the storage maps without a bound on their
+	/// items, which is the default `--worst-case-map-values` of `benchmark pallet`
+	const WORST_CASE_MAP_VALUES: u32 = 1_000_000;
+
+	/// Maximum proof size of reading an item of encoded size `max_size` from a storage with up to
+	/// `max_values` items, as accounted by the benchmarks
+	fn item_proof_size(max_size: Option<u32>, max_values: Option<u32>) -> u64 {
+		let values = max_values.unwrap_or(WORST_CASE_MAP_VALUES);
+		// the depth of the trie is the base 16 logarithm of the items, rounded up
+		let depth = values.saturating_sub(1).checked_ilog(16).map_or(1, |depth| depth + 1);
+
+		u64::from(max_size.unwrap_or_default())
+			.saturating_add(TRIE_NODE_PROOF_SIZE.saturating_mul(depth.into()))
+	}
+
+	/// Maximum proof size of reading an item of the storage `S`
+	fn max_proof_size<S: StorageInfoTrait>() -> u64 {
+		S::storage_info()
+			.iter()
+			.map(|info| item_proof_size(info.max_size, info.max_values))
+			.sum()
+	}
+
+	/// Weight of reading the storage version of the pallet
+	fn version_read_weight<T: Config>() -> Weight {
+		let max_size = StorageVersion::max_encoded_len().try_into().unwrap_or(u32::MAX);
+
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(Weight::from_parts(0, item_proof_size(Some(max_size), Some(1))))
+	}
+
+	/// Weight of reading the cursor of the migration
+	fn cursor_read_weight<T: Config>() -> Weight {
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(Weight::from_parts(0, max_proof_size::<TokenIndexMigrationCursor<T>>()))
+	}
+
+	/// Weight of indexing a single token
+	fn weight_per_token<T: Config>() -> Weight {
+		// the token URI, the index of the token and the token count of its collection are read
+		let proof_size = max_proof_size::<TokenURI<T>>()
+			.saturating_add(max_proof_size::<TokenIndex<T>>())
+			.saturating_add(max_proof_size::<TokenCount<T>>());
+
+		T::DbWeight::get()
+			.reads_writes(3, 3)
+			.saturating_add(Weight::from_parts(0, proof_size))
+	}
+
+	/// Indexes as many tokens as fit in `limit`, returning the weight consumed
+	///
+	/// Nothing but the storage version is read unless the pallet is at storage version 1, which
+	/// is the version of the pallet while the migration is in progress. The pallet is moved to
+	/// storage version 2 once every token has been indexed.
+	///
+	/// Both the ref time and the proof size of `limit` bound the number of tokens indexed.
+	pub fn migrate_token_index<T: Config>(limit: Weight) -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 1 {
+			return version_read_weight::<T>();
+		}
+		let read_weight = version_read_weight::<T>().saturating_add(cursor_read_weight::<T>());
+		if !TokenIndexMigrationCursor::<T>::exists() {
+			StorageVersion::new(2).put::<Pallet<T>>();
+			return read_weight.saturating_add(T::DbWeight::get().writes(1));
+		}
+
+		// the cursor and the storage version are written
+		let base_weight = read_weight.saturating_add(T::DbWeight::get().writes(2));
+		// a component of the weight of a token that is zero does not bound the number of tokens
+		let max_tokens = limit.checked_sub(&base_weight).map_or(0, |weight| {
+			weight.checked_div_per_component(&weight_per_token::<T>()).unwrap_or(u64::MAX)
+		});
+		if max_tokens == 0 {
+			return read_weight;
+		}
+
+		let tokens = index_tokens::<T>(max_tokens);
+		if !TokenIndexMigrationCursor::<T>::exists() {
+			StorageVersion::new(2).put::<Pallet<T>>();
+		}
+
+		base_weight.saturating_add(weight_per_token::<T>().saturating_mul(tokens))
+	}
+
+	/// Indexes up to `max_tokens` tokens after the cursor, returning the number of tokens visited
+	pub fn index_tokens<T: Config>(max_tokens: u64) -> u64 {
+		match TokenIndexMigrationCursor::<T>::get() {
+			Some((collection_id, token_id)) => index_tokens_after::<T>(
+				TokenURI::<T>::hashed_key_for(collection_id, token_id),
+				max_tokens,
+			),
+			None => 0,
+		}
+	}
+
+	/// Indexes up to `max_tokens` tokens of [`TokenURI`] after `raw_key`, returning the number of
+	/// tokens visited
+	///
+	/// Tokens already indexed by a mint after the upgrade are skipped. The cursor is moved to the
+	/// last token visited, and it is removed once every token has been visited.
+	fn index_tokens_after<T: Config>(raw_key: Vec<u8>, max_tokens: u64) -> u64 {
+		let mut keys = TokenURI::<T>::iter_keys_from(raw_key);
+		let mut tokens = 0;
+		while tokens < max_tokens {
+			let Some((collection_id, token_id)) = keys.next() else {
+				TokenIndexMigrationCursor::<T>::kill();
+				return tokens;
+			};
+			tokens += 1;
+
+			if !TokenIndex::<T>::contains_key(collection_id, token_id) {
+				let index = TokenCount::<T>::get(collection_id);
+				TokenByIndex::<T>::insert(collection_id, index, token_id);
+				TokenIndex::<T>::insert(collection_id, token_id, index);
+				TokenCount::<T>::insert(collection_id, index.saturating_add(1));
+			}
+			TokenIndexMigrationCursor::<T>::put((collection_id, token_id));
+		}
+
+		tokens
+	}
+}
+
+/// Migrates the pallet from storage version 0 to 1, see [`v1::InnerMigrateV0ToV1`]
+pub type MigrateV0ToV1<T> = VersionedMigration<
+	0,
+	1,
+	v1::InnerMigrateV0ToV1<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
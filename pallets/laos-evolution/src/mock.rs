@@ -15,8 +15,13 @@
 // along with LAOS.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate as pallet_laos_evolution;
+use crate::{
+	traits::{OnEvolve, OnMint, OnOwnershipTransferred},
+	types::{CollectionId, TokenId, TokenUriOf},
+};
 use frame_support::{derive_impl, pallet_prelude::Weight, parameter_types};
 use sp_core::H160;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{traits::IdentityLookup, BuildStorage};
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -27,6 +32,8 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		LaosEvolution: pallet_laos_evolution,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
 	}
 );
 
@@ -37,10 +44,80 @@ impl frame_system::Config for Test {
 	type Block = Block;
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
 }
 
 parameter_types! {
 	pub const MaxTokenUriLength: u32 = 512;
+	pub const MaxCollectionNameLength: u32 = 128;
+	pub const MaxCollectionSymbolLength: u32 = 32;
+	pub static MaxUriHistory: u32 = 3;
+	pub const MaxScheduledEvolutionsPerBlock: u32 = 2;
+	pub const MaxAttributeValueLength: u32 = 32;
+	pub const MaxAttributesPerToken: u32 = 2;
+	pub const ChainId: u64 = 62_621;
+	pub static CollectionDeposit: u64 = 0;
+	pub static DepositPerByte: u64 = 0;
+	pub static HookCalls: Vec<HookCall> = vec![];
+}
+
+/// Weight returned by [`RecordHooks`] every time it is called
+pub const HOOK_WEIGHT: Weight = Weight::from_parts(1_000, 0);
+
+/// Call received by [`RecordHooks`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum HookCall {
+	Mint(CollectionId, TokenId, AccountId),
+	Evolve(CollectionId, TokenId, TokenUriOf<Test>),
+	OwnershipTransferred(CollectionId, AccountId, Option<AccountId>),
+}
+
+/// Hooks that record every call in [`HookCalls`]
+pub struct RecordHooks;
+
+impl OnMint<AccountId> for RecordHooks {
+	fn on_mint(collection_id: CollectionId, token_id: TokenId, to: &AccountId) -> Weight {
+		HookCalls::mutate(|calls| calls.push(HookCall::Mint(collection_id, token_id, *to)));
+		HOOK_WEIGHT
+	}
+
+	fn weight() -> Weight {
+		HOOK_WEIGHT
+	}
+}
+
+impl OnEvolve<TokenUriOf<Test>> for RecordHooks {
+	fn on_evolve(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		token_uri: &TokenUriOf<Test>,
+	) -> Weight {
+		HookCalls::mutate(|calls| {
+			calls.push(HookCall::Evolve(collection_id, token_id, token_uri.clone()))
+		});
+		HOOK_WEIGHT
+	}
+
+	fn weight() -> Weight {
+		HOOK_WEIGHT
+	}
+}
+
+impl OnOwnershipTransferred<AccountId> for RecordHooks {
+	fn on_ownership_transferred(
+		collection_id: CollectionId,
+		from: &AccountId,
+		to: Option<&AccountId>,
+	) -> Weight {
+		HookCalls::mutate(|calls| {
+			calls.push(HookCall::OwnershipTransferred(collection_id, *from, to.copied()))
+		});
+		HOOK_WEIGHT
+	}
+
+	fn weight() -> Weight {
+		HOOK_WEIGHT
+	}
 }
 
 pub struct AccountIdToH160;
@@ -61,14 +138,56 @@ impl pallet_laos_evolution::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AccountIdToH160 = AccountIdToH160;
 	type MaxTokenUriLength = MaxTokenUriLength;
+	type MaxCollectionNameLength = MaxCollectionNameLength;
+	type MaxCollectionSymbolLength = MaxCollectionSymbolLength;
+	type MaxUriHistory = MaxUriHistory;
+	type MaxScheduledEvolutionsPerBlock = MaxScheduledEvolutionsPerBlock;
+	type MaxAttributeValueLength = MaxAttributeValueLength;
+	type MaxAttributesPerToken = MaxAttributesPerToken;
+	type ChainId = ChainId;
+	type Timestamp = Timestamp;
 	type WeightInfo = ();
 	type GasWeightMapping = MockGasWeightMapping;
 	type OnCreateCollection = ();
+	type OnMint = (RecordHooks, ());
+	type OnEvolve = (RecordHooks, ());
+	type OnOwnershipTransferred = (RecordHooks, ());
+	type Currency = Balances;
+	type CollectionDeposit = CollectionDeposit;
+	type DepositPerByte = DepositPerByte;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 0;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type RuntimeHoldReason = ();
+	type DustRemoval = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 1000;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut ext: sp_io::TestExternalities =
+		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+	// the benchmarks sign mint authorizations with keys of this keystore
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext
 }
 
 pub struct MockGasWeightMapping;
@@ -1,9 +1,39 @@
 // SPDX-License-Identifier: GPL-3.0-only
 pragma solidity >=0.8.3;
 
+/// @title Interface of ERC-165
+/// @author LAOS Team
+/// @notice Standard interface detection
+interface ERC165 {
+    /// @notice Query if the collection implements an interface, following ERC-165
+    /// @param _interfaceId the interface identifier, as specified in ERC-165
+    /// @return true if the collection implements `_interfaceId`
+    function supportsInterface(bytes4 _interfaceId) external view returns (bool);
+}
+
+/// @title Interface of EIP-2981
+/// @author LAOS Team
+/// @notice Royalties of tokens, as specified in EIP-2981
+interface ERC2981 {
+    /// @notice Royalty to be paid for a sale of a token, following EIP-2981
+    /// @dev The royalty of the token applies if set, otherwise the default royalty of the collection
+    /// @dev Returns the zero address and a zero amount if no royalty applies
+    /// @param _tokenId the id of the token
+    /// @param _salePrice the sale price of the token
+    /// @return receiver the account that receives the royalties
+    /// @return royaltyAmount the royalty to be paid, in the same unit as `_salePrice`
+    function royaltyInfo(
+        uint256 _tokenId,
+        uint256 _salePrice
+    ) external view returns (address receiver, uint256 royaltyAmount);
+}
+
 /// @title Pallet Laos Evolution Interface
 /// @author LAOS Team
 /// @notice This interface allows Solidity contracts to interact with pallet-laos-evolution
+/// @dev Collections report support for ERC-165, EIP-2981, EIP-4906, this core interface and
+/// @dev each of the extensions below through `supportsInterface`. The identifier of every
+/// @dev interface is its `type(...).interfaceId`, which only covers its own functions
 interface EvolutionCollection {
     /// @notice Emitted when a new token is minted
     /// @notice The emitted tokenURI has not undergone any on-chain validation.
@@ -37,8 +67,34 @@ interface EvolutionCollection {
         address indexed _newOwner
     );
 
+    /// @notice Emitted when a token is minted or burned, following the ERC-721 `Transfer` event
+    /// @dev On mint `_from` is the zero address and `_to` the initial owner, together with `MintedWithExternalURI`
+    /// @dev On burn `_from` is the initial owner encoded in `_tokenId` and `_to` is the zero address
+    /// @param _from the zero address on mint, the initial owner of the token on burn
+    /// @param _to the initial owner of the token on mint, the zero address on burn
+    /// @param _tokenId the id of the minted or burned token
+    event Transfer(
+        address indexed _from,
+        address indexed _to,
+        uint256 indexed _tokenId
+    );
+
+    /// @notice Emitted when the metadata of a token changes, following EIP-4906
+    /// @dev Emitted together with `EvolvedWithExternalURI` when a token is evolved, also in batches
+    /// @dev unless the evolved tokens are all the tokens of a range of token ids
+    /// @param _tokenId the id of the token for which the metadata has changed
+    event MetadataUpdate(uint256 _tokenId);
+
+    /// @notice Emitted when the metadata of a range of tokens changes, following EIP-4906
+    /// @dev Emitted once per `batchEvolveWithExternalURI` whose evolved tokens are all the tokens of a range of
+    /// @dev token ids, and by `setBaseURI` with the range of all token ids
+    /// @param _fromTokenId the first token id of the range
+    /// @param _toTokenId the last token id of the range
+    event BatchMetadataUpdate(uint256 _fromTokenId, uint256 _toTokenId);
+
     /// @notice Owner of the collection
     /// @dev Call this function to get the owner of a collection
+    /// @dev Returns the zero address once the ownership has been renounced
     /// @return the owner of the collection
     function owner() external view returns (address);
 
@@ -47,6 +103,7 @@ interface EvolutionCollection {
     /// @notice any on-chain validation. Users are fully responsible for accuracy,
     /// @notice authenticity and preventing potential misuse or exploits.
     /// @dev Implementations must follow the ERC-721 standard for token URIs, which should point to a JSON file conforming to the "ERC721 Metadata JSON Schema".
+    /// @dev Tokens minted with an empty tokenURI resolve through the base URI of the collection, if set
     /// @param _tokenId The unique identifier of the token within the specified collection.
     /// @return A string representing the URI of the specified token.
     function tokenURI(uint256 _tokenId) external view returns (string memory);
@@ -55,7 +112,7 @@ interface EvolutionCollection {
     /// @notice The tokenURI provided to this method does not undergo
     /// @notice any on-chain validation. Users are fully responsible for accuracy,
     /// @notice authenticity and preventing potential misuse or exploits.
-    /// @dev Call this function to mint a new token, the caller must be the owner of the collection
+    /// @dev Call this function to mint a new token, the caller must be the owner of the collection or have `MINTER_ROLE`
     /// @param _to the owner of the newly minted token
     /// @param _slot the slot of the token
     /// @param _tokenURI the tokenURI of the newly minted token
@@ -70,7 +127,8 @@ interface EvolutionCollection {
     /// @notice The tokenURI provided to this method does not undergo
     /// @notice any on-chain validation. Users are fully responsible for accuracy,
     /// @notice authenticity and preventing potential misuse or exploits.
-    /// @dev Call this function to evolve an existing token, the caller must be the owner of the collection
+    /// @dev Call this function to evolve an existing token, the caller must be the owner of the collection or have `EVOLVER_ROLE`
+    /// @dev If `evolutionConsentRequired`, the evolution must have been approved with `approveEvolution`
     /// @param _tokenId the id of the token
     /// @param _tokenURI the new tokenURI of the token
     function evolveWithExternalURI(
@@ -78,8 +136,575 @@ interface EvolutionCollection {
         string calldata _tokenURI
     ) external;
 
-    /// @notice Transfers ownership of the collection to a new account (`newOwner`).
+    /// @notice Starts the transfer of the ownership of the collection to a new account (`newOwner`).
     /// @dev Call this function to transfer ownership of the collection, the caller must be the owner of the collection
+    /// @dev The ownership only changes once `_newOwner` calls `acceptOwnership`, replacing any previous pending transfer
+    /// @dev Emits an `OwnershipTransferStarted` event
     /// @param _newOwner The address to transfer ownership to.
     function transferOwnership(address _newOwner) external;
+
+    /// @notice Initial owner of a token, which is encoded in the last 20 bytes of its id
+    /// @dev The token does not need to be minted, as the result is computed from `_tokenId` alone
+    /// @param _tokenId the id of the token
+    /// @return the initial owner of the token
+    function initialOwnerOf(uint256 _tokenId) external view returns (address);
+
+    /// @notice Slot of a token, which is encoded in the first 12 bytes of its id
+    /// @dev The token does not need to be minted, as the result is computed from `_tokenId` alone
+    /// @param _tokenId the id of the token
+    /// @return the slot of the token
+    function slotOf(uint256 _tokenId) external view returns (uint96);
+}
+
+/// @title Metadata extension of collections
+/// @author LAOS Team
+/// @notice Name, symbol, contract-level metadata and base URI of the collection
+interface EvolutionCollectionMetadata {
+    /// @notice Emitted when the name, symbol or contractURI of the collection is updated
+    /// @dev Follows the ERC-7572 convention so that marketplaces refresh the collection metadata
+    event ContractURIUpdated();
+
+    /// @notice Emitted when the base URI of the collection is set
+    /// @param _baseURI the base URI, which is empty when it has been removed
+    event BaseURISet(string _baseURI);
+
+    /// @notice Sets the base URI of the collection, or removes it if `_baseURI` is empty
+    /// @notice Tokens minted with an empty tokenURI resolve to the base URI, with every `{id}`
+    /// @notice replaced by their token id as 64 lowercase hex digits, like in ERC-1155
+    /// @dev Call this function to set the base URI, the caller must be the owner of the collection
+    /// @dev Reverts if the collection is frozen
+    /// @param _baseURI the base URI of the collection
+    function setBaseURI(string calldata _baseURI) external;
+
+    /// @notice Base URI of the collection
+    /// @return the base URI of the collection, or an empty string if it is not set
+    function baseURI() external view returns (string memory);
+
+    /// @notice Name of the collection
+    /// @return the name of the collection, or an empty string if it has not been set
+    function name() external view returns (string memory);
+
+    /// @notice Symbol of the collection
+    /// @return the symbol of the collection, or an empty string if it has not been set
+    function symbol() external view returns (string memory);
+
+    /// @notice URI of the collection-level metadata, following ERC-7572
+    /// @return the contractURI of the collection, or an empty string if it has not been set
+    function contractURI() external view returns (string memory);
+
+    /// @notice Sets the name, symbol and contractURI of the collection
+    /// @dev Call this function to update the collection metadata, the caller must be the owner of the collection
+    /// @dev Emits a `ContractURIUpdated` event
+    /// @param _name the new name of the collection
+    /// @param _symbol the new symbol of the collection
+    /// @param _contractURI the new contractURI of the collection
+    function setCollectionMetadata(
+        string calldata _name,
+        string calldata _symbol,
+        string calldata _contractURI
+    ) external;
+}
+
+/// @title Ownership extension of collections
+/// @author LAOS Team
+/// @notice Two-step transfers and renouncement of the ownership of the collection
+interface EvolutionCollectionOwnership {
+    /// @notice Emitted when the owner starts a transfer of the ownership of the collection
+    /// @param _previousOwner the current owner of the collection
+    /// @param _newOwner the account that has to accept the ownership
+    event OwnershipTransferStarted(
+        address indexed _previousOwner,
+        address indexed _newOwner
+    );
+
+    /// @notice Emitted when the owner cancels a pending transfer of the ownership of the collection
+    /// @param _owner the owner of the collection
+    /// @param _pendingOwner the account that could no longer accept the ownership
+    event OwnershipTransferCanceled(
+        address indexed _owner,
+        address indexed _pendingOwner
+    );
+
+    /// @notice Accepts the pending transfer of the ownership of the collection
+    /// @dev Call this function to become the owner of the collection, the caller must be the pending owner
+    /// @dev Emits an `OwnershipTransferred` event
+    function acceptOwnership() external;
+
+    /// @notice Cancels the pending transfer of the ownership of the collection
+    /// @dev Call this function to cancel a transfer, the caller must be the owner of the collection
+    /// @dev Emits an `OwnershipTransferCanceled` event
+    function cancelOwnershipTransfer() external;
+
+    /// @notice Renounces the ownership of the collection, leaving it without owner
+    /// @notice Once renounced, tokens can no longer be minted nor evolved, and the ownership cannot be recovered
+    /// @dev Call this function to renounce the ownership, the caller must be the owner of the collection
+    /// @dev Any pending ownership transfer is canceled, and an `OwnershipTransferred` event is emitted with the zero address as `_newOwner`
+    /// @dev The drop of the collection is removed, as its leaves are minted on behalf of the owner
+    function renounceOwnership() external;
+
+    /// @notice Pending owner of the collection
+    /// @return the account that can accept the ownership, or the zero address if there is none
+    function pendingOwner() external view returns (address);
+}
+
+/// @title Roles extension of collections
+/// @author LAOS Team
+/// @notice Minter and evolver roles granted by the owner of the collection
+interface EvolutionCollectionRoles {
+    /// @notice Emitted when `_role` is granted to `_account`
+    /// @param _role the id of the role, either `MINTER_ROLE` or `EVOLVER_ROLE`
+    /// @param _account the account that received the role
+    /// @param _sender the owner of the collection that granted the role
+    event RoleGranted(
+        bytes32 indexed _role,
+        address indexed _account,
+        address indexed _sender
+    );
+
+    /// @notice Emitted when `_role` is revoked from `_account`
+    /// @param _role the id of the role, either `MINTER_ROLE` or `EVOLVER_ROLE`
+    /// @param _account the account that lost the role
+    /// @param _sender the owner of the collection that revoked the role
+    event RoleRevoked(
+        bytes32 indexed _role,
+        address indexed _account,
+        address indexed _sender
+    );
+
+    /// @notice Grants `_role` to `_account`
+    /// @dev Call this function to grant a role, the caller must be the owner of the collection
+    /// @dev `MINTER_ROLE` is `keccak256("MINTER_ROLE")` and allows minting tokens,
+    /// @dev `EVOLVER_ROLE` is `keccak256("EVOLVER_ROLE")` and allows evolving tokens
+    /// @dev Emits a `RoleGranted` event if `_account` did not have the role yet
+    /// @param _role the id of the role
+    /// @param _account the account that receives the role
+    function grantRole(bytes32 _role, address _account) external;
+
+    /// @notice Revokes `_role` from `_account`
+    /// @dev Call this function to revoke a role, the caller must be the owner of the collection
+    /// @dev Emits a `RoleRevoked` event if `_account` had the role
+    /// @param _role the id of the role
+    /// @param _account the account that loses the role
+    function revokeRole(bytes32 _role, address _account) external;
+
+    /// @notice Whether `_account` has been granted `_role`
+    /// @param _role the id of the role
+    /// @param _account the account to check
+    /// @return true if `_account` has the role
+    function hasRole(bytes32 _role, address _account) external view returns (bool);
+}
+
+/// @title Batch extension of collections
+/// @author LAOS Team
+/// @notice Minting to the next free slot and minting and evolving in batches
+interface EvolutionCollectionBatch {
+    /// @notice Mint a new token in the next free slot of `_to`
+    /// @notice The tokenURI provided to this method does not undergo
+    /// @notice any on-chain validation. Users are fully responsible for accuracy,
+    /// @notice authenticity and preventing potential misuse or exploits.
+    /// @dev Call this function to mint a new token, the caller must be the owner of the collection or have `MINTER_ROLE`
+    /// @dev Slots are allocated in increasing order per owner, right after the highest slot minted to them
+    /// @dev Reverts with `SlotsExhausted` once the maximum slot of the owner has been minted
+    /// @param _to the owner of the newly minted token
+    /// @param _tokenURI the tokenURI of the newly minted token
+    /// @return the slot and the id of the newly minted token
+    function mintNext(
+        address _to,
+        string calldata _tokenURI
+    ) external returns (uint96, uint256);
+
+    /// @notice Mints new tokens in a single atomic operation
+    /// @notice The tokenURIs provided to this method do not undergo
+    /// @notice any on-chain validation. Users are fully responsible for accuracy,
+    /// @notice authenticity and preventing potential misuse or exploits.
+    /// @dev Call this function to mint new tokens, the caller must be the owner of the collection or have `MINTER_ROLE`
+    /// @dev All arrays must have the same length, and `MintedWithExternalURI` and `Transfer` events are emitted per token
+    /// @param _to the owners of the newly minted tokens
+    /// @param _slots the slots of the tokens
+    /// @param _tokenURIs the tokenURIs of the newly minted tokens
+    /// @return the ids of the newly minted tokens
+    function batchMintWithExternalURI(
+        address[] calldata _to,
+        uint96[] calldata _slots,
+        string[] calldata _tokenURIs
+    ) external returns (uint256[] memory);
+
+    /// @notice Changes the tokenURIs of existing tokens in a single atomic operation
+    /// @notice The tokenURIs provided to this method do not undergo
+    /// @notice any on-chain validation. Users are fully responsible for accuracy,
+    /// @notice authenticity and preventing potential misuse or exploits.
+    /// @dev Call this function to evolve existing tokens, the caller must be the owner of the collection or have `EVOLVER_ROLE`
+    /// @dev Both arrays must have the same length, and `EvolvedWithExternalURI` and `MetadataUpdate` events are emitted per token,
+    /// @dev or a single `BatchMetadataUpdate` instead of the latter if the token ids are a contiguous range
+    /// @dev If `evolutionConsentRequired`, every evolution must have been approved with `approveEvolution`
+    /// @param _tokenIds the ids of the tokens
+    /// @param _tokenURIs the new tokenURIs of the tokens
+    function batchEvolveWithExternalURI(
+        uint256[] calldata _tokenIds,
+        string[] calldata _tokenURIs
+    ) external;
+}
+
+/// @title Signatures extension of collections
+/// @author LAOS Team
+/// @notice Minting and evolving with off-chain authorizations
+interface EvolutionCollectionSignatures {
+    /// @notice Emitted when a signer invalidates a nonce of its mint authorizations
+    /// @param _signer the signer of the authorizations
+    /// @param _nonce the invalidated nonce
+    event MintNonceInvalidated(address indexed _signer, uint256 _nonce);
+
+    /// @notice Mint a new token authorized off-chain by the owner of the collection or a minter
+    /// @notice The tokenURI provided to this method does not undergo
+    /// @notice any on-chain validation. Users are fully responsible for accuracy,
+    /// @notice authenticity and preventing potential misuse or exploits.
+    /// @dev Anyone can submit the authorization, which is an EIP-712 signature of
+    /// @dev `MintWithSignature(address to,uint96 slot,string tokenURI,uint256 nonce,uint256 deadline)`
+    /// @dev in the domain `EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)`
+    /// @dev with name "LaosEvolution", version "1" and this collection as verifying contract.
+    /// @dev The nonce is marked as used for the signer so that the signature cannot be replayed.
+    /// @dev Authorizations can be used in any order.
+    /// @dev The signature must have `s` in the lower half of the order of the curve and `v` 27 or 28
+    /// @param _to the owner of the newly minted token
+    /// @param _slot the slot of the token
+    /// @param _tokenURI the tokenURI of the newly minted token
+    /// @param _nonce the nonce of the authorization, which can only be used once by the signer
+    /// @param _deadline the timestamp, in seconds, after which the signature is no longer valid
+    /// @param _signature the 65-byte signature of the owner of the collection or of a minter
+    /// @return the id of the newly minted token
+    function mintWithSignature(
+        address _to,
+        uint96 _slot,
+        string calldata _tokenURI,
+        uint256 _nonce,
+        uint256 _deadline,
+        bytes calldata _signature
+    ) external returns (uint256);
+
+    /// @notice Whether a nonce of the mint authorizations of a signer has already been used or invalidated
+    /// @param _signer the signer of the authorizations
+    /// @param _nonce the nonce of the authorizations
+    /// @return true if the nonce has been used or invalidated
+    function isMintNonceUsed(
+        address _signer,
+        uint256 _nonce
+    ) external view returns (bool);
+
+    /// @notice Invalidates a nonce of the mint authorizations signed by the caller
+    /// @dev Authorizations signed with the nonce can no longer be used
+    /// @dev Emits a `MintNonceInvalidated` event
+    /// @param _nonce the nonce to invalidate
+    function invalidateMintNonce(uint256 _nonce) external;
+
+    /// @notice Changes the tokenURI of an existing token with the off-chain consent of its initial owner
+    /// @notice The tokenURI provided to this method does not undergo
+    /// @notice any on-chain validation. Users are fully responsible for accuracy,
+    /// @notice authenticity and preventing potential misuse or exploits.
+    /// @dev Call this function to evolve an existing token, the caller must be the owner of the collection or have `EVOLVER_ROLE`
+    /// @dev Reverts if not `evolutionConsentRequired`. The consent is an EIP-712 signature of
+    /// @dev `EvolveWithSignature(uint256 tokenId,string tokenURI,uint32 version,uint256 deadline)`
+    /// @dev in the same domain as `mintWithSignature`. The version is `tokenURIVersion` of the token,
+    /// @dev which is bumped by the evolution so that the signature cannot be replayed
+    /// @param _tokenId the id of the token
+    /// @param _tokenURI the new tokenURI of the token
+    /// @param _deadline the timestamp, in seconds, after which the signature is no longer valid
+    /// @param _signature the 65-byte signature of the initial owner of the token
+    function evolveWithSignature(
+        uint256 _tokenId,
+        string calldata _tokenURI,
+        uint256 _deadline,
+        bytes calldata _signature
+    ) external;
+}
+
+/// @title Drop extension of collections
+/// @author LAOS Team
+/// @notice Minting of the leaves of a Merkle tree committed by the owner of the collection
+interface EvolutionCollectionDrop {
+    /// @notice Emitted when the Merkle root of the drop of the collection is set
+    /// @param _root the Merkle root of the drop
+    event DropRootSet(bytes32 _root);
+
+    /// @notice Mint the token of a leaf of the drop of the collection
+    /// @notice The tokenURI provided to this method does not undergo
+    /// @notice any on-chain validation. Users are fully responsible for accuracy,
+    /// @notice authenticity and preventing potential misuse or exploits.
+    /// @dev Anyone can claim a leaf, which is minted on behalf of the owner of the collection.
+    /// @dev Leaves are `keccak256(bytes.concat(keccak256(abi.encode(_slot, _to, _tokenURI))))` and pairs
+    /// @dev of nodes are sorted before being hashed, as in the standard Merkle trees of OpenZeppelin.
+    /// @dev Every leaf can only be claimed once, and reverts with `OwnershipRenounced` once the ownership is renounced
+    /// @param _proof the Merkle proof of the leaf
+    /// @param _to the owner of the newly minted token
+    /// @param _slot the slot of the token
+    /// @param _tokenURI the tokenURI of the newly minted token
+    /// @return the id of the newly minted token
+    function claimFromDrop(
+        bytes32[] calldata _proof,
+        address _to,
+        uint96 _slot,
+        string calldata _tokenURI
+    ) external returns (uint256);
+
+    /// @notice Sets the Merkle root of the drop of the collection, replacing the previous one
+    /// @dev Call this function to commit a drop, the caller must be the owner of the collection
+    /// @param _root the Merkle root of the drop
+    function setDropRoot(bytes32 _root) external;
+
+    /// @notice Merkle root of the drop of the collection
+    /// @return the Merkle root of the drop, or zero if the collection has no drop
+    function dropRoot() external view returns (bytes32);
+}
+
+/// @title Supply extension of collections
+/// @author LAOS Team
+/// @notice Enumeration, maximum supply and burning of the tokens of the collection
+interface EvolutionCollectionSupply {
+    /// @notice Number of tokens of the collection
+    /// @dev Burned tokens are not counted
+    /// @return the number of tokens of the collection
+    function totalSupply() external view returns (uint256);
+
+    /// @notice Maximum number of tokens ever minted in the collection, set when it was created
+    /// @dev Burned tokens keep counting towards it
+    /// @return the maximum number of tokens, or `type(uint256).max` if the collection is not capped
+    function maxSupply() external view returns (uint256);
+
+    /// @notice Token at the given index of the collection, following ERC-721 Enumerable
+    /// @dev Indexes go from zero to `totalSupply()`, and burning a token moves the last token to its index
+    /// @dev Reverts if `_index` is not lower than `totalSupply()`
+    /// @param _index the index of the token
+    /// @return the id of the token at `_index`
+    function tokenByIndex(uint256 _index) external view returns (uint256);
+
+    /// @notice Burns an existing token, removing its tokenURI
+    /// @dev Call this function to burn an existing token, the caller must be the owner of the collection
+    /// @dev Reverts if the token is frozen, so that it cannot be minted again with another tokenURI
+    /// @dev After burning, `tokenURI` reverts for `_tokenId`
+    /// @param _tokenId the id of the token
+    function burn(uint256 _tokenId) external;
+}
+
+/// @title Freezing extension of collections
+/// @author LAOS Team
+/// @notice Freezing of the tokenURI of tokens and of the whole collection
+interface EvolutionCollectionFreezing {
+    /// @notice Emitted when the tokenURI of a token is frozen and can no longer be evolved
+    /// @dev Follows the `PermanentURI` convention used by marketplaces to flag locked metadata
+    /// @param _value the frozen URI of the token
+    /// @param _id the id of the frozen token
+    event PermanentURI(string _value, uint256 indexed _id);
+
+    /// @notice Emitted when the tokenURIs of all the tokens of the collection are frozen
+    event CollectionFrozen();
+
+    /// @notice Freezes the tokenURI of an existing token, so that it can no longer be evolved
+    /// @dev Call this function to freeze an existing token, the caller must be the owner of the collection
+    /// @dev A token resolved through the base URI keeps its resolved tokenURI from then on
+    /// @dev Emits a `PermanentURI` event with the frozen tokenURI
+    /// @param _tokenId the id of the token
+    function freezeToken(uint256 _tokenId) external;
+
+    /// @notice Freezes the tokenURIs of all the tokens of the collection, including future ones
+    /// @dev Call this function to freeze the collection, the caller must be the owner of the collection
+    function freezeCollection() external;
+
+    /// @notice Whether the tokenURI of a token is frozen, either by itself or through its collection
+    /// @param _tokenId the id of the token
+    /// @return true if the token can no longer be evolved
+    function isFrozen(uint256 _tokenId) external view returns (bool);
+}
+
+/// @title Royalties extension of collections
+/// @author LAOS Team
+/// @notice Royalties of the collection and of its tokens, reported by `royaltyInfo` of EIP-2981
+interface EvolutionCollectionRoyalties {
+    /// @notice Emitted when the default royalty of the collection is set
+    /// @param _receiver the account that receives the royalties
+    /// @param _basisPoints the royalty fee in basis points of the sale price
+    event DefaultRoyaltySet(address indexed _receiver, uint16 _basisPoints);
+
+    /// @notice Emitted when the royalty of a token is set
+    /// @param _tokenId the id of the token
+    /// @param _receiver the account that receives the royalties
+    /// @param _basisPoints the royalty fee in basis points of the sale price
+    event TokenRoyaltySet(
+        uint256 indexed _tokenId,
+        address indexed _receiver,
+        uint16 _basisPoints
+    );
+
+    /// @notice Sets the royalty of every token of the collection without its own royalty
+    /// @dev Call this function to set the default royalty, the caller must be the owner of the collection
+    /// @dev `_basisPoints` can not exceed 10000, which is the whole sale price
+    /// @dev Emits a `DefaultRoyaltySet` event
+    /// @param _receiver the account that receives the royalties
+    /// @param _basisPoints the royalty fee in basis points of the sale price
+    function setDefaultRoyalty(address _receiver, uint16 _basisPoints) external;
+
+    /// @notice Sets the royalty of an existing token, overriding the default royalty of the collection
+    /// @dev Call this function to set the royalty of a token, the caller must be the owner of the collection
+    /// @dev `_basisPoints` can not exceed 10000, which is the whole sale price
+    /// @dev Emits a `TokenRoyaltySet` event
+    /// @param _tokenId the id of the token
+    /// @param _receiver the account that receives the royalties
+    /// @param _basisPoints the royalty fee in basis points of the sale price
+    function setTokenRoyalty(
+        uint256 _tokenId,
+        address _receiver,
+        uint16 _basisPoints
+    ) external;
+}
+
+/// @title History extension of collections
+/// @author LAOS Team
+/// @notice Past and scheduled tokenURIs of tokens
+interface EvolutionCollectionHistory {
+    /// @notice Emitted when the evolution of a token is scheduled
+    /// @dev No log is emitted when the evolution is applied, as it is applied outside of any EVM transaction:
+    /// @dev unless `ScheduledEvolutionCanceled` is emitted or the token is burned before, the tokenURI of the token
+    /// @dev is `_tokenURI` from block `_block` on, which can be checked with `tokenURI` and `tokenURIVersion`.
+    /// @dev Evolutions that cannot be applied, for instance because the token has been frozen meanwhile, are skipped
+    /// @param _tokenId the id of the token
+    /// @param _tokenURI the tokenURI of the token after the evolution
+    /// @param _block the block at the beginning of which the evolution is applied
+    event EvolutionScheduled(uint256 indexed _tokenId, string _tokenURI, uint256 _block);
+
+    /// @notice Emitted when the scheduled evolution of a token is canceled with `cancelScheduledEvolution`
+    /// @param _tokenId the id of the token
+    event ScheduledEvolutionCanceled(uint256 indexed _tokenId);
+
+    /// @notice Number of times the tokenURI of a token has been evolved
+    /// @dev The tokenURI set when minting is version 0, and every evolution increments the version
+    /// @param _tokenId the id of the token
+    /// @return the current version of the tokenURI of the token
+    function tokenURIVersion(uint256 _tokenId) external view returns (uint32);
+
+    /// @notice TokenURI of a token at a given version
+    /// @dev Reverts if the token does not exist, burning a token removes its history. Only a limited
+    /// @dev number of previous versions are kept, older versions revert
+    /// @param _tokenId the id of the token
+    /// @param _version the version of the tokenURI
+    /// @return the tokenURI of the token at `_version`
+    function tokenURIAt(
+        uint256 _tokenId,
+        uint32 _version
+    ) external view returns (string memory);
+
+    /// @notice Evolution of a token scheduled by the owner of the collection, which is applied
+    /// @notice at the beginning of the given block with the permissions of the owner at that block
+    /// @dev Reverts if the token has no scheduled evolution, which is also the case once it has been applied
+    /// @param _tokenId the id of the token
+    /// @return _block the block at the beginning of which the evolution is applied
+    /// @return _tokenURI the tokenURI of the token after the evolution
+    function scheduledEvolution(
+        uint256 _tokenId
+    ) external view returns (uint256 _block, string memory _tokenURI);
+
+    /// @notice Schedules the evolution of a token to `_tokenURI` at the beginning of `_block`
+    /// @notice The tokenURI provided to this method does not undergo
+    /// @notice any on-chain validation. Users are fully responsible for accuracy,
+    /// @notice authenticity and preventing potential misuse or exploits.
+    /// @dev Call this function to schedule an evolution, the caller must be the owner of the collection or have `EVOLVER_ROLE`
+    /// @dev Replaces any previous scheduled evolution of the token, and `_block` must be in the future
+    /// @dev The storage deposit of `_tokenURI` is reserved from the caller until the evolution is applied or canceled
+    /// @dev Emits an `EvolutionScheduled` event
+    /// @param _tokenId the id of the token
+    /// @param _tokenURI the tokenURI of the token after the evolution
+    /// @param _block the block at the beginning of which the evolution is applied
+    function scheduleEvolution(
+        uint256 _tokenId,
+        string calldata _tokenURI,
+        uint256 _block
+    ) external;
+
+    /// @notice Cancels the scheduled evolution of a token, releasing its deposit
+    /// @dev Call this function to cancel an evolution, the caller must be the owner of the collection or have `EVOLVER_ROLE`
+    /// @dev Reverts if the token has no scheduled evolution
+    /// @dev Emits a `ScheduledEvolutionCanceled` event
+    /// @param _tokenId the id of the token
+    function cancelScheduledEvolution(uint256 _tokenId) external;
+}
+
+/// @title Consent extension of collections
+/// @author LAOS Team
+/// @notice Approval of evolutions by the initial owners of tokens
+interface EvolutionCollectionConsent {
+    /// @notice Emitted when the owner of the collection sets whether evolutions require the approval
+    /// @notice of the initial owner of the token
+    /// @param _required whether evolutions require the approval of the initial owner of the token
+    event EvolutionConsentRequiredSet(bool _required);
+
+    /// @notice Emitted when the initial owner of a token approves its next evolution
+    /// @param _tokenId the id of the token
+    /// @param _uriHash the Keccak hash of the approved tokenURI
+    event EvolutionApproved(uint256 indexed _tokenId, bytes32 _uriHash);
+
+    /// @notice Approves the next evolution of a token, which is required when `evolutionConsentRequired`
+    /// @dev Call this function to approve an evolution, the caller must be the initial owner of the token
+    /// @dev Replaces any previous approval, and the approval is consumed by the evolution
+    /// @dev Reverts if the collection does not require the consent of initial owners
+    /// @dev Emits an `EvolutionApproved` event
+    /// @param _tokenId the id of the token
+    /// @param _uriHash the Keccak hash of the approved tokenURI
+    function approveEvolution(uint256 _tokenId, bytes32 _uriHash) external;
+
+    /// @notice Sets whether evolutions require the approval of the initial owner of the token
+    /// @dev Call this function to set the consent mode, the caller must be the owner of the collection
+    /// @dev Emits an `EvolutionConsentRequiredSet` event
+    /// @param _required whether evolutions require the approval of the initial owner of the token
+    function setEvolutionConsentRequired(bool _required) external;
+
+    /// @notice Whether evolutions require the approval of the initial owner of the token
+    /// @return true if evolutions must be approved with `approveEvolution` or `evolveWithSignature`
+    function evolutionConsentRequired() external view returns (bool);
+}
+
+/// @title Attributes extension of collections
+/// @author LAOS Team
+/// @notice On-chain key-value attributes of tokens
+interface EvolutionCollectionAttributes {
+    /// @notice Emitted when an attribute of a token is set
+    /// @param _tokenId the id of the token
+    /// @param _key the key of the attribute
+    /// @param _value the value of the attribute
+    event AttributeSet(
+        uint256 indexed _tokenId,
+        bytes32 indexed _key,
+        bytes _value
+    );
+
+    /// @notice Emitted when an attribute of a token is removed
+    /// @param _tokenId the id of the token
+    /// @param _key the key of the attribute
+    event AttributeRemoved(uint256 indexed _tokenId, bytes32 indexed _key);
+
+    /// @notice Sets the value of an attribute of an existing token
+    /// @dev Call this function to set an attribute, the caller must be the owner of the collection
+    /// @dev Reverts if the token is frozen or already has the maximum number of attributes
+    /// @dev Emits an `AttributeSet` event
+    /// @param _tokenId the id of the token
+    /// @param _key the key of the attribute
+    /// @param _value the value of the attribute
+    function setAttribute(
+        uint256 _tokenId,
+        bytes32 _key,
+        bytes calldata _value
+    ) external;
+
+    /// @notice Removes an attribute of a token
+    /// @dev Call this function to remove an attribute, the caller must be the owner of the collection
+    /// @dev Emits an `AttributeRemoved` event
+    /// @param _tokenId the id of the token
+    /// @param _key the key of the attribute
+    function removeAttribute(uint256 _tokenId, bytes32 _key) external;
+
+    /// @notice Value of an attribute of a token
+    /// @dev Reverts if the token has no attribute with key `_key`
+    /// @param _tokenId the id of the token
+    /// @param _key the key of the attribute
+    /// @return the value of the attribute
+    function getAttribute(
+        uint256 _tokenId,
+        bytes32 _key
+    ) external view returns (bytes memory);
 }
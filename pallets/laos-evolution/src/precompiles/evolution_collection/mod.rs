@@ -1,27 +1,46 @@
 use crate::{
-	address_to_collection_id,
-	traits::EvolutionCollection,
-	types::{CollectionId, Slot},
+	address_to_collection_id, token_id_to_initial_owner, token_id_to_slot,
+	traits::{
+		EvolutionCollection, EvolutionCollectionAttributes, EvolutionCollectionBaseUri,
+		EvolutionCollectionBatch, EvolutionCollectionBurn, EvolutionCollectionConsent,
+		EvolutionCollectionDrop, EvolutionCollectionFreezing, EvolutionCollectionHistory,
+		EvolutionCollectionMetadata, EvolutionCollectionMintNext, EvolutionCollectionNonces,
+		EvolutionCollectionOwnership, EvolutionCollectionRoles, EvolutionCollectionRoyalties,
+		EvolutionCollectionScheduling, EvolutionCollectionSupply, OnEvolve, OnMint,
+		OnOwnershipTransferred,
+	},
+	types::{
+		AttributeValueOf, BasisPoints, CollectionId, InterfaceId, Role, Slot,
+		MAX_ROYALTY_BASIS_POINTS,
+	},
 	weights::WeightInfo,
 	Config, Pallet as LaosEvolution, TokenId,
 };
 use fp_evm::ExitError;
-use frame_support::DefaultNoBound;
+use frame_support::{
+	traits::{Get, UnixTime},
+	DefaultNoBound,
+};
 use pallet_evm::GasWeightMapping;
 use precompile_utils::{
 	keccak256,
 	prelude::{
-		log2, log3, revert, Address, DiscriminantResult, EvmResult, LogExt, PrecompileHandle,
-		String,
+		log1, log2, log3, log4, revert, Address, DiscriminantResult, EvmResult, LogExt,
+		PrecompileHandle, String,
+	},
+	solidity::{
+		self,
+		codec::{UnboundedBytes, UnboundedString},
 	},
-	solidity::{self, codec::UnboundedString},
 };
 use scale_info::prelude::format;
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256, U512};
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
 use sp_runtime::{
 	traits::{Convert, ConvertBack, PhantomData},
 	BoundedVec, DispatchError,
 };
+use sp_std::vec::Vec;
 
 /// Solidity selector of the MintedWithExternalURI log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI: [u8; 32] =
@@ -35,6 +54,191 @@ pub const SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI: [u8; 32] =
 pub const SELECTOR_LOG_OWNERSHIP_TRANSFERRED: [u8; 32] =
 	keccak256!("OwnershipTransferred(address,address)");
 
+/// Solidity selector of the `OwnershipTransferStarted` log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_OWNERSHIP_TRANSFER_STARTED: [u8; 32] =
+	keccak256!("OwnershipTransferStarted(address,address)");
+
+/// Solidity selector of the `OwnershipTransferCanceled` log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_OWNERSHIP_TRANSFER_CANCELED: [u8; 32] =
+	keccak256!("OwnershipTransferCanceled(address,address)");
+
+/// Solidity selector of the ERC-7572 `ContractURIUpdated` log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_CONTRACT_URI_UPDATED: [u8; 32] = keccak256!("ContractURIUpdated()");
+
+/// Solidity selector of the ERC-721 `Transfer` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the EIP-4906 `MetadataUpdate` log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_METADATA_UPDATE: [u8; 32] = keccak256!("MetadataUpdate(uint256)");
+
+/// Solidity selector of the EIP-4906 `BatchMetadataUpdate` log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_BATCH_METADATA_UPDATE: [u8; 32] =
+	keccak256!("BatchMetadataUpdate(uint256,uint256)");
+
+/// Solidity selector of the `PermanentURI` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_PERMANENT_URI: [u8; 32] = keccak256!("PermanentURI(string,uint256)");
+
+/// Solidity selector of the `CollectionFrozen` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_COLLECTION_FROZEN: [u8; 32] = keccak256!("CollectionFrozen()");
+
+/// Solidity selector of the `RoleGranted` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_ROLE_GRANTED: [u8; 32] = keccak256!("RoleGranted(bytes32,address,address)");
+
+/// Solidity selector of the `RoleRevoked` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_ROLE_REVOKED: [u8; 32] = keccak256!("RoleRevoked(bytes32,address,address)");
+
+/// Solidity selector of the `DefaultRoyaltySet` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_DEFAULT_ROYALTY_SET: [u8; 32] =
+	keccak256!("DefaultRoyaltySet(address,uint16)");
+
+/// Solidity selector of the `TokenRoyaltySet` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TOKEN_ROYALTY_SET: [u8; 32] =
+	keccak256!("TokenRoyaltySet(uint256,address,uint16)");
+
+/// Solidity selector of the `DropRootSet` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_DROP_ROOT_SET: [u8; 32] = keccak256!("DropRootSet(bytes32)");
+
+/// Solidity selector of the `BaseURISet` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_BASE_URI_SET: [u8; 32] = keccak256!("BaseURISet(string)");
+
+/// Solidity selector of the `AttributeSet` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_ATTRIBUTE_SET: [u8; 32] = keccak256!("AttributeSet(uint256,bytes32,bytes)");
+
+/// Solidity selector of the `AttributeRemoved` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_ATTRIBUTE_REMOVED: [u8; 32] =
+	keccak256!("AttributeRemoved(uint256,bytes32)");
+
+/// Solidity selector of the `EvolutionConsentRequiredSet` log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_EVOLUTION_CONSENT_REQUIRED_SET: [u8; 32] =
+	keccak256!("EvolutionConsentRequiredSet(bool)");
+
+/// Solidity selector of the `EvolutionApproved` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_EVOLUTION_APPROVED: [u8; 32] =
+	keccak256!("EvolutionApproved(uint256,bytes32)");
+
+/// Solidity selector of the `EvolutionScheduled` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_EVOLUTION_SCHEDULED: [u8; 32] =
+	keccak256!("EvolutionScheduled(uint256,string,uint256)");
+
+/// Solidity selector of the `ScheduledEvolutionCanceled` log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_SCHEDULED_EVOLUTION_CANCELED: [u8; 32] =
+	keccak256!("ScheduledEvolutionCanceled(uint256)");
+
+/// Solidity selector of the `MintNonceInvalidated` log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_MINT_NONCE_INVALIDATED: [u8; 32] =
+	keccak256!("MintNonceInvalidated(address,uint256)");
+
+/// Identifier of the minter role, which is the Keccak of `MINTER_ROLE`.
+pub const MINTER_ROLE: [u8; 32] = keccak256!("MINTER_ROLE");
+
+/// Identifier of the evolver role, which is the Keccak of `EVOLVER_ROLE`.
+pub const EVOLVER_ROLE: [u8; 32] = keccak256!("EVOLVER_ROLE");
+
+/// EIP-712 type hash of the domain of signed authorizations.
+pub const EIP712_DOMAIN_TYPEHASH: [u8; 32] = keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// EIP-712 type hash of signed mint authorizations.
+pub const MINT_WITH_SIGNATURE_TYPEHASH: [u8; 32] = keccak256!(
+	"MintWithSignature(address to,uint96 slot,string tokenURI,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP-712 type hash of evolutions signed by the initial owner of the token.
+pub const EVOLVE_WITH_SIGNATURE_TYPEHASH: [u8; 32] = keccak256!(
+	"EvolveWithSignature(uint256 tokenId,string tokenURI,uint32 version,uint256 deadline)"
+);
+
+/// Name of the EIP-712 domain of signed authorizations.
+pub const EIP712_DOMAIN_NAME: &[u8] = b"LaosEvolution";
+
+/// Version of the EIP-712 domain of signed authorizations.
+pub const EIP712_DOMAIN_VERSION: &[u8] = b"1";
+
+/// ERC-165 interface identifier of ERC-165 itself.
+pub const INTERFACE_ID_ERC165: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+
+/// ERC-165 interface identifier of EIP-2981: `royaltyInfo`.
+pub const INTERFACE_ID_ERC2981: [u8; 4] = [0x2a, 0x55, 0x20, 0x5a];
+
+/// ERC-165 interface identifier of EIP-4906, which signals the `MetadataUpdate` and
+/// `BatchMetadataUpdate` logs.
+pub const INTERFACE_ID_ERC4906: [u8; 4] = [0x49, 0x06, 0x49, 0x06];
+
+/// ERC-165 interface identifier of the core `EvolutionCollection` interface of
+/// `EvolutionCollection.sol`: `owner`, `tokenURI`, `mintWithExternalURI`, `evolveWithExternalURI`,
+/// `transferOwnership`, `initialOwnerOf` and `slotOf`.
+///
+/// Functions added to collections belong to extensions with their own identifiers, so that this
+/// one never changes.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION: [u8; 4] = [0xb1, 0x9b, 0x88, 0x5f];
+
+/// ERC-165 interface identifier of `EvolutionCollectionMetadata` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_METADATA: [u8; 4] = [0xb0, 0xfd, 0x8a, 0x0b];
+
+/// ERC-165 interface identifier of `EvolutionCollectionOwnership` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_OWNERSHIP: [u8; 4] = [0xc8, 0xa3, 0x5a, 0xd5];
+
+/// ERC-165 interface identifier of `EvolutionCollectionRoles` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_ROLES: [u8; 4] = [0x6b, 0xb9, 0xcd, 0x16];
+
+/// ERC-165 interface identifier of `EvolutionCollectionBatch` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_BATCH: [u8; 4] = [0x0d, 0x89, 0x00, 0x77];
+
+/// ERC-165 interface identifier of `EvolutionCollectionSignatures` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_SIGNATURES: [u8; 4] = [0x18, 0x26, 0xdb, 0x87];
+
+/// ERC-165 interface identifier of `EvolutionCollectionDrop` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_DROP: [u8; 4] = [0x58, 0x7b, 0x9d, 0xab];
+
+/// ERC-165 interface identifier of `EvolutionCollectionSupply` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_SUPPLY: [u8; 4] = [0xc0, 0x47, 0x46, 0x53];
+
+/// ERC-165 interface identifier of `EvolutionCollectionFreezing` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_FREEZING: [u8; 4] = [0x98, 0xcd, 0xde, 0x5e];
+
+/// ERC-165 interface identifier of `EvolutionCollectionRoyalties` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_ROYALTIES: [u8; 4] = [0x3b, 0xea, 0x9a, 0x6a];
+
+/// ERC-165 interface identifier of `EvolutionCollectionHistory` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_HISTORY: [u8; 4] = [0x79, 0xba, 0x86, 0xe0];
+
+/// ERC-165 interface identifier of `EvolutionCollectionConsent` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_CONSENT: [u8; 4] = [0xf5, 0x27, 0x1e, 0xf1];
+
+/// ERC-165 interface identifier of `EvolutionCollectionAttributes` in `EvolutionCollection.sol`.
+pub const INTERFACE_ID_EVOLUTION_COLLECTION_ATTRIBUTES: [u8; 4] = [0x01, 0xe9, 0xa0, 0xc1];
+
+/// Interfaces reported as supported by `supportsInterface`.
+///
+/// ERC-721 Metadata is not reported even if `name`, `symbol` and `tokenURI` are implemented, as
+/// it requires the rest of ERC-721, which collections do not implement.
+pub const SUPPORTED_INTERFACES: [[u8; 4]; 16] = [
+	INTERFACE_ID_ERC165,
+	INTERFACE_ID_ERC2981,
+	INTERFACE_ID_ERC4906,
+	INTERFACE_ID_EVOLUTION_COLLECTION,
+	INTERFACE_ID_EVOLUTION_COLLECTION_METADATA,
+	INTERFACE_ID_EVOLUTION_COLLECTION_OWNERSHIP,
+	INTERFACE_ID_EVOLUTION_COLLECTION_ROLES,
+	INTERFACE_ID_EVOLUTION_COLLECTION_BATCH,
+	INTERFACE_ID_EVOLUTION_COLLECTION_SIGNATURES,
+	INTERFACE_ID_EVOLUTION_COLLECTION_DROP,
+	INTERFACE_ID_EVOLUTION_COLLECTION_SUPPLY,
+	INTERFACE_ID_EVOLUTION_COLLECTION_FREEZING,
+	INTERFACE_ID_EVOLUTION_COLLECTION_ROYALTIES,
+	INTERFACE_ID_EVOLUTION_COLLECTION_HISTORY,
+	INTERFACE_ID_EVOLUTION_COLLECTION_CONSENT,
+	INTERFACE_ID_EVOLUTION_COLLECTION_ATTRIBUTES,
+];
+
 #[derive(Clone, DefaultNoBound)]
 pub struct EvolutionCollectionPrecompileSet<R>(PhantomData<R>);
 
@@ -60,9 +264,11 @@ where
 			return DiscriminantResult::OutOfGas;
 		}
 
+		// addresses of nonexistent collections behave like empty accounts
 		match address_to_collection_id(address) {
-			Ok(id) => DiscriminantResult::Some(id, extra_cost),
-			Err(_) => DiscriminantResult::None(extra_cost),
+			Ok(id) if LaosEvolution::<R>::collection_exists(id) =>
+				DiscriminantResult::Some(id, extra_cost),
+			_ => DiscriminantResult::None(extra_cost),
 		}
 	}
 
@@ -76,6 +282,9 @@ where
 
 		if let Some(owner) = LaosEvolution::<R>::collection_owner(collection_id) {
 			Ok(Address(R::AccountIdToH160::convert(owner)))
+		} else if LaosEvolution::<R>::collection_exists(collection_id) {
+			// ownership has been renounced
+			Ok(Address(H160::zero()))
 		} else {
 			Err(revert("collection does not exist"))
 		}
@@ -91,7 +300,10 @@ where
 		                             * BoundedString<<R as Config>::MaxTokenUriLength> */
 	) -> EvmResult<U256> {
 		let token_uri_size = token_uri.as_bytes().len().try_into().unwrap();
-		super::register_cost::<R>(handle, R::WeightInfo::precompile_mint(token_uri_size))?;
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_mint(token_uri_size).saturating_add(R::OnMint::weight()),
+		)?;
 
 		let to: H160 = to.into();
 
@@ -102,7 +314,7 @@ where
 			.try_into()
 			.map_err(|_| revert("invalid token uri length"))?;
 
-		match LaosEvolution::<R>::mint_with_external_uri(
+		match <LaosEvolution<R> as EvolutionCollection<_, _>>::mint_with_external_uri(
 			R::AccountIdToH160::convert_back(handle.context().caller),
 			collection_id,
 			slot,
@@ -110,20 +322,279 @@ where
 			token_uri_bounded.clone(),
 		) {
 			Ok(token_id) => {
+				record_mint_logs(handle, to, slot, token_id, token_uri)?;
+
+				Ok(token_id)
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("mintNext(address,string)")]
+	pub fn mint_next(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		to: Address,
+		token_uri: UnboundedString,
+	) -> EvmResult<(Slot, U256)> {
+		let token_uri_size = token_uri.as_bytes().len().try_into().unwrap();
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_mint_next(token_uri_size).saturating_add(R::OnMint::weight()),
+		)?;
+
+		let to: H160 = to.into();
+
+		let token_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = token_uri
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| revert("invalid token uri length"))?;
+
+		match <LaosEvolution<R> as EvolutionCollectionMintNext<_, _>>::mint_next(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			R::AccountIdToH160::convert_back(to),
+			token_uri_bounded,
+		) {
+			Ok((slot, token_id)) => {
+				record_mint_logs(handle, to, slot, token_id, token_uri)?;
+
+				Ok((slot, token_id))
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("mintWithSignature(address,uint96,string,uint256,uint256,bytes)")]
+	pub fn mint_with_signature(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		to: Address,
+		slot: Slot,
+		token_uri: UnboundedString,
+		nonce: U256,
+		deadline: U256,
+		signature: UnboundedBytes,
+	) -> EvmResult<U256> {
+		let token_uri_size = token_uri.as_bytes().len().try_into().unwrap();
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_mint_with_signature(token_uri_size)
+				.saturating_add(R::OnMint::weight()),
+		)?;
+
+		if deadline < U256::from(R::Timestamp::now().as_secs()) {
+			return Err(revert("expired deadline"));
+		}
+
+		let to: H160 = to.into();
+
+		let token_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = token_uri
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| revert("invalid token uri length"))?;
+
+		let digest = mint_authorization_digest::<R>(
+			handle.context().address,
+			to,
+			slot,
+			token_uri.as_bytes(),
+			nonce,
+			deadline,
+		);
+		let signer = recover_signer(&digest, signature.as_bytes())
+			.ok_or_else(|| revert("invalid signature"))?;
+
+		match <LaosEvolution<R> as EvolutionCollectionNonces<_, _>>::mint_with_nonce(
+			R::AccountIdToH160::convert_back(signer),
+			collection_id,
+			slot,
+			R::AccountIdToH160::convert_back(to),
+			token_uri_bounded,
+			nonce,
+		) {
+			Ok(token_id) => {
+				record_mint_logs(handle, to, slot, token_id, token_uri)?;
+
+				Ok(token_id)
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("isMintNonceUsed(address,uint256)")]
+	#[precompile::view]
+	pub fn is_mint_nonce_used(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		signer: Address,
+		nonce: U256,
+	) -> EvmResult<bool> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_is_mint_nonce_used())?;
+
+		Ok(<LaosEvolution<R> as EvolutionCollectionNonces<_, _>>::is_mint_nonce_used(
+			collection_id,
+			R::AccountIdToH160::convert_back(signer.into()),
+			nonce,
+		))
+	}
+
+	#[precompile::public("invalidateMintNonce(uint256)")]
+	pub fn invalidate_mint_nonce(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		nonce: U256,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_invalidate_mint_nonce())?;
+
+		let caller = handle.context().caller;
+		match <LaosEvolution<R> as EvolutionCollectionNonces<_, _>>::invalidate_mint_nonce(
+			R::AccountIdToH160::convert_back(caller),
+			collection_id,
+			nonce,
+		) {
+			Ok(()) => {
 				log2(
 					handle.context().address,
-					SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI,
-					to,
-					solidity::encode_event_data((slot, token_id, token_uri)), /* TODO token_uri_bounded */
+					SELECTOR_LOG_MINT_NONCE_INVALIDATED,
+					caller,
+					solidity::encode_event_data(nonce),
 				)
 				.record(handle)?;
 
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("claimFromDrop(bytes32[],address,uint96,string)")]
+	pub fn claim_from_drop(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		proof: Vec<H256>,
+		to: Address,
+		slot: Slot,
+		token_uri: UnboundedString,
+	) -> EvmResult<U256> {
+		let proof_length = proof.len().try_into().unwrap();
+		let token_uri_size = token_uri.as_bytes().len().try_into().unwrap();
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_claim_from_drop(proof_length, token_uri_size)
+				.saturating_add(R::OnMint::weight()),
+		)?;
+
+		let to: H160 = to.into();
+
+		let token_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = token_uri
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| revert("invalid token uri length"))?;
+
+		match <LaosEvolution<R> as EvolutionCollectionDrop<_, _>>::claim_from_drop(
+			collection_id,
+			proof,
+			slot,
+			R::AccountIdToH160::convert_back(to),
+			token_uri_bounded,
+		) {
+			Ok(token_id) => {
+				record_mint_logs(handle, to, slot, token_id, token_uri)?;
+
 				Ok(token_id)
 			},
 			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
 		}
 	}
 
+	#[precompile::public("setDropRoot(bytes32)")]
+	pub fn set_drop_root(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		root: H256,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_set_drop_root())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionDrop<_, _>>::set_drop_root(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			root,
+		) {
+			Ok(()) => {
+				log1(
+					handle.context().address,
+					SELECTOR_LOG_DROP_ROOT_SET,
+					solidity::encode_event_data(root),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("dropRoot()")]
+	#[precompile::view]
+	pub fn drop_root(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<H256> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_drop_root())?;
+
+		// collections without a drop have a zero root, which no proof can match
+		Ok(<LaosEvolution<R> as EvolutionCollectionDrop<_, _>>::drop_root(collection_id)
+			.unwrap_or_default())
+	}
+
+	#[precompile::public("totalSupply()")]
+	#[precompile::view]
+	pub fn total_supply(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<U256> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_total_supply())?;
+
+		Ok(<LaosEvolution<R> as EvolutionCollectionSupply>::token_count(collection_id).into())
+	}
+
+	#[precompile::public("maxSupply()")]
+	#[precompile::view]
+	pub fn max_supply(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<U256> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_max_supply())?;
+
+		// collections without a max supply are reported as capped at the largest `uint256`
+		Ok(<LaosEvolution<R> as EvolutionCollectionSupply>::max_supply(collection_id)
+			.map_or(U256::MAX, Into::into))
+	}
+
+	#[precompile::public("tokenByIndex(uint256)")]
+	#[precompile::view]
+	pub fn token_by_index(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		index: U256,
+	) -> EvmResult<TokenId> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_token_by_index())?;
+
+		u64::try_from(index)
+			.ok()
+			.and_then(|index| {
+				<LaosEvolution<R> as EvolutionCollectionSupply>::token_by_index(
+					collection_id,
+					index,
+				)
+			})
+			.ok_or_else(|| revert("index out of bounds"))
+	}
+
 	#[precompile::public("evolveWithExternalURI(uint256,string)")]
 	pub fn evolve(
 		collection_id: CollectionId,
@@ -133,20 +604,80 @@ where
 		                             * BoundedString<<R as Config>::MaxTokenUriLength> */
 	) -> EvmResult<()> {
 		let token_uri_size = token_uri.as_bytes().len().try_into().unwrap();
-		super::register_cost::<R>(handle, R::WeightInfo::precompile_evolve(token_uri_size))?;
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_evolve(token_uri_size).saturating_add(R::OnEvolve::weight()),
+		)?;
 
-		// TODO this might be remove when we have the bounded string as param
-		let token_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = token_uri
-			.as_bytes()
-			.to_vec()
-			.try_into()
-			.map_err(|_| revert("invalid token uri length"))?;
+		evolve_with_logs::<R>(collection_id, handle, token_id, token_uri)
+	}
+
+	#[precompile::public("evolveWithSignature(uint256,string,uint256,bytes)")]
+	pub fn evolve_with_signature(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		token_uri: UnboundedString,
+		deadline: U256,
+		signature: UnboundedBytes,
+	) -> EvmResult<()> {
+		let token_uri_size = token_uri.as_bytes().len().try_into().unwrap();
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_evolve_with_signature(token_uri_size)
+				.saturating_add(R::OnEvolve::weight()),
+		)?;
+
+		if deadline < U256::from(R::Timestamp::now().as_secs()) {
+			return Err(revert("expired deadline"));
+		}
+		if !<LaosEvolution<R> as EvolutionCollectionConsent<_>>::evolution_consent_required(
+			collection_id,
+		) {
+			return Err(revert("evolution consent is not required"));
+		}
+
+		let version = <LaosEvolution<R> as EvolutionCollectionHistory<_>>::token_uri_version(
+			collection_id,
+			token_id,
+		);
+		let digest = evolution_authorization_digest::<R>(
+			handle.context().address,
+			token_id,
+			token_uri.as_bytes(),
+			version,
+			deadline,
+		);
+		let signer = recover_signer(&digest, signature.as_bytes())
+			.ok_or_else(|| revert("invalid signature"))?;
 
-		match LaosEvolution::<R>::evolve_with_external_uri(
+		// the approval is consumed by the evolution, which also bumps the version of the token
+		// URI so that the signature cannot be replayed
+		<LaosEvolution<R> as EvolutionCollectionConsent<_>>::approve_evolution(
+			R::AccountIdToH160::convert_back(signer),
+			collection_id,
+			token_id,
+			H256(keccak_256(token_uri.as_bytes())),
+		)
+		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+
+		evolve_with_logs::<R>(collection_id, handle, token_id, token_uri)
+	}
+
+	#[precompile::public("approveEvolution(uint256,bytes32)")]
+	pub fn approve_evolution(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		uri_hash: H256,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_approve_evolution())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionConsent<_>>::approve_evolution(
 			R::AccountIdToH160::convert_back(handle.context().caller),
 			collection_id,
 			token_id,
-			token_uri_bounded.clone(),
+			uri_hash,
 		) {
 			Ok(()) => {
 				let mut token_id_bytes = [0u8; 32];
@@ -154,9 +685,9 @@ where
 
 				log2(
 					handle.context().address,
-					SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+					SELECTOR_LOG_EVOLUTION_APPROVED,
 					token_id_bytes,
-					solidity::encode_event_data(token_uri),
+					solidity::encode_event_data(uri_hash),
 				)
 				.record(handle)?;
 
@@ -166,32 +697,1026 @@ where
 		}
 	}
 
-	#[precompile::public("transferOwnership(address)")]
-	pub fn transfer_ownership(
+	#[precompile::public("setEvolutionConsentRequired(bool)")]
+	pub fn set_evolution_consent_required(
 		collection_id: CollectionId,
 		handle: &mut impl PrecompileHandle,
-		to: Address,
+		required: bool,
 	) -> EvmResult<()> {
-		super::register_cost::<R>(handle, R::WeightInfo::precompile_transfer_ownership())?;
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_set_evolution_consent_required(),
+		)?;
 
-		let to: H160 = to.into();
-		LaosEvolution::<R>::transfer_ownership(
+		match <LaosEvolution<R> as EvolutionCollectionConsent<_>>::set_evolution_consent_required(
 			R::AccountIdToH160::convert_back(handle.context().caller),
-			R::AccountIdToH160::convert_back(to),
 			collection_id,
-		)
-		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+			required,
+		) {
+			Ok(()) => {
+				log1(
+					handle.context().address,
+					SELECTOR_LOG_EVOLUTION_CONSENT_REQUIRED_SET,
+					solidity::encode_event_data(required),
+				)
+				.record(handle)?;
 
-		log3(
-			handle.context().address,
-			SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
-			handle.context().caller,
-			to,
-			solidity::encode_event_data(()),
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("evolutionConsentRequired()")]
+	#[precompile::view]
+	pub fn evolution_consent_required(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<bool> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_evolution_consent_required())?;
+
+		Ok(<LaosEvolution<R> as EvolutionCollectionConsent<_>>::evolution_consent_required(
+			collection_id,
+		))
+	}
+
+	#[precompile::public("batchMintWithExternalURI(address[],uint96[],string[])")]
+	pub fn batch_mint(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		to: Vec<Address>,
+		slots: Vec<Slot>,
+		token_uris: Vec<UnboundedString>,
+	) -> EvmResult<Vec<U256>> {
+		let batch_size = token_uris.len().try_into().unwrap();
+		let max_token_uri_size = max_token_uri_size(&token_uris);
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_batch_mint(batch_size, max_token_uri_size)
+				.saturating_add(R::OnMint::weight().saturating_mul(batch_size.into())),
+		)?;
+
+		if to.len() != token_uris.len() || slots.len() != token_uris.len() {
+			return Err(revert("input arrays length mismatch"));
+		}
+
+		let mints = to
+			.iter()
+			.zip(slots.iter())
+			.zip(token_uris.iter())
+			.map(|((to, slot), token_uri)| -> EvmResult<_> {
+				// TODO this might be remove when we have the bounded string as param
+				let token_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = token_uri
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.map_err(|_| revert("invalid token uri length"))?;
+
+				Ok((*slot, R::AccountIdToH160::convert_back(to.0), token_uri_bounded))
+			})
+			.collect::<EvmResult<Vec<_>>>()?;
+
+		match LaosEvolution::<R>::batch_mint_with_external_uri(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			mints,
+		) {
+			Ok(token_ids) => {
+				for (((to, slot), token_uri), token_id) in
+					to.into_iter().zip(slots).zip(token_uris).zip(token_ids.iter())
+				{
+					record_mint_logs(handle, to.0, slot, *token_id, token_uri)?;
+				}
+
+				Ok(token_ids)
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("batchEvolveWithExternalURI(uint256[],string[])")]
+	pub fn batch_evolve(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_ids: Vec<TokenId>,
+		token_uris: Vec<UnboundedString>,
+	) -> EvmResult<()> {
+		let batch_size = token_uris.len().try_into().unwrap();
+		let max_token_uri_size = max_token_uri_size(&token_uris);
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_batch_evolve(batch_size, max_token_uri_size)
+				.saturating_add(R::OnEvolve::weight().saturating_mul(batch_size.into())),
+		)?;
+
+		if token_ids.len() != token_uris.len() {
+			return Err(revert("input arrays length mismatch"));
+		}
+
+		let evolutions = token_ids
+			.iter()
+			.zip(token_uris.iter())
+			.map(|(token_id, token_uri)| -> EvmResult<_> {
+				// TODO this might be remove when we have the bounded string as param
+				let token_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = token_uri
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.map_err(|_| revert("invalid token uri length"))?;
+
+				Ok((*token_id, token_uri_bounded))
+			})
+			.collect::<EvmResult<Vec<_>>>()?;
+
+		match LaosEvolution::<R>::batch_evolve_with_external_uri(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			evolutions,
+		) {
+			Ok(()) => {
+				// a range of token ids also covers tokens that have not been evolved, so it is only
+				// used when the evolved tokens are exactly the tokens of the range
+				let contiguous_range = contiguous_range(&token_ids);
+
+				for (token_id, token_uri) in token_ids.into_iter().zip(token_uris) {
+					let mut token_id_bytes = [0u8; 32];
+					token_id.to_big_endian(&mut token_id_bytes);
+
+					log2(
+						handle.context().address,
+						SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+						token_id_bytes,
+						solidity::encode_event_data(token_uri),
+					)
+					.record(handle)?;
+
+					if contiguous_range.is_none() {
+						log1(
+							handle.context().address,
+							SELECTOR_LOG_METADATA_UPDATE,
+							solidity::encode_event_data(token_id),
+						)
+						.record(handle)?;
+					}
+				}
+
+				if let Some((from, to)) = contiguous_range {
+					log1(
+						handle.context().address,
+						SELECTOR_LOG_BATCH_METADATA_UPDATE,
+						solidity::encode_event_data((from, to)),
+					)
+					.record(handle)?;
+				}
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("transferOwnership(address)")]
+	pub fn transfer_ownership(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		to: Address,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_transfer_ownership())?;
+
+		let to: H160 = to.into();
+		<LaosEvolution<R> as EvolutionCollection<_, _>>::transfer_ownership(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			R::AccountIdToH160::convert_back(to),
+			collection_id,
+		)
+		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_OWNERSHIP_TRANSFER_STARTED,
+			handle.context().caller,
+			to,
+			solidity::encode_event_data(()),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("acceptOwnership()")]
+	pub fn accept_ownership(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_accept_ownership()
+				.saturating_add(R::OnOwnershipTransferred::weight()),
+		)?;
+
+		let previous_owner = LaosEvolution::<R>::collection_owner(collection_id)
+			.map(R::AccountIdToH160::convert)
+			.unwrap_or_default();
+
+		<LaosEvolution<R> as EvolutionCollectionOwnership<_>>::accept_ownership(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+		)
+		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+			previous_owner,
+			handle.context().caller,
+			solidity::encode_event_data(()),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("cancelOwnershipTransfer()")]
+	pub fn cancel_ownership_transfer(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_cancel_ownership_transfer())?;
+
+		let pending_owner = LaosEvolution::<R>::pending_owner(collection_id)
+			.map(R::AccountIdToH160::convert)
+			.unwrap_or_default();
+
+		<LaosEvolution<R> as EvolutionCollectionOwnership<_>>::cancel_ownership_transfer(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+		)
+		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_OWNERSHIP_TRANSFER_CANCELED,
+			handle.context().caller,
+			pending_owner,
+			solidity::encode_event_data(()),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("renounceOwnership()")]
+	pub fn renounce_ownership(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_renounce_ownership()
+				.saturating_add(R::OnOwnershipTransferred::weight()),
+		)?;
+
+		<LaosEvolution<R> as EvolutionCollectionOwnership<_>>::renounce_ownership(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+		)
+		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+			handle.context().caller,
+			H160::zero(),
+			solidity::encode_event_data(()),
 		)
 		.record(handle)?;
 
-		Ok(())
+		Ok(())
+	}
+
+	#[precompile::public("pendingOwner()")]
+	#[precompile::view]
+	pub fn pending_owner(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<Address> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_pending_owner())?;
+
+		Ok(Address(
+			LaosEvolution::<R>::pending_owner(collection_id)
+				.map(R::AccountIdToH160::convert)
+				.unwrap_or_default(),
+		))
+	}
+
+	#[precompile::public("burn(uint256)")]
+	pub fn burn(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+	) -> EvmResult<()> {
+		// every attribute and the whole history of the token may be removed
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_burn(
+				<R as Config>::MaxAttributesPerToken::get(),
+				<R as Config>::MaxUriHistory::get(),
+			),
+		)?;
+
+		match LaosEvolution::<R>::burn(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			token_id,
+		) {
+			Ok(()) => {
+				let mut token_id_bytes = [0u8; 32];
+				token_id.to_big_endian(&mut token_id_bytes);
+
+				log4(
+					handle.context().address,
+					SELECTOR_LOG_TRANSFER,
+					token_id_to_initial_owner(token_id),
+					H160::zero(),
+					token_id_bytes,
+					solidity::encode_event_data(()),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("freezeToken(uint256)")]
+	pub fn freeze_token(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_freeze_token())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionFreezing<_>>::freeze_token(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			token_id,
+		) {
+			Ok(()) => {
+				let token_uri =
+					LaosEvolution::<R>::token_uri(collection_id, token_id).unwrap_or_default();
+				let mut token_id_bytes = [0u8; 32];
+				token_id.to_big_endian(&mut token_id_bytes);
+
+				log2(
+					handle.context().address,
+					SELECTOR_LOG_PERMANENT_URI,
+					token_id_bytes,
+					solidity::encode_event_data(UnboundedString::from(token_uri.to_vec())),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("freezeCollection()")]
+	pub fn freeze_collection(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_freeze_collection())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionFreezing<_>>::freeze_collection(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+		) {
+			Ok(()) => {
+				log1(
+					handle.context().address,
+					SELECTOR_LOG_COLLECTION_FROZEN,
+					solidity::encode_event_data(()),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("isFrozen(uint256)")]
+	#[precompile::view]
+	pub fn is_frozen(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+	) -> EvmResult<bool> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_is_frozen())?;
+
+		Ok(<LaosEvolution<R> as EvolutionCollectionFreezing<_>>::is_frozen(collection_id, token_id))
+	}
+
+	#[precompile::public("grantRole(bytes32,address)")]
+	pub fn grant_role(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		role: H256,
+		account: Address,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_grant_role())?;
+
+		let role_id = role;
+		let role = role_from_bytes32(role_id)?;
+
+		let account: H160 = account.into();
+		let had_role = <LaosEvolution<R> as EvolutionCollectionRoles<_>>::has_role(
+			collection_id,
+			role,
+			R::AccountIdToH160::convert_back(account),
+		);
+
+		<LaosEvolution<R> as EvolutionCollectionRoles<_>>::grant_role(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			role,
+			R::AccountIdToH160::convert_back(account),
+		)
+		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+
+		if !had_role {
+			log4(
+				handle.context().address,
+				SELECTOR_LOG_ROLE_GRANTED,
+				role_id,
+				account,
+				handle.context().caller,
+				solidity::encode_event_data(()),
+			)
+			.record(handle)?;
+		}
+
+		Ok(())
+	}
+
+	#[precompile::public("revokeRole(bytes32,address)")]
+	pub fn revoke_role(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		role: H256,
+		account: Address,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_revoke_role())?;
+
+		let role_id = role;
+		let role = role_from_bytes32(role_id)?;
+
+		let account: H160 = account.into();
+		let had_role = <LaosEvolution<R> as EvolutionCollectionRoles<_>>::has_role(
+			collection_id,
+			role,
+			R::AccountIdToH160::convert_back(account),
+		);
+
+		<LaosEvolution<R> as EvolutionCollectionRoles<_>>::revoke_role(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			role,
+			R::AccountIdToH160::convert_back(account),
+		)
+		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+
+		if had_role {
+			log4(
+				handle.context().address,
+				SELECTOR_LOG_ROLE_REVOKED,
+				role_id,
+				account,
+				handle.context().caller,
+				solidity::encode_event_data(()),
+			)
+			.record(handle)?;
+		}
+
+		Ok(())
+	}
+
+	#[precompile::public("hasRole(bytes32,address)")]
+	#[precompile::view]
+	pub fn has_role(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		role: H256,
+		account: Address,
+	) -> EvmResult<bool> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_has_role())?;
+
+		let role = role_from_bytes32(role)?;
+
+		Ok(<LaosEvolution<R> as EvolutionCollectionRoles<_>>::has_role(
+			collection_id,
+			role,
+			R::AccountIdToH160::convert_back(account.into()),
+		))
+	}
+
+	#[precompile::public("setCollectionMetadata(string,string,string)")]
+	pub fn set_collection_metadata(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		name: UnboundedString,
+		symbol: UnboundedString,
+		contract_uri: UnboundedString,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_set_collection_metadata())?;
+
+		let metadata = super::collection_metadata::<R>(name, symbol, contract_uri)?;
+
+		<LaosEvolution<R> as EvolutionCollectionMetadata<_, _>>::set_collection_metadata(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			metadata,
+		)
+		.map_err(|err| revert(convert_dispatch_error_to_string(err)))?;
+
+		log1(
+			handle.context().address,
+			SELECTOR_LOG_CONTRACT_URI_UPDATED,
+			solidity::encode_event_data(()),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("name()")]
+	#[precompile::view]
+	pub fn name(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedString> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_collection_metadata())?;
+
+		Ok(LaosEvolution::<R>::collection_metadata(collection_id)
+			.unwrap_or_default()
+			.name
+			.to_vec()
+			.into())
+	}
+
+	#[precompile::public("symbol()")]
+	#[precompile::view]
+	pub fn symbol(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedString> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_collection_metadata())?;
+
+		Ok(LaosEvolution::<R>::collection_metadata(collection_id)
+			.unwrap_or_default()
+			.symbol
+			.to_vec()
+			.into())
+	}
+
+	#[precompile::public("contractURI()")]
+	#[precompile::view]
+	pub fn contract_uri(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedString> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_collection_metadata())?;
+
+		Ok(LaosEvolution::<R>::collection_metadata(collection_id)
+			.unwrap_or_default()
+			.contract_uri
+			.to_vec()
+			.into())
+	}
+
+	#[precompile::public("setDefaultRoyalty(address,uint16)")]
+	pub fn set_default_royalty(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		receiver: Address,
+		basis_points: BasisPoints,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_set_default_royalty())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionRoyalties<_>>::set_default_royalty(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			R::AccountIdToH160::convert_back(receiver.into()),
+			basis_points,
+		) {
+			Ok(()) => {
+				log2(
+					handle.context().address,
+					SELECTOR_LOG_DEFAULT_ROYALTY_SET,
+					receiver.0,
+					solidity::encode_event_data(basis_points),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("setTokenRoyalty(uint256,address,uint16)")]
+	pub fn set_token_royalty(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		receiver: Address,
+		basis_points: BasisPoints,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_set_token_royalty())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionRoyalties<_>>::set_token_royalty(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			token_id,
+			R::AccountIdToH160::convert_back(receiver.into()),
+			basis_points,
+		) {
+			Ok(()) => {
+				let mut token_id_bytes = [0u8; 32];
+				token_id.to_big_endian(&mut token_id_bytes);
+
+				log3(
+					handle.context().address,
+					SELECTOR_LOG_TOKEN_ROYALTY_SET,
+					token_id_bytes,
+					receiver.0,
+					solidity::encode_event_data(basis_points),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("royaltyInfo(uint256,uint256)")]
+	#[precompile::view]
+	pub fn royalty_info(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		sale_price: U256,
+	) -> EvmResult<(Address, U256)> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_royalty_info())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionRoyalties<_>>::royalty_info(
+			collection_id,
+			token_id,
+		) {
+			Some(royalty) => {
+				// `basis_points` never exceeds `MAX_ROYALTY_BASIS_POINTS`, so the amount fits in
+				// a `U256`
+				let royalty_amount = sale_price.full_mul(U256::from(royalty.basis_points)) /
+					U512::from(MAX_ROYALTY_BASIS_POINTS);
+
+				Ok((
+					Address(R::AccountIdToH160::convert(royalty.receiver)),
+					U256::try_from(royalty_amount).map_err(|_| revert("royalty overflow"))?,
+				))
+			},
+			None => Ok((Address(H160::zero()), U256::zero())),
+		}
+	}
+
+	#[precompile::public("supportsInterface(bytes4)")]
+	#[precompile::view]
+	pub fn supports_interface(
+		_collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		interface_id: InterfaceId,
+	) -> EvmResult<bool> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_supports_interface())?;
+
+		Ok(SUPPORTED_INTERFACES.contains(&interface_id.0))
+	}
+
+	#[precompile::public("initialOwnerOf(uint256)")]
+	#[precompile::view]
+	pub fn initial_owner_of(
+		_collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+	) -> EvmResult<Address> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_initial_owner_of())?;
+
+		Ok(Address(token_id_to_initial_owner(token_id)))
+	}
+
+	#[precompile::public("slotOf(uint256)")]
+	#[precompile::view]
+	pub fn slot_of(
+		_collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+	) -> EvmResult<Slot> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_slot_of())?;
+
+		Ok(token_id_to_slot(token_id))
+	}
+
+	#[precompile::public("tokenURIVersion(uint256)")]
+	#[precompile::view]
+	pub fn token_uri_version(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+	) -> EvmResult<u32> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_token_uri_version())?;
+
+		if LaosEvolution::<R>::token_uri(collection_id, token_id).is_none() {
+			return Err(revert("asset does not exist"));
+		}
+
+		Ok(<LaosEvolution<R> as EvolutionCollectionHistory<_>>::token_uri_version(
+			collection_id,
+			token_id,
+		))
+	}
+
+	#[precompile::public("tokenURIAt(uint256,uint32)")]
+	#[precompile::view]
+	pub fn token_uri_at(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		version: u32,
+	) -> EvmResult<UnboundedString> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_token_uri_at())?;
+
+		if LaosEvolution::<R>::token_uri(collection_id, token_id).is_none() {
+			return Err(revert("asset does not exist"));
+		}
+
+		match <LaosEvolution<R> as EvolutionCollectionHistory<_>>::token_uri_at(
+			collection_id,
+			token_id,
+			version,
+		) {
+			Some(token_uri) => Ok(token_uri.to_vec().into()),
+			None => Err(revert("version does not exist")),
+		}
+	}
+
+	#[precompile::public("setBaseURI(string)")]
+	pub fn set_base_uri(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		base_uri: UnboundedString,
+	) -> EvmResult<()> {
+		let base_uri_size = base_uri.as_bytes().len().try_into().unwrap();
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_set_base_uri(base_uri_size))?;
+
+		let base_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = base_uri
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| revert("invalid base uri length"))?;
+
+		match <LaosEvolution<R> as EvolutionCollectionBaseUri<_, _>>::set_base_uri(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			base_uri_bounded,
+		) {
+			Ok(()) => {
+				log1(
+					handle.context().address,
+					SELECTOR_LOG_BASE_URI_SET,
+					solidity::encode_event_data(base_uri),
+				)
+				.record(handle)?;
+
+				// the token URI of every token minted with an empty URI changes
+				log1(
+					handle.context().address,
+					SELECTOR_LOG_BATCH_METADATA_UPDATE,
+					solidity::encode_event_data((U256::zero(), U256::MAX)),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("baseURI()")]
+	#[precompile::view]
+	pub fn base_uri(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedString> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_base_uri())?;
+
+		Ok(<LaosEvolution<R> as EvolutionCollectionBaseUri<_, _>>::base_uri(collection_id)
+			.unwrap_or_default()
+			.to_vec()
+			.into())
+	}
+
+	#[precompile::public("scheduleEvolution(uint256,string,uint256)")]
+	pub fn schedule_evolution(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		token_uri: UnboundedString,
+		block: U256,
+	) -> EvmResult<()> {
+		let token_uri_size = token_uri.as_bytes().len().try_into().unwrap();
+		super::register_cost::<R>(
+			handle,
+			R::WeightInfo::precompile_schedule_evolution(token_uri_size),
+		)?;
+
+		let token_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = token_uri
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| revert("invalid token uri length"))?;
+		let block_number: u64 = block.try_into().map_err(|_| revert("invalid block number"))?;
+
+		match <LaosEvolution<R> as EvolutionCollectionScheduling<_, _>>::schedule_evolution(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			token_id,
+			token_uri_bounded,
+			block_number,
+		) {
+			Ok(()) => {
+				let mut token_id_bytes = [0u8; 32];
+				token_id.to_big_endian(&mut token_id_bytes);
+
+				log2(
+					handle.context().address,
+					SELECTOR_LOG_EVOLUTION_SCHEDULED,
+					token_id_bytes,
+					solidity::encode_event_data((token_uri, block)),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("cancelScheduledEvolution(uint256)")]
+	pub fn cancel_scheduled_evolution(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_cancel_scheduled_evolution())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionScheduling<_, _>>::cancel_scheduled_evolution(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			token_id,
+		) {
+			Ok(()) => {
+				let mut token_id_bytes = [0u8; 32];
+				token_id.to_big_endian(&mut token_id_bytes);
+
+				log2(
+					handle.context().address,
+					SELECTOR_LOG_SCHEDULED_EVOLUTION_CANCELED,
+					token_id_bytes,
+					solidity::encode_event_data(()),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("scheduledEvolution(uint256)")]
+	#[precompile::view]
+	pub fn scheduled_evolution(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+	) -> EvmResult<(U256, UnboundedString)> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_scheduled_evolution())?;
+
+		match LaosEvolution::<R>::scheduled_evolution(collection_id, token_id) {
+			Some((block, token_uri)) => {
+				let block: u64 = block.try_into().map_err(|_| revert("invalid block number"))?;
+
+				Ok((U256::from(block), token_uri.to_vec().into()))
+			},
+			None => Err(revert("token has no scheduled evolution")),
+		}
+	}
+
+	#[precompile::public("setAttribute(uint256,bytes32,bytes)")]
+	pub fn set_attribute(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		key: H256,
+		value: UnboundedBytes,
+	) -> EvmResult<()> {
+		let value_size = value.as_bytes().len().try_into().unwrap();
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_set_attribute(value_size))?;
+
+		let value_bounded: AttributeValueOf<R> = value
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| revert("invalid attribute value length"))?;
+
+		match <LaosEvolution<R> as EvolutionCollectionAttributes<_, _>>::set_attribute(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			token_id,
+			key,
+			value_bounded,
+		) {
+			Ok(()) => {
+				let mut token_id_bytes = [0u8; 32];
+				token_id.to_big_endian(&mut token_id_bytes);
+
+				log3(
+					handle.context().address,
+					SELECTOR_LOG_ATTRIBUTE_SET,
+					token_id_bytes,
+					key,
+					solidity::encode_event_data(value),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("removeAttribute(uint256,bytes32)")]
+	pub fn remove_attribute(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		key: H256,
+	) -> EvmResult<()> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_remove_attribute())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionAttributes<_, _>>::remove_attribute(
+			R::AccountIdToH160::convert_back(handle.context().caller),
+			collection_id,
+			token_id,
+			key,
+		) {
+			Ok(()) => {
+				let mut token_id_bytes = [0u8; 32];
+				token_id.to_big_endian(&mut token_id_bytes);
+
+				log3(
+					handle.context().address,
+					SELECTOR_LOG_ATTRIBUTE_REMOVED,
+					token_id_bytes,
+					key,
+					solidity::encode_event_data(()),
+				)
+				.record(handle)?;
+
+				Ok(())
+			},
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("getAttribute(uint256,bytes32)")]
+	#[precompile::view]
+	pub fn get_attribute(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		token_id: TokenId,
+		key: H256,
+	) -> EvmResult<UnboundedBytes> {
+		super::register_cost::<R>(handle, R::WeightInfo::precompile_get_attribute())?;
+
+		match <LaosEvolution<R> as EvolutionCollectionAttributes<_, _>>::attribute(
+			collection_id,
+			token_id,
+			key,
+		) {
+			Some(value) => Ok(value.to_vec().into()),
+			None => Err(revert("attribute does not exist")),
+		}
 	}
 
 	#[precompile::public("tokenURI(uint256)")]
@@ -211,6 +1736,198 @@ where
 	}
 }
 
+/// Returns the length of the longest token URI in `token_uris`, used to weight batch operations
+fn max_token_uri_size(token_uris: &[UnboundedString]) -> u32 {
+	token_uris
+		.iter()
+		.map(|token_uri| token_uri.as_bytes().len())
+		.max()
+		.unwrap_or_default()
+		.try_into()
+		.unwrap()
+}
+
+/// Records the logs of minting `token_id` in `slot` for `to` with `token_uri`
+fn record_mint_logs(
+	handle: &mut impl PrecompileHandle,
+	to: H160,
+	slot: Slot,
+	token_id: TokenId,
+	token_uri: UnboundedString,
+) -> EvmResult<()> {
+	log2(
+		handle.context().address,
+		SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI,
+		to,
+		solidity::encode_event_data((slot, token_id, token_uri)),
+	)
+	.record(handle)?;
+
+	let mut token_id_bytes = [0u8; 32];
+	token_id.to_big_endian(&mut token_id_bytes);
+
+	log4(
+		handle.context().address,
+		SELECTOR_LOG_TRANSFER,
+		H160::zero(),
+		to,
+		token_id_bytes,
+		solidity::encode_event_data(()),
+	)
+	.record(handle)?;
+
+	Ok(())
+}
+
+/// Evolves `token_id` to `token_uri` on behalf of the caller and records the logs of the evolution
+fn evolve_with_logs<R: Config>(
+	collection_id: CollectionId,
+	handle: &mut impl PrecompileHandle,
+	token_id: TokenId,
+	token_uri: UnboundedString,
+) -> EvmResult<()> {
+	// TODO this might be remove when we have the bounded string as param
+	let token_uri_bounded: BoundedVec<u8, <R as Config>::MaxTokenUriLength> = token_uri
+		.as_bytes()
+		.to_vec()
+		.try_into()
+		.map_err(|_| revert("invalid token uri length"))?;
+
+	match <LaosEvolution<R> as EvolutionCollection<_, _>>::evolve_with_external_uri(
+		R::AccountIdToH160::convert_back(handle.context().caller),
+		collection_id,
+		token_id,
+		token_uri_bounded.clone(),
+	) {
+		Ok(()) => {
+			let mut token_id_bytes = [0u8; 32];
+			token_id.to_big_endian(&mut token_id_bytes);
+
+			log2(
+				handle.context().address,
+				SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+				token_id_bytes,
+				solidity::encode_event_data(token_uri),
+			)
+			.record(handle)?;
+
+			log1(
+				handle.context().address,
+				SELECTOR_LOG_METADATA_UPDATE,
+				solidity::encode_event_data(token_id),
+			)
+			.record(handle)?;
+
+			Ok(())
+		},
+		Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+	}
+}
+
+/// Returns the first and last token ids of `token_ids` if they are all the token ids of that
+/// range, and there are at least two of them
+fn contiguous_range(token_ids: &[TokenId]) -> Option<(TokenId, TokenId)> {
+	let mut token_ids = token_ids.to_vec();
+	token_ids.sort_unstable();
+	token_ids.dedup();
+
+	let (from, to) = (*token_ids.first()?, *token_ids.last()?);
+	let contiguous = token_ids.windows(2).all(|pair| pair[1] == pair[0] + 1);
+	(from != to && contiguous).then_some((from, to))
+}
+
+/// Returns the EIP-712 digest that the owner of the collection at `collection_address` signs to
+/// authorize minting `slot` to `to` with `nonce`
+pub(crate) fn mint_authorization_digest<R: Config>(
+	collection_address: H160,
+	to: H160,
+	slot: Slot,
+	token_uri: &[u8],
+	nonce: U256,
+	deadline: U256,
+) -> [u8; 32] {
+	let struct_hash = keccak_256(&solidity::encode_arguments((
+		H256(MINT_WITH_SIGNATURE_TYPEHASH),
+		Address(to),
+		slot,
+		H256(keccak_256(token_uri)),
+		nonce,
+		deadline,
+	)));
+
+	typed_data_digest::<R>(collection_address, struct_hash)
+}
+
+/// Returns the EIP-712 digest that the initial owner of `token_id` signs to authorize evolving it
+/// from `version` to `token_uri` in the collection at `collection_address`
+pub(crate) fn evolution_authorization_digest<R: Config>(
+	collection_address: H160,
+	token_id: TokenId,
+	token_uri: &[u8],
+	version: u32,
+	deadline: U256,
+) -> [u8; 32] {
+	let struct_hash = keccak_256(&solidity::encode_arguments((
+		H256(EVOLVE_WITH_SIGNATURE_TYPEHASH),
+		token_id,
+		H256(keccak_256(token_uri)),
+		version,
+		deadline,
+	)));
+
+	typed_data_digest::<R>(collection_address, struct_hash)
+}
+
+/// Returns the EIP-712 digest of `struct_hash` in the domain of the collection at
+/// `collection_address`
+fn typed_data_digest<R: Config>(collection_address: H160, struct_hash: [u8; 32]) -> [u8; 32] {
+	let domain_separator = keccak_256(&solidity::encode_arguments((
+		H256(EIP712_DOMAIN_TYPEHASH),
+		H256(keccak_256(EIP712_DOMAIN_NAME)),
+		H256(keccak_256(EIP712_DOMAIN_VERSION)),
+		U256::from(R::ChainId::get()),
+		Address(collection_address),
+	)));
+
+	keccak_256(&[b"\x19\x01".as_slice(), &domain_separator, &struct_hash].concat())
+}
+
+/// Half of the order of the secp256k1 curve, the largest `s` of a signature in canonical form
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+	0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+	0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// Recovers the address that signed `digest`, returning `None` if `signature` is not a valid
+/// 65-byte `r || s || v` signature
+///
+/// As in `ecrecover` based verifiers, `s` must be in the lower half of the order of the curve
+/// and `v` must be 27 or 28 (or 0 or 1), so that every signed message has a single valid
+/// signature.
+fn recover_signer(digest: &[u8; 32], signature: &[u8]) -> Option<H160> {
+	let mut signature: [u8; 65] = signature.try_into().ok()?;
+	if signature[32..64] > SECP256K1_HALF_ORDER[..] {
+		return None;
+	}
+	signature[64] = match signature[64] {
+		0 | 27 => 0,
+		1 | 28 => 1,
+		_ => return None,
+	};
+	let public_key = secp256k1_ecdsa_recover(&signature, digest).ok()?;
+
+	Some(H160::from_slice(&keccak_256(&public_key)[12..]))
+}
+
+/// Converts a Solidity role identifier to a `Role`, reverting for unknown roles
+fn role_from_bytes32(role: H256) -> EvmResult<Role> {
+	match role.0 {
+		MINTER_ROLE => Ok(Role::Minter),
+		EVOLVER_ROLE => Ok(Role::Evolver),
+		_ => Err(revert("unknown role")),
+	}
+}
+
 fn convert_dispatch_error_to_string(err: DispatchError) -> String {
 	match err {
 		DispatchError::Module(mod_err) => mod_err.message.unwrap_or("Unknown module error").into(),
@@ -21,7 +21,7 @@ use fp_evm::{Context, PrecompileSet};
 use mock::*;
 use precompile_utils::testing::*;
 use solidity::codec::Writer;
-use sp_core::{H160, U256};
+use sp_core::{ecdsa, Pair, H160, H256, U256};
 use std::str::FromStr;
 
 const ALICE: &str = "0xf24FF3a9CF04c71Dbc94D0b566f7A27B94566cac";
@@ -50,6 +50,25 @@ fn create_collection(owner: impl Into<H160>) -> H160 {
 	H160::from_slice(res.output.as_slice()[12..].as_ref())
 }
 
+/// Utility function to create a collection that can never have more than `max_supply` tokens
+fn create_collection_with_max_supply(owner: impl Into<H160>, max_supply: u64) -> H160 {
+	let owner: H160 = owner.into();
+
+	let mut handle = MockHandle::new(
+		Precompile1.into(),
+		Context { address: Precompile1.into(), caller: owner, apparent_value: U256::zero() },
+	);
+	handle.input = FactoryPrecompileCall::create_collection_with_max_supply {
+		owner: Address(owner),
+		max_supply,
+	}
+	.into();
+
+	let res = precompiles().execute(&mut handle).unwrap().unwrap();
+
+	H160::from_slice(res.output.as_slice()[12..].as_ref())
+}
+
 /// Utility function to mint a token with external token uri
 ///
 /// Note: this function is used instead of `PrecompileTesterExt::execute_returns` because the latter
@@ -76,6 +95,83 @@ fn mint(
 	TokenId::from(res.output.as_slice())
 }
 
+/// Returns the key used to sign mint authorizations along with its address
+fn signer() -> (ecdsa::Pair, H160) {
+	let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+	let message = [0u8; 32];
+	let public_key =
+		sp_io::crypto::secp256k1_ecdsa_recover(&pair.sign_prehashed(&message).0, &message).unwrap();
+
+	(pair, H160::from_slice(&sp_io::hashing::keccak_256(&public_key)[12..]))
+}
+
+/// Utility function to sign the authorization to mint `slot` to `to` in a collection with `nonce`
+fn sign_mint(
+	pair: &ecdsa::Pair,
+	collection_address: H160,
+	to: H160,
+	slot: Slot,
+	token_uri: &str,
+	nonce: U256,
+	deadline: U256,
+) -> UnboundedBytes {
+	let digest = mint_authorization_digest::<Test>(
+		collection_address,
+		to,
+		slot,
+		token_uri.as_bytes(),
+		nonce,
+		deadline,
+	);
+
+	pair.sign_prehashed(&digest).0[..].into()
+}
+
+/// Utility function to sign the consent of the initial owner of `token_id` to evolve it from
+/// `version` to `token_uri`
+fn sign_evolution(
+	pair: &ecdsa::Pair,
+	collection_address: H160,
+	token_id: TokenId,
+	token_uri: &str,
+	version: u32,
+) -> UnboundedBytes {
+	let digest = evolution_authorization_digest::<Test>(
+		collection_address,
+		token_id,
+		token_uri.as_bytes(),
+		version,
+		U256::MAX,
+	);
+
+	pair.sign_prehashed(&digest).0[..].into()
+}
+
+/// Utility function to create a collection of `ALICE` that requires the consent of the initial
+/// owner of its tokens to evolve them, with a token minted to `holder`
+fn create_consent_collection_with_token(holder: H160) -> (H160, TokenId) {
+	let alice = H160::from_str(ALICE).unwrap();
+	let collection_address = create_collection(alice);
+	let slot: Slot = 0.try_into().unwrap();
+
+	precompiles()
+		.prepare_test(
+			alice,
+			collection_address,
+			PrecompileCall::mint { to: Address(holder), slot, token_uri: "ipfs://0".into() },
+		)
+		.execute_some();
+	precompiles()
+		.prepare_test(
+			alice,
+			collection_address,
+			PrecompileCall::set_evolution_consent_required { required: true },
+		)
+		.execute_some();
+
+	(collection_address, crate::slot_and_owner_to_token_id(slot, holder))
+}
+
 #[test]
 fn selectors() {
 	assert!(PrecompileCall::owner_selectors().contains(&0x8DA5CB5B));
@@ -83,6 +179,53 @@ fn selectors() {
 	assert!(PrecompileCall::evolve_selectors().contains(&0x2FD38F4D));
 	assert!(PrecompileCall::transfer_ownership_selectors().contains(&0xF2FDE38B));
 	assert!(PrecompileCall::token_uri_selectors().contains(&0xC87B56DD));
+	assert!(PrecompileCall::burn_selectors().contains(&0x42966C68));
+	assert!(PrecompileCall::batch_mint_selectors().contains(&0x24AA6392));
+	assert!(PrecompileCall::batch_evolve_selectors().contains(&0xF234A01D));
+	assert!(PrecompileCall::freeze_token_selectors().contains(&0xB6854F96));
+	assert!(PrecompileCall::freeze_collection_selectors().contains(&0x8EC1D651));
+	assert!(PrecompileCall::is_frozen_selectors().contains(&0xA0894799));
+	assert!(PrecompileCall::grant_role_selectors().contains(&0x2F2FF15D));
+	assert!(PrecompileCall::revoke_role_selectors().contains(&0xD547741F));
+	assert!(PrecompileCall::has_role_selectors().contains(&0x91D14854));
+	assert!(PrecompileCall::accept_ownership_selectors().contains(&0x79BA5097));
+	assert!(PrecompileCall::cancel_ownership_transfer_selectors().contains(&0x23452B9C));
+	assert!(PrecompileCall::pending_owner_selectors().contains(&0xE30C3978));
+	assert!(PrecompileCall::renounce_ownership_selectors().contains(&0x715018A6));
+	assert!(PrecompileCall::set_collection_metadata_selectors().contains(&0xF2807F94));
+	assert!(PrecompileCall::name_selectors().contains(&0x06FDDE03));
+	assert!(PrecompileCall::symbol_selectors().contains(&0x95D89B41));
+	assert!(PrecompileCall::contract_uri_selectors().contains(&0xE8A3D485));
+	assert!(PrecompileCall::royalty_info_selectors().contains(&0x2A55205A));
+	assert!(PrecompileCall::set_default_royalty_selectors().contains(&0x4331F639));
+	assert!(PrecompileCall::set_token_royalty_selectors().contains(&0x78DB6C53));
+	assert!(PrecompileCall::supports_interface_selectors().contains(&0x01FFC9A7));
+	assert!(PrecompileCall::initial_owner_of_selectors().contains(&0xF2560207));
+	assert!(PrecompileCall::slot_of_selectors().contains(&0x263F3E7E));
+	assert!(PrecompileCall::token_uri_version_selectors().contains(&0xEE3E0CB9));
+	assert!(PrecompileCall::token_uri_at_selectors().contains(&0xDCAE318B));
+	assert!(PrecompileCall::mint_with_signature_selectors().contains(&0x8B5CAB0B));
+	assert!(PrecompileCall::is_mint_nonce_used_selectors().contains(&0x03AFA8B1));
+	assert!(PrecompileCall::invalidate_mint_nonce_selectors().contains(&0xEF27C956));
+	assert!(PrecompileCall::claim_from_drop_selectors().contains(&0x722A1B03));
+	assert!(PrecompileCall::set_drop_root_selectors().contains(&0xD59BA153));
+	assert!(PrecompileCall::drop_root_selectors().contains(&0xFFCA27FB));
+	assert!(PrecompileCall::total_supply_selectors().contains(&0x18160DDD));
+	assert!(PrecompileCall::max_supply_selectors().contains(&0xD5ABEB01));
+	assert!(PrecompileCall::token_by_index_selectors().contains(&0x4F6CCCE7));
+	assert!(PrecompileCall::set_base_uri_selectors().contains(&0x55F804B3));
+	assert!(PrecompileCall::base_uri_selectors().contains(&0x6C0360EB));
+	assert!(PrecompileCall::mint_next_selectors().contains(&0xDB17C3F8));
+	assert!(PrecompileCall::scheduled_evolution_selectors().contains(&0xCDF06BDB));
+	assert!(PrecompileCall::schedule_evolution_selectors().contains(&0xB367582B));
+	assert!(PrecompileCall::cancel_scheduled_evolution_selectors().contains(&0x35BD8822));
+	assert!(PrecompileCall::set_attribute_selectors().contains(&0xA487806A));
+	assert!(PrecompileCall::remove_attribute_selectors().contains(&0xF1B0875D));
+	assert!(PrecompileCall::get_attribute_selectors().contains(&0x54DEA7F6));
+	assert!(PrecompileCall::evolve_with_signature_selectors().contains(&0x7FF2116B));
+	assert!(PrecompileCall::approve_evolution_selectors().contains(&0x45600AA6));
+	assert!(PrecompileCall::set_evolution_consent_required_selectors().contains(&0x0D0C5CAB));
+	assert!(PrecompileCall::evolution_consent_required_selectors().contains(&0xBD4B48FC));
 }
 
 #[test]
@@ -103,17 +246,151 @@ fn check_log_selectors() {
 		hex::encode(SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI),
 		"a7135052b348b0b4e9943bae82d8ef1c5ac225e594ef4271d12f0744cfc98348"
 	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_TRANSFER),
+		"ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_PERMANENT_URI),
+		"a109ba539900bf1b633f956d63c96fc89b814c7287f7aa50a9216d0b55657207"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_COLLECTION_FROZEN),
+		"1ccfbef2687249b5b14e75790af4eccca6582748af5b72e9683dc698939ff47c"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_OWNERSHIP_TRANSFER_STARTED),
+		"38d16b8cac22d99fc7c124b9cd0de2d3fa1faef420bfe791d8c362d765e22700"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_OWNERSHIP_TRANSFER_CANCELED),
+		"e83a760af9d3c86797ea13c8979010086f067cfe3c985b2d03d951248600c50f"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_CONTRACT_URI_UPDATED),
+		"a5d4097edda6d87cb9329af83fb3712ef77eeb13738ffe43cc35a4ce305ad962"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_DEFAULT_ROYALTY_SET),
+		"2c5ea6e4103e78cb101e796fb2dace540362fc542cbff5145eaa24af7dd8fe41"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_TOKEN_ROYALTY_SET),
+		"389b70fb0887f01e83784eb1c4c589f740eca53b00ed0f45e41db5d079719abb"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_DROP_ROOT_SET),
+		"316298aa4c987037120b9498055bb5db0e2e8175322533700afd197f0db6ac73"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_BASE_URI_SET),
+		"f9c7803e94e0d3c02900d8a90893a6d5e90dd04d32a4cfe825520f82bf9f32f6"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_ATTRIBUTE_SET),
+		"840db4c564ec8ec61fd9377b125346993b20659d558d2e066e33c588b60f9fc3"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_ATTRIBUTE_REMOVED),
+		"f98807a9db68b66148badb3bc69b5e39c1f14f20f2ebf92a866767a335665eed"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_EVOLUTION_CONSENT_REQUIRED_SET),
+		"bd8453665bbb57ab6aaf3feecc510170f3b14519b7dec3a4e68283dcc6b92302"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_EVOLUTION_APPROVED),
+		"f925f10a0d9cc7b08670f30dce0e5fffd858c28bcd26f41654438ed692c6bcb7"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_EVOLUTION_SCHEDULED),
+		"d35d327cbb6cb23c0e67f70e969583d757008a6f5a235edd1a0a8ade65ee079e"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_SCHEDULED_EVOLUTION_CANCELED),
+		"e2e5046e18ebc3b7698a740f024d9e194264a8fb6f9909e2052b5bb58a3085fd"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_METADATA_UPDATE),
+		"f8e1a15aba9398e019f0b49df1a4fde98ee17ae345cb5f6b5e2c27f5033e8ce7"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_BATCH_METADATA_UPDATE),
+		"6bd5c950a8d8df17f772f5af37cb3655737899cbf903264b9795592da439661c"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_ROLE_GRANTED),
+		"2f8788117e7eff1d82e926ec794901d17c78024a50270940304540a733656f0d"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_ROLE_REVOKED),
+		"f6391f5c32d9c69d2a47ea670b442974b53935d1edc7fd64eb21e047a839171b"
+	);
+	assert_eq!(
+		hex::encode(MINTER_ROLE),
+		"9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6"
+	);
+	assert_eq!(
+		hex::encode(EVOLVER_ROLE),
+		"cbf38a01544922da03866be84bd120ea288e14e87ae11440145f17719d7a8369"
+	);
 }
 
 #[test]
-fn owner_of_non_existent_collection_should_revert() {
+fn non_existent_collection_is_not_a_precompile() {
 	new_test_ext().execute_with(|| {
 		let collection_address =
 			H160::from_str("fffffffffffffffffffffffe0000000000000000").unwrap();
 
 		precompiles()
 			.prepare_test(Alice, collection_address, PrecompileCall::owner {})
-			.execute_reverts(|r| r == b"collection does not exist");
+			.execute_none();
+	})
+}
+
+#[test]
+fn discriminant_charges_the_lookup_of_the_collection() {
+	new_test_ext().execute_with(|| {
+		let discriminant_cost = <Test as Config>::GasWeightMapping::weight_to_gas(
+			<Test as Config>::WeightInfo::precompile_discriminant(),
+		);
+		let non_existent_collection_address =
+			H160::from_str("fffffffffffffffffffffffe0000000000000001").unwrap();
+		let collection_address = create_collection(Alice);
+
+		assert!(matches!(
+			EvolutionCollectionPrecompileSet::<Test>::discriminant(collection_address, u64::MAX),
+			DiscriminantResult::Some(0, cost) if cost == discriminant_cost
+		));
+		assert!(matches!(
+			EvolutionCollectionPrecompileSet::<Test>::discriminant(
+				non_existent_collection_address,
+				u64::MAX
+			),
+			DiscriminantResult::None(cost) if cost == discriminant_cost
+		));
+		assert!(matches!(
+			EvolutionCollectionPrecompileSet::<Test>::discriminant(
+				collection_address,
+				discriminant_cost - 1
+			),
+			DiscriminantResult::OutOfGas
+		));
+
+		// collections stay precompiles once their ownership has been renounced
+		precompiles()
+			.prepare_test(Alice, collection_address, PrecompileCall::renounce_ownership {})
+			.execute_some();
+		assert!(matches!(
+			EvolutionCollectionPrecompileSet::<Test>::discriminant(collection_address, u64::MAX),
+			DiscriminantResult::Some(0, _)
+		));
+
+		// calls to nonexistent collections only pay for the lookup
+		precompiles()
+			.prepare_test(Alice, non_existent_collection_address, PrecompileCall::owner {})
+			.expect_cost(discriminant_cost)
+			.execute_none();
 	})
 }
 
@@ -153,6 +430,9 @@ fn mint_should_generate_log() {
 				.unwrap();
 		let owner = H160([1u8; 20]);
 
+		let mut token_id_bytes = [0u8; 32];
+		expected_token_id.to_big_endian(&mut token_id_bytes);
+
 		precompiles()
 			.prepare_test(
 				Alice,
@@ -165,6 +445,14 @@ fn mint_should_generate_log() {
 				owner,
 				solidity::encode_event_data((slot, expected_token_id, token_uri)),
 			))
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_TRANSFER,
+				H160::zero(),
+				owner,
+				token_id_bytes,
+				solidity::encode_event_data(()),
+			))
 			.execute_some();
 	});
 }
@@ -283,6 +571,11 @@ fn evolve_generates_log() {
 				token_id_bytes,
 				solidity::encode_event_data(token_uri),
 			))
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_METADATA_UPDATE,
+				solidity::encode_event_data(token_id),
+			))
 			.execute_some();
 	});
 }
@@ -331,7 +624,7 @@ fn expected_cost_owner() {
 		// functions that precompile calls internally.
 		precompiles()
 			.prepare_test(alice, collection_address, PrecompileCall::owner {})
-			.expect_cost(57599000) //  [`WeightToGas`] set to 1:1 in mock
+			.expect_cost(107599000) //  [`WeightToGas`] set to 1:1 in mock
 			.execute_some();
 	})
 }
@@ -358,7 +651,7 @@ fn expected_cost_mint_with_external_uri() {
 					token_uri: "ciao".into(),
 				},
 			)
-			.expect_cost(197820463) // [`WeightToGas`] set to 1:1 in mock
+			.expect_cost(1097825883) // [`WeightToGas`] set to 1:1 in mock
 			.execute_some();
 	})
 }
@@ -368,7 +661,7 @@ fn expected_cost_evolve_with_external_uri() {
 	new_test_ext().execute_with(|| {
 		let alice = H160::from_str(ALICE).unwrap();
 		let collection_address = create_collection(alice);
-		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), Vec::new().into());
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "".into());
 
 		// Expected weight of the precompile call implementation.
 		// Since benchmarking precompiles is not support.try_into().unwrap()ed yet, we are
@@ -382,7 +675,7 @@ fn expected_cost_evolve_with_external_uri() {
 				collection_address,
 				PrecompileCall::evolve { token_id, token_uri: Vec::new().into() },
 			)
-			.expect_cost(196356684) // [`WeightToGas`] set to 1:1 in mock
+			.expect_cost(1196356684) // [`WeightToGas`] set to 1:1 in mock
 			.execute_some();
 	})
 }
@@ -421,7 +714,7 @@ fn non_existent_collection_cannot_be_transferred() {
 				non_existing_collection_address,
 				PrecompileCall::transfer_ownership { to: bob.into() },
 			)
-			.execute_reverts(|r| r == b"CollectionDoesNotExist");
+			.execute_none();
 	})
 }
 
@@ -456,6 +749,17 @@ fn collection_transfer_of_ownership_emits_log() {
 				collection_address,
 				PrecompileCall::transfer_ownership { to: bob.into() },
 			)
+			.expect_log(log3(
+				collection_address,
+				SELECTOR_LOG_OWNERSHIP_TRANSFER_STARTED,
+				alice,
+				bob,
+				vec![],
+			))
+			.execute_some();
+
+		precompiles()
+			.prepare_test(bob, collection_address, PrecompileCall::accept_ownership {})
 			.expect_log(log3(
 				collection_address,
 				SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
@@ -467,6 +771,89 @@ fn collection_transfer_of_ownership_emits_log() {
 	});
 }
 
+#[test]
+fn ownership_changes_only_when_pending_owner_accepts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::pending_owner {})
+			.execute_returns(Address(H160::zero()));
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::transfer_ownership { to: bob.into() },
+			)
+			.execute_some();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::owner {})
+			.execute_returns(Address(alice));
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::pending_owner {})
+			.execute_returns(Address(bob));
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::accept_ownership {})
+			.execute_reverts(|r| r == b"NotPendingOwner");
+
+		precompiles()
+			.prepare_test(bob, collection_address, PrecompileCall::accept_ownership {})
+			.execute_some();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::owner {})
+			.execute_returns(Address(bob));
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::pending_owner {})
+			.execute_returns(Address(H160::zero()));
+	});
+}
+
+#[test]
+fn cancel_ownership_transfer_emits_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::cancel_ownership_transfer {})
+			.execute_reverts(|r| r == b"NoPendingOwnershipTransfer");
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::transfer_ownership { to: bob.into() },
+			)
+			.execute_some();
+
+		precompiles()
+			.prepare_test(bob, collection_address, PrecompileCall::cancel_ownership_transfer {})
+			.execute_reverts(|r| r == b"NoPermission");
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::cancel_ownership_transfer {})
+			.expect_log(log3(
+				collection_address,
+				SELECTOR_LOG_OWNERSHIP_TRANSFER_CANCELED,
+				alice,
+				bob,
+				vec![],
+			))
+			.execute_some();
+
+		precompiles()
+			.prepare_test(bob, collection_address, PrecompileCall::accept_ownership {})
+			.execute_reverts(|r| r == b"NotPendingOwner");
+	});
+}
+
 #[test]
 fn collection_transfer_of_ownership_records_costs() {
 	new_test_ext().execute_with(|| {
@@ -485,3 +872,2373 @@ fn collection_transfer_of_ownership_records_costs() {
 			.execute_some();
 	});
 }
+
+#[test]
+fn burn_a_minted_asset_works() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_uri: UnboundedString = "ciao".into();
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), token_uri);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::burn { token_id })
+			.execute_returns_raw(vec![]);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::token_uri { token_id })
+			.execute_reverts(|r| r == b"asset does not exist");
+	});
+}
+
+#[test]
+fn burn_generates_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_uri: UnboundedString = "ciao".into();
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), token_uri);
+
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::burn { token_id })
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_TRANSFER,
+				alice,
+				H160::zero(),
+				token_id_bytes,
+				vec![],
+			))
+			.execute_some();
+	});
+}
+
+#[test]
+fn when_burn_reverts_should_return_error() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = U256::from(1);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::burn { token_id })
+			.execute_reverts(|r| r == b"AssetDoesNotExist");
+	});
+}
+
+#[test]
+fn non_owner_cannot_burn() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ciao".into());
+
+		precompiles()
+			.prepare_test(bob, collection_address, PrecompileCall::burn { token_id })
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn batch_mint_works_and_generates_logs() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+		let slots: Vec<Slot> = vec![1.try_into().unwrap(), 2.try_into().unwrap()];
+		let token_uris: Vec<UnboundedString> = vec!["ciao".into(), "hola".into()];
+		let token_ids: Vec<TokenId> =
+			slots.iter().map(|slot| crate::slot_and_owner_to_token_id(*slot, bob)).collect();
+
+		let mut first_token_id_bytes = [0u8; 32];
+		token_ids[0].to_big_endian(&mut first_token_id_bytes);
+		let mut second_token_id_bytes = [0u8; 32];
+		token_ids[1].to_big_endian(&mut second_token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::batch_mint {
+					to: vec![Address(bob), Address(bob)],
+					slots: slots.clone(),
+					token_uris: token_uris.clone(),
+				},
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI,
+				bob,
+				solidity::encode_event_data((slots[0], token_ids[0], token_uris[0].clone())),
+			))
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_TRANSFER,
+				H160::zero(),
+				bob,
+				first_token_id_bytes,
+				solidity::encode_event_data(()),
+			))
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI,
+				bob,
+				solidity::encode_event_data((slots[1], token_ids[1], token_uris[1].clone())),
+			))
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_TRANSFER,
+				H160::zero(),
+				bob,
+				second_token_id_bytes,
+				solidity::encode_event_data(()),
+			))
+			.execute_returns(token_ids.clone());
+
+		for (token_id, token_uri) in token_ids.into_iter().zip(token_uris) {
+			precompiles()
+				.prepare_test(alice, collection_address, PrecompileCall::token_uri { token_id })
+				.execute_returns(token_uri);
+		}
+	});
+}
+
+#[test]
+fn batch_mint_with_mismatching_lengths_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::batch_mint {
+					to: vec![Address(alice), Address(alice)],
+					slots: vec![1.try_into().unwrap()],
+					token_uris: vec!["ciao".into()],
+				},
+			)
+			.execute_reverts(|r| r == b"input arrays length mismatch");
+	});
+}
+
+#[test]
+fn batch_mint_reverts_when_any_mint_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let slot: Slot = 1.try_into().unwrap();
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::batch_mint {
+					to: vec![Address(alice), Address(alice)],
+					slots: vec![slot, slot],
+					token_uris: vec!["ciao".into(), "hola".into()],
+				},
+			)
+			.execute_reverts(|r| r == b"AlreadyMinted");
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::token_uri {
+					token_id: crate::slot_and_owner_to_token_id(slot, alice),
+				},
+			)
+			.execute_reverts(|r| r == b"asset does not exist");
+	});
+}
+
+#[test]
+fn batch_evolve_works_and_generates_logs() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_ids = vec![
+			mint(alice, collection_address, 1.try_into().unwrap(), "ciao".into()),
+			mint(alice, collection_address, 2.try_into().unwrap(), "ciao".into()),
+		];
+		let token_uris: Vec<UnboundedString> = vec!["hola".into(), "hello".into()];
+
+		let mut first_token_id_bytes = [0u8; 32];
+		token_ids[0].to_big_endian(&mut first_token_id_bytes);
+		let mut second_token_id_bytes = [0u8; 32];
+		token_ids[1].to_big_endian(&mut second_token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::batch_evolve {
+					token_ids: token_ids.clone(),
+					token_uris: token_uris.clone(),
+				},
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+				first_token_id_bytes,
+				solidity::encode_event_data(token_uris[0].clone()),
+			))
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_METADATA_UPDATE,
+				solidity::encode_event_data(token_ids[0]),
+			))
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+				second_token_id_bytes,
+				solidity::encode_event_data(token_uris[1].clone()),
+			))
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_METADATA_UPDATE,
+				solidity::encode_event_data(token_ids[1]),
+			))
+			.execute_returns_raw(vec![]);
+
+		for (token_id, token_uri) in token_ids.into_iter().zip(token_uris) {
+			precompiles()
+				.prepare_test(alice, collection_address, PrecompileCall::token_uri { token_id })
+				.execute_returns(token_uri);
+		}
+	});
+}
+
+#[test]
+fn batch_evolve_of_contiguous_token_ids_generates_a_batch_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		// tokens in the same slot of consecutive addresses have consecutive ids
+		let token_ids: Vec<TokenId> = [2, 1]
+			.into_iter()
+			.map(|owner| {
+				let to = H160::from_low_u64_be(owner);
+				precompiles()
+					.prepare_test(
+						alice,
+						collection_address,
+						PrecompileCall::mint {
+							to: Address(to),
+							slot: 0.try_into().unwrap(),
+							token_uri: "ciao".into(),
+						},
+					)
+					.execute_some();
+				crate::slot_and_owner_to_token_id(0.try_into().unwrap(), to)
+			})
+			.collect();
+		assert_eq!(token_ids, vec![U256::from(2), U256::from(1)]);
+		let token_uris: Vec<UnboundedString> = vec!["hola".into(), "hello".into()];
+
+		let mut first_token_id_bytes = [0u8; 32];
+		token_ids[0].to_big_endian(&mut first_token_id_bytes);
+		let mut second_token_id_bytes = [0u8; 32];
+		token_ids[1].to_big_endian(&mut second_token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::batch_evolve { token_ids, token_uris: token_uris.clone() },
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+				first_token_id_bytes,
+				solidity::encode_event_data(token_uris[0].clone()),
+			))
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+				second_token_id_bytes,
+				solidity::encode_event_data(token_uris[1].clone()),
+			))
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_BATCH_METADATA_UPDATE,
+				solidity::encode_event_data((U256::from(1), U256::from(2))),
+			))
+			.execute_returns_raw(vec![]);
+	});
+}
+
+#[test]
+fn batch_evolve_with_mismatching_lengths_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 1.try_into().unwrap(), "ciao".into());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::batch_evolve {
+					token_ids: vec![token_id],
+					token_uris: vec!["hola".into(), "hello".into()],
+				},
+			)
+			.execute_reverts(|r| r == b"input arrays length mismatch");
+	});
+}
+
+#[test]
+fn frozen_token_cannot_be_evolved() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ciao".into());
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::is_frozen { token_id })
+			.execute_returns(false);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::freeze_token { token_id })
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::is_frozen { token_id })
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve { token_id, token_uri: "hello".into() },
+			)
+			.execute_reverts(|r| r == b"TokenFrozen");
+	});
+}
+
+#[test]
+fn freeze_token_generates_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_uri: UnboundedString = "ciao".into();
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), token_uri.clone());
+
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::freeze_token { token_id })
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_PERMANENT_URI,
+				token_id_bytes,
+				solidity::encode_event_data(token_uri),
+			))
+			.execute_some();
+	});
+}
+
+#[test]
+fn non_owner_cannot_freeze_token() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ciao".into());
+
+		precompiles()
+			.prepare_test(bob, collection_address, PrecompileCall::freeze_token { token_id })
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn freeze_collection_freezes_all_tokens_and_generates_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ciao".into());
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::freeze_collection {})
+			.expect_log(log1(collection_address, SELECTOR_LOG_COLLECTION_FROZEN, vec![]))
+			.execute_some();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::is_frozen { token_id })
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve { token_id, token_uri: "hello".into() },
+			)
+			.execute_reverts(|r| r == b"TokenFrozen");
+	});
+}
+
+#[test]
+fn granted_minter_can_mint() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+		let role = H256(MINTER_ROLE);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::has_role { role, account: Address(bob) },
+			)
+			.execute_returns(false);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::grant_role { role, account: Address(bob) },
+			)
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_ROLE_GRANTED,
+				role,
+				bob,
+				alice,
+				vec![],
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::has_role { role, account: Address(bob) },
+			)
+			.execute_returns(true);
+
+		let token_id = mint(bob, collection_address, 0.try_into().unwrap(), "ciao".into());
+
+		precompiles()
+			.prepare_test(
+				bob,
+				collection_address,
+				PrecompileCall::evolve { token_id, token_uri: "hello".into() },
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn revoke_role_generates_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+		let role = H256(EVOLVER_ROLE);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::grant_role { role, account: Address(bob) },
+			)
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::revoke_role { role, account: Address(bob) },
+			)
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_ROLE_REVOKED,
+				role,
+				bob,
+				alice,
+				vec![],
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::has_role { role, account: Address(bob) },
+			)
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn non_owner_cannot_grant_role() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				bob,
+				collection_address,
+				PrecompileCall::grant_role { role: H256(MINTER_ROLE), account: Address(bob) },
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn unknown_role_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::grant_role { role: H256::zero(), account: Address(alice) },
+			)
+			.execute_reverts(|r| r == b"unknown role");
+	});
+}
+
+#[test]
+fn renounce_ownership_emits_log_and_clears_owner() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(bob, collection_address, PrecompileCall::renounce_ownership {})
+			.execute_reverts(|r| r == b"NoPermission");
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::renounce_ownership {})
+			.expect_log(log3(
+				collection_address,
+				SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+				alice,
+				H160::zero(),
+				vec![],
+			))
+			.execute_some();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::owner {})
+			.execute_returns(Address(H160::zero()));
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::mint {
+					to: Address(alice),
+					slot: 0.try_into().unwrap(),
+					token_uri: "ciao".into(),
+				},
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn collection_metadata_is_empty_by_default() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::name {})
+			.execute_returns(UnboundedString::from(""));
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::symbol {})
+			.execute_returns(UnboundedString::from(""));
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::contract_uri {})
+			.execute_returns(UnboundedString::from(""));
+	});
+}
+
+#[test]
+fn owner_can_set_collection_metadata() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_collection_metadata {
+					name: "LAOS Punks".into(),
+					symbol: "LPUNK".into(),
+					contract_uri: "ipfs://contract".into(),
+				},
+			)
+			.expect_log(log1(collection_address, SELECTOR_LOG_CONTRACT_URI_UPDATED, vec![]))
+			.execute_some();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::name {})
+			.execute_returns(UnboundedString::from("LAOS Punks"));
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::symbol {})
+			.execute_returns(UnboundedString::from("LPUNK"));
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::contract_uri {})
+			.execute_returns(UnboundedString::from("ipfs://contract"));
+	});
+}
+
+#[test]
+fn non_owner_cannot_set_collection_metadata() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				bob,
+				collection_address,
+				PrecompileCall::set_collection_metadata {
+					name: "LAOS Punks".into(),
+					symbol: "LPUNK".into(),
+					contract_uri: "ipfs://contract".into(),
+				},
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn set_collection_metadata_with_too_long_symbol_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_collection_metadata {
+					name: "LAOS Punks".into(),
+					symbol: vec![b'a'; MaxCollectionSymbolLength::get() as usize + 1].into(),
+					contract_uri: "ipfs://contract".into(),
+				},
+			)
+			.execute_reverts(|r| r == b"invalid symbol length");
+	});
+}
+
+#[test]
+fn royalty_info_is_zero_by_default() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::royalty_info { token_id: 1.into(), sale_price: 10_000.into() },
+			)
+			.execute_returns((Address(H160::zero()), U256::zero()));
+	});
+}
+
+#[test]
+fn set_default_royalty_applies_to_every_token() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_default_royalty { receiver: bob.into(), basis_points: 250 },
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_DEFAULT_ROYALTY_SET,
+				bob,
+				solidity::encode_event_data(250u16),
+			))
+			.execute_some();
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::royalty_info { token_id: 1.into(), sale_price: 1_000_000.into() },
+			)
+			.execute_returns((Address(bob), U256::from(25_000)));
+	});
+}
+
+#[test]
+fn set_token_royalty_overrides_default_royalty() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "".into());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_default_royalty { receiver: alice.into(), basis_points: 250 },
+			)
+			.execute_some();
+
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_token_royalty {
+					token_id,
+					receiver: bob.into(),
+					basis_points: 1_000,
+				},
+			)
+			.expect_log(log3(
+				collection_address,
+				SELECTOR_LOG_TOKEN_ROYALTY_SET,
+				token_id_bytes,
+				bob,
+				solidity::encode_event_data(1_000u16),
+			))
+			.execute_some();
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::royalty_info { token_id, sale_price: U256::MAX },
+			)
+			.execute_returns((Address(bob), U256::MAX / 10));
+	});
+}
+
+#[test]
+fn set_royalty_above_max_basis_points_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_default_royalty {
+					receiver: alice.into(),
+					basis_points: MAX_ROYALTY_BASIS_POINTS + 1,
+				},
+			)
+			.execute_reverts(|r| r == b"RoyaltyTooHigh");
+	});
+}
+
+#[test]
+fn non_owner_cannot_set_royalty() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				bob,
+				collection_address,
+				PrecompileCall::set_default_royalty { receiver: bob.into(), basis_points: 250 },
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn interface_ids_match_selectors() {
+	let xor = |selectors: &[u32]| selectors.iter().fold(0u32, |acc, selector| acc ^ selector);
+	let id = |interface_id: [u8; 4]| u32::from_be_bytes(interface_id);
+
+	assert_eq!(id(INTERFACE_ID_ERC165), PrecompileCall::supports_interface_selectors()[0]);
+	assert_eq!(id(INTERFACE_ID_ERC2981), PrecompileCall::royalty_info_selectors()[0]);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION),
+		xor(&[
+			PrecompileCall::owner_selectors()[0],
+			PrecompileCall::token_uri_selectors()[0],
+			PrecompileCall::mint_selectors()[0],
+			PrecompileCall::evolve_selectors()[0],
+			PrecompileCall::transfer_ownership_selectors()[0],
+			PrecompileCall::initial_owner_of_selectors()[0],
+			PrecompileCall::slot_of_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_METADATA),
+		xor(&[
+			PrecompileCall::name_selectors()[0],
+			PrecompileCall::symbol_selectors()[0],
+			PrecompileCall::contract_uri_selectors()[0],
+			PrecompileCall::set_collection_metadata_selectors()[0],
+			PrecompileCall::set_base_uri_selectors()[0],
+			PrecompileCall::base_uri_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_OWNERSHIP),
+		xor(&[
+			PrecompileCall::accept_ownership_selectors()[0],
+			PrecompileCall::cancel_ownership_transfer_selectors()[0],
+			PrecompileCall::renounce_ownership_selectors()[0],
+			PrecompileCall::pending_owner_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_ROLES),
+		xor(&[
+			PrecompileCall::grant_role_selectors()[0],
+			PrecompileCall::revoke_role_selectors()[0],
+			PrecompileCall::has_role_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_BATCH),
+		xor(&[
+			PrecompileCall::mint_next_selectors()[0],
+			PrecompileCall::batch_mint_selectors()[0],
+			PrecompileCall::batch_evolve_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_SIGNATURES),
+		xor(&[
+			PrecompileCall::mint_with_signature_selectors()[0],
+			PrecompileCall::is_mint_nonce_used_selectors()[0],
+			PrecompileCall::invalidate_mint_nonce_selectors()[0],
+			PrecompileCall::evolve_with_signature_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_DROP),
+		xor(&[
+			PrecompileCall::claim_from_drop_selectors()[0],
+			PrecompileCall::set_drop_root_selectors()[0],
+			PrecompileCall::drop_root_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_SUPPLY),
+		xor(&[
+			PrecompileCall::total_supply_selectors()[0],
+			PrecompileCall::max_supply_selectors()[0],
+			PrecompileCall::token_by_index_selectors()[0],
+			PrecompileCall::burn_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_FREEZING),
+		xor(&[
+			PrecompileCall::freeze_token_selectors()[0],
+			PrecompileCall::freeze_collection_selectors()[0],
+			PrecompileCall::is_frozen_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_ROYALTIES),
+		xor(&[
+			PrecompileCall::set_default_royalty_selectors()[0],
+			PrecompileCall::set_token_royalty_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_HISTORY),
+		xor(&[
+			PrecompileCall::token_uri_version_selectors()[0],
+			PrecompileCall::token_uri_at_selectors()[0],
+			PrecompileCall::scheduled_evolution_selectors()[0],
+			PrecompileCall::schedule_evolution_selectors()[0],
+			PrecompileCall::cancel_scheduled_evolution_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_CONSENT),
+		xor(&[
+			PrecompileCall::approve_evolution_selectors()[0],
+			PrecompileCall::set_evolution_consent_required_selectors()[0],
+			PrecompileCall::evolution_consent_required_selectors()[0],
+		])
+	);
+	assert_eq!(
+		id(INTERFACE_ID_EVOLUTION_COLLECTION_ATTRIBUTES),
+		xor(&[
+			PrecompileCall::set_attribute_selectors()[0],
+			PrecompileCall::remove_attribute_selectors()[0],
+			PrecompileCall::get_attribute_selectors()[0],
+		])
+	);
+	// every function of the precompile belongs to exactly one of the reported interfaces
+	let reported: Vec<u32> = SUPPORTED_INTERFACES
+		.into_iter()
+		.filter(|interface_id| *interface_id != INTERFACE_ID_ERC4906)
+		.map(id)
+		.collect();
+	assert_eq!(xor(&reported), xor(PrecompileCall::selectors()));
+}
+
+#[test]
+fn supports_interface_reports_supported_interfaces() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		for interface_id in SUPPORTED_INTERFACES {
+			precompiles()
+				.prepare_test(
+					alice,
+					collection_address,
+					PrecompileCall::supports_interface { interface_id: InterfaceId(interface_id) },
+				)
+				.execute_returns(true);
+		}
+
+		// neither ERC-721 nor its metadata extension are supported, as tokens can not be
+		// transferred
+		for interface_id in
+			[[0x80, 0xac, 0x58, 0xcd], [0x5b, 0x5e, 0x13, 0x9f], [0xff, 0xff, 0xff, 0xff]]
+		{
+			precompiles()
+				.prepare_test(
+					alice,
+					collection_address,
+					PrecompileCall::supports_interface { interface_id: InterfaceId(interface_id) },
+				)
+				.execute_returns(false);
+		}
+	});
+}
+
+#[test]
+fn initial_owner_of_and_slot_of_decode_token_id() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+		let slot = Slot::try_from(1234).unwrap();
+		// tokens do not need to be minted
+		let token_id = crate::slot_and_owner_to_token_id(slot, bob);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::initial_owner_of { token_id })
+			.execute_returns(Address(bob));
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::slot_of { token_id })
+			.execute_returns(slot);
+	});
+}
+
+#[test]
+fn views_without_storage_reads_charge_their_cost() {
+	new_test_ext().execute_with(|| {
+		let collection_address = create_collection(Alice);
+		let collection_id = crate::address_to_collection_id(collection_address).unwrap();
+		let gas = |weight| <Test as Config>::GasWeightMapping::weight_to_gas(weight);
+		let mut handle = MockHandle::new(
+			collection_address,
+			Context {
+				address: collection_address,
+				caller: Alice.into(),
+				apparent_value: U256::zero(),
+			},
+		);
+
+		assert!(EvolutionCollectionPrecompileSet::<Test>::supports_interface(
+			collection_id,
+			&mut handle,
+			InterfaceId(INTERFACE_ID_ERC165),
+		)
+		.unwrap());
+		assert_eq!(
+			handle.gas_used,
+			gas(<Test as Config>::WeightInfo::precompile_supports_interface())
+		);
+
+		handle.gas_used = 0;
+		assert!(EvolutionCollectionPrecompileSet::<Test>::initial_owner_of(
+			collection_id,
+			&mut handle,
+			U256::zero(),
+		)
+		.is_ok());
+		assert_eq!(
+			handle.gas_used,
+			gas(<Test as Config>::WeightInfo::precompile_initial_owner_of())
+		);
+
+		handle.gas_used = 0;
+		assert!(EvolutionCollectionPrecompileSet::<Test>::slot_of(
+			collection_id,
+			&mut handle,
+			U256::zero(),
+		)
+		.is_ok());
+		assert_eq!(handle.gas_used, gas(<Test as Config>::WeightInfo::precompile_slot_of()));
+	});
+}
+
+#[test]
+fn token_uri_version_and_history_after_evolve() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ipfs://0".into());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve { token_id, token_uri: "ipfs://1".into() },
+			)
+			.execute_some();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::token_uri_version { token_id })
+			.execute_returns(1u32);
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::token_uri_at { token_id, version: 0 },
+			)
+			.execute_returns(UnboundedString::from("ipfs://0"));
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::token_uri_at { token_id, version: 1 },
+			)
+			.execute_returns(UnboundedString::from("ipfs://1"));
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::token_uri_at { token_id, version: 2 },
+			)
+			.execute_reverts(|r| r == b"version does not exist");
+	});
+}
+
+#[test]
+fn token_uri_version_of_unexistent_token_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::token_uri_version { token_id: 1.into() },
+			)
+			.execute_reverts(|r| r == b"asset does not exist");
+	});
+}
+
+#[test]
+fn token_uri_at_of_burned_token_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ipfs://0".into());
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve { token_id, token_uri: "ipfs://1".into() },
+			)
+			.execute_some();
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::burn { token_id })
+			.execute_some();
+
+		for version in 0..=1 {
+			precompiles()
+				.prepare_test(
+					alice,
+					collection_address,
+					PrecompileCall::token_uri_at { token_id, version },
+				)
+				.execute_reverts(|r| r == b"asset does not exist");
+		}
+	});
+}
+
+#[test]
+fn check_eip712_type_hashes() {
+	assert_eq!(
+		hex::encode(EIP712_DOMAIN_TYPEHASH),
+		"8b73c3c69bb8fe3d512ecc4cf759cc79239f7b179b0ffacaa9a75d522b39400f"
+	);
+	assert_eq!(
+		hex::encode(MINT_WITH_SIGNATURE_TYPEHASH),
+		"44616629393ccdc507386fc09ca08b7e7cec06c56eaa4c47282bebf7cd04b94c"
+	);
+	assert_eq!(
+		hex::encode(EVOLVE_WITH_SIGNATURE_TYPEHASH),
+		"a7615452abd47fe73d302313d18585646deda2cbaf85740a024b64310fcf8b9a"
+	);
+}
+
+#[test]
+fn mint_authorization_digest_follows_eip712() {
+	let digest = mint_authorization_digest::<Test>(
+		H160([2u8; 20]),
+		H160([1u8; 20]),
+		9.try_into().unwrap(),
+		b"ciao",
+		5.into(),
+		1000.into(),
+	);
+
+	assert_eq!(
+		hex::encode(digest),
+		"ffcf9b1be2de166f470bf56b1f87f2eb2b1538b7a87ce7becc182840ba4500ae"
+	);
+}
+
+#[test]
+fn evolution_authorization_digest_follows_eip712() {
+	let digest =
+		evolution_authorization_digest::<Test>(H160([2u8; 20]), 9.into(), b"ciao", 0, 1000.into());
+
+	assert_eq!(
+		hex::encode(digest),
+		"f255813f0e2e401d6ea858495fbc7a77101b861ac5b79fb504532cbff8dab208"
+	);
+}
+
+#[test]
+fn mint_with_signature_works_and_generates_log() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let relayer = H160([2u8; 20]);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let token_id = crate::slot_and_owner_to_token_id(slot, to);
+		let signature =
+			sign_mint(&pair, collection_address, to, slot, "ciao", U256::zero(), U256::MAX);
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				relayer,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline: U256::MAX,
+					signature,
+				},
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI,
+				to,
+				solidity::encode_event_data((slot, token_id, UnboundedString::from("ciao"))),
+			))
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_TRANSFER,
+				H160::zero(),
+				to,
+				token_id_bytes,
+				solidity::encode_event_data(()),
+			))
+			.execute_returns(token_id);
+
+		precompiles()
+			.prepare_test(
+				relayer,
+				collection_address,
+				PrecompileCall::is_mint_nonce_used { signer: Address(owner), nonce: U256::zero() },
+			)
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn mint_with_signature_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let signature =
+			sign_mint(&pair, collection_address, to, slot, "ciao", U256::zero(), U256::MAX);
+		let call = || PrecompileCall::mint_with_signature {
+			to: Address(to),
+			slot,
+			token_uri: "ciao".into(),
+			nonce: U256::zero(),
+			deadline: U256::MAX,
+			signature: signature.clone(),
+		};
+
+		precompiles().prepare_test(to, collection_address, call()).execute_some();
+		precompiles()
+			.prepare_test(
+				owner,
+				collection_address,
+				PrecompileCall::burn { token_id: crate::slot_and_owner_to_token_id(slot, to) },
+			)
+			.execute_some();
+
+		precompiles()
+			.prepare_test(to, collection_address, call())
+			.execute_reverts(|r| r == b"MintNonceAlreadyUsed");
+	});
+}
+
+#[test]
+fn mint_with_signature_of_invalidated_nonce_reverts() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let nonce = U256::from(7);
+		let signature = sign_mint(&pair, collection_address, to, slot, "ciao", nonce, U256::MAX);
+
+		precompiles()
+			.prepare_test(
+				owner,
+				collection_address,
+				PrecompileCall::invalidate_mint_nonce { nonce },
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_MINT_NONCE_INVALIDATED,
+				owner,
+				solidity::encode_event_data(nonce),
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce,
+					deadline: U256::MAX,
+					signature,
+				},
+			)
+			.execute_reverts(|r| r == b"MintNonceAlreadyUsed");
+	});
+}
+
+#[test]
+fn mint_with_signature_of_non_owner_reverts() {
+	new_test_ext().execute_with(|| {
+		let (pair, _) = signer();
+		let collection_address = create_collection(H160([3u8; 20]));
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let signature =
+			sign_mint(&pair, collection_address, to, slot, "ciao", U256::zero(), U256::MAX);
+
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline: U256::MAX,
+					signature,
+				},
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn mint_with_signature_for_another_collection_reverts() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let other_collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let signature =
+			sign_mint(&pair, other_collection_address, to, slot, "ciao", U256::zero(), U256::MAX);
+
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline: U256::MAX,
+					signature,
+				},
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn mint_with_signature_of_minter_works() {
+	new_test_ext().execute_with(|| {
+		let (pair, minter) = signer();
+		let owner = H160([3u8; 20]);
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let signature =
+			sign_mint(&pair, collection_address, to, slot, "ciao", U256::zero(), U256::MAX);
+
+		precompiles()
+			.prepare_test(
+				owner,
+				collection_address,
+				PrecompileCall::grant_role { role: H256(MINTER_ROLE), account: Address(minter) },
+			)
+			.execute_returns(());
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline: U256::MAX,
+					signature,
+				},
+			)
+			.execute_returns(crate::slot_and_owner_to_token_id(slot, to));
+	});
+}
+
+#[test]
+fn mint_with_signature_can_use_authorizations_in_any_order() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let call = |slot: Slot, nonce: U256| PrecompileCall::mint_with_signature {
+			to: Address(to),
+			slot,
+			token_uri: "ciao".into(),
+			nonce: U256::zero(),
+			nonce,
+			deadline: U256::MAX,
+			signature: sign_mint(&pair, collection_address, to, slot, "ciao", nonce, U256::MAX),
+		};
+
+		// an authorization that is never used does not block the following ones
+		for nonce in [10u128, 9] {
+			let slot: Slot = nonce.try_into().unwrap();
+			precompiles()
+				.prepare_test(to, collection_address, call(slot, nonce.into()))
+				.execute_returns(crate::slot_and_owner_to_token_id(slot, to));
+		}
+	});
+}
+
+#[test]
+fn mint_with_signature_accepts_ethereum_recovery_ids() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let mut signature =
+			sign_mint(&pair, collection_address, to, slot, "ciao", U256::zero(), U256::MAX)
+				.as_bytes()
+				.to_vec();
+		signature[64] += 27;
+
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline: U256::MAX,
+					signature: signature.into(),
+				},
+			)
+			.execute_returns(crate::slot_and_owner_to_token_id(slot, to));
+	});
+}
+
+#[test]
+fn mint_with_signature_of_invalid_recovery_id_reverts() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let mut signature =
+			sign_mint(&pair, collection_address, to, slot, "ciao", U256::zero(), U256::MAX)
+				.as_bytes()
+				.to_vec();
+		signature[64] += 29;
+
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline: U256::MAX,
+					signature: signature.into(),
+				},
+			)
+			.execute_reverts(|r| r == b"invalid signature");
+	});
+}
+
+#[test]
+fn mint_with_malleated_signature_reverts() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let mut signature =
+			sign_mint(&pair, collection_address, to, slot, "ciao", U256::zero(), U256::MAX)
+				.as_bytes()
+				.to_vec();
+
+		// (r, n - s, v ^ 1) is the other valid signature of the same message
+		let order = U256::from_big_endian(
+			&hex::decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+				.unwrap(),
+		);
+		(order - U256::from_big_endian(&signature[32..64])).to_big_endian(&mut signature[32..64]);
+		signature[64] ^= 1;
+
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline: U256::MAX,
+					signature: signature.into(),
+				},
+			)
+			.execute_reverts(|r| r == b"invalid signature");
+	});
+}
+
+#[test]
+fn mint_with_expired_signature_reverts() {
+	new_test_ext().execute_with(|| {
+		let (pair, owner) = signer();
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+		let slot: Slot = 9.try_into().unwrap();
+		let deadline = U256::from(9);
+		let signature =
+			sign_mint(&pair, collection_address, to, slot, "ciao", U256::zero(), deadline);
+		Timestamp::set_timestamp(10_000);
+
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot,
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline,
+					signature,
+				},
+			)
+			.execute_reverts(|r| r == b"expired deadline");
+	});
+}
+
+#[test]
+fn mint_with_malformed_signature_reverts() {
+	new_test_ext().execute_with(|| {
+		let (_, owner) = signer();
+		let collection_address = create_collection(owner);
+		let to = H160([1u8; 20]);
+
+		precompiles()
+			.prepare_test(
+				to,
+				collection_address,
+				PrecompileCall::mint_with_signature {
+					to: Address(to),
+					slot: 9.try_into().unwrap(),
+					token_uri: "ciao".into(),
+					nonce: U256::zero(),
+					deadline: U256::MAX,
+					signature: [1u8; 64][..].into(),
+				},
+			)
+			.execute_reverts(|r| r == b"invalid signature");
+	});
+}
+
+#[test]
+fn set_drop_root_generates_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let root = H256::repeat_byte(1);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::drop_root {})
+			.execute_returns(H256::zero());
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::set_drop_root { root })
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_DROP_ROOT_SET,
+				solidity::encode_event_data(root),
+			))
+			.execute_some();
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::drop_root {})
+			.execute_returns(root);
+	});
+}
+
+#[test]
+fn non_owner_cannot_set_drop_root() {
+	new_test_ext().execute_with(|| {
+		let collection_address = create_collection(H160::from_str(ALICE).unwrap());
+
+		precompiles()
+			.prepare_test(
+				H160([2u8; 20]),
+				collection_address,
+				PrecompileCall::set_drop_root { root: H256::repeat_byte(1) },
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn anyone_can_claim_from_drop_once() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let bob = H160([2u8; 20]);
+		let collection_address = create_collection(alice);
+		let slot: Slot = 1.try_into().unwrap();
+		let token_id = crate::slot_and_owner_to_token_id(slot, bob);
+		let leaf = crate::drop_leaf(slot, bob, b"ipfs://1");
+		let sibling = crate::drop_leaf(slot, alice, b"ipfs://2");
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+		let claim = || PrecompileCall::claim_from_drop {
+			proof: vec![sibling],
+			to: Address(bob),
+			slot,
+			token_uri: "ipfs://1".into(),
+		};
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_drop_root { root: crate::merkle_root(leaf, &[sibling]) },
+			)
+			.execute_some();
+		precompiles()
+			.prepare_test(bob, collection_address, claim())
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI,
+				bob,
+				solidity::encode_event_data((slot, token_id, UnboundedString::from("ipfs://1"))),
+			))
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_TRANSFER,
+				H160::zero(),
+				bob,
+				token_id_bytes,
+				solidity::encode_event_data(()),
+			))
+			.execute_returns(token_id);
+		precompiles()
+			.prepare_test(bob, collection_address, claim())
+			.execute_reverts(|r| r == b"AlreadyClaimed");
+	});
+}
+
+#[test]
+fn claim_from_drop_with_invalid_proof_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_drop_root { root: H256::repeat_byte(1) },
+			)
+			.execute_some();
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::claim_from_drop {
+					proof: vec![H256::repeat_byte(2)],
+					to: Address(alice),
+					slot: 1.try_into().unwrap(),
+					token_uri: "ipfs://1".into(),
+				},
+			)
+			.execute_reverts(|r| r == b"InvalidMerkleProof");
+	});
+}
+
+#[test]
+fn total_supply_counts_minted_and_burned_tokens() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::total_supply {})
+			.execute_returns(U256::zero());
+
+		let token_id = mint(alice, collection_address, 1.try_into().unwrap(), "ciao".into());
+		mint(alice, collection_address, 2.try_into().unwrap(), "ciao".into());
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::total_supply {})
+			.execute_returns(U256::from(2));
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::burn { token_id })
+			.execute_some();
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::total_supply {})
+			.execute_returns(U256::from(1));
+	});
+}
+
+#[test]
+fn max_supply_of_uncapped_collection_is_max_uint256() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::max_supply {})
+			.execute_returns(U256::MAX);
+	});
+}
+
+#[test]
+fn mint_beyond_max_supply_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection_with_max_supply(alice, 1);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::max_supply {})
+			.execute_returns(U256::one());
+
+		mint(alice, collection_address, 1.try_into().unwrap(), "ciao".into());
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::mint {
+					to: Address(alice),
+					slot: 2.try_into().unwrap(),
+					token_uri: "ciao".into(),
+				},
+			)
+			.execute_reverts(|r| r == b"MaxSupplyReached");
+	});
+}
+
+#[test]
+fn token_by_index_works() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 1.try_into().unwrap(), "ciao".into());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::token_by_index { index: U256::zero() },
+			)
+			.execute_returns(token_id);
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::token_by_index { index: U256::one() },
+			)
+			.execute_reverts(|r| r == b"index out of bounds");
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::token_by_index { index: U256::MAX },
+			)
+			.execute_reverts(|r| r == b"index out of bounds");
+	});
+}
+
+#[test]
+fn set_base_uri_generates_log_and_resolves_token_uri() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 1.try_into().unwrap(), "".into());
+		let base_uri: UnboundedString = "https://api.game/items/{id}".into();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::base_uri {})
+			.execute_returns(UnboundedString::from(""));
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_base_uri { base_uri: base_uri.clone() },
+			)
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_BASE_URI_SET,
+				solidity::encode_event_data(base_uri.clone()),
+			))
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_BATCH_METADATA_UPDATE,
+				solidity::encode_event_data((U256::zero(), U256::MAX)),
+			))
+			.execute_some();
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::base_uri {})
+			.execute_returns(base_uri);
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::token_uri { token_id })
+			.execute_returns(UnboundedString::from(
+				format!("https://api.game/items/{:064x}", token_id).as_str(),
+			));
+	});
+}
+
+#[test]
+fn non_owner_cannot_set_base_uri() {
+	new_test_ext().execute_with(|| {
+		let collection_address = create_collection(H160::from_str(ALICE).unwrap());
+
+		precompiles()
+			.prepare_test(
+				H160([2u8; 20]),
+				collection_address,
+				PrecompileCall::set_base_uri { base_uri: "ipfs://{id}".into() },
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn set_base_uri_of_frozen_collection_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::freeze_collection {})
+			.execute_some();
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_base_uri { base_uri: "ipfs://{id}".into() },
+			)
+			.execute_reverts(|r| r == b"CollectionFrozen");
+	});
+}
+
+#[test]
+fn mint_next_returns_slot_and_token_id_and_generates_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		mint(alice, collection_address, 0.try_into().unwrap(), "".into());
+		let token_uri: UnboundedString = "ipfs://next".into();
+
+		let slot: Slot = 1.try_into().unwrap();
+		let token_id = slot_and_owner_to_token_id(slot, alice);
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::mint_next { to: Address(alice), token_uri: token_uri.clone() },
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_MINTED_WITH_EXTERNAL_TOKEN_URI,
+				alice,
+				solidity::encode_event_data((slot, token_id, token_uri)),
+			))
+			.expect_log(log4(
+				collection_address,
+				SELECTOR_LOG_TRANSFER,
+				H160::zero(),
+				alice,
+				token_id_bytes,
+				solidity::encode_event_data(()),
+			))
+			.execute_returns((slot, token_id));
+	});
+}
+
+#[test]
+fn non_minter_cannot_mint_next() {
+	new_test_ext().execute_with(|| {
+		let collection_address = create_collection(H160::from_str(ALICE).unwrap());
+
+		precompiles()
+			.prepare_test(
+				H160([2u8; 20]),
+				collection_address,
+				PrecompileCall::mint_next { to: Address(H160([2u8; 20])), token_uri: "".into() },
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn scheduled_evolution_returns_block_and_token_uri() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let collection_id = crate::address_to_collection_id(collection_address).unwrap();
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ipfs://0".into());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::scheduled_evolution { token_id },
+			)
+			.execute_reverts(|r| r == b"token has no scheduled evolution");
+
+		assert!(LaosEvolutionPallet::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"ipfs://1".to_vec().try_into().unwrap(),
+			10,
+		)
+		.is_ok());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::scheduled_evolution { token_id },
+			)
+			.execute_returns((U256::from(10), UnboundedString::from("ipfs://1")));
+	});
+}
+
+#[test]
+fn schedule_and_cancel_evolution_generate_logs() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ipfs://0".into());
+		let token_uri: UnboundedString = "ipfs://1".into();
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::schedule_evolution {
+					token_id,
+					token_uri: token_uri.clone(),
+					block: U256::from(10),
+				},
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_EVOLUTION_SCHEDULED,
+				token_id_bytes,
+				solidity::encode_event_data((token_uri.clone(), U256::from(10))),
+			))
+			.execute_returns(());
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::scheduled_evolution { token_id },
+			)
+			.execute_returns((U256::from(10), token_uri));
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::cancel_scheduled_evolution { token_id },
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_SCHEDULED_EVOLUTION_CANCELED,
+				token_id_bytes,
+				solidity::encode_event_data(()),
+			))
+			.execute_returns(());
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::scheduled_evolution { token_id },
+			)
+			.execute_reverts(|r| r == b"token has no scheduled evolution");
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::cancel_scheduled_evolution { token_id },
+			)
+			.execute_reverts(|r| r == b"NoScheduledEvolution");
+	});
+}
+
+#[test]
+fn schedule_evolution_reverts_for_non_evolvers_and_past_blocks() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ipfs://0".into());
+
+		precompiles()
+			.prepare_test(
+				H160([2u8; 20]),
+				collection_address,
+				PrecompileCall::schedule_evolution {
+					token_id,
+					token_uri: "ipfs://1".into(),
+					block: U256::from(10),
+				},
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::schedule_evolution {
+					token_id,
+					token_uri: "ipfs://1".into(),
+					block: U256::zero(),
+				},
+			)
+			.execute_reverts(|r| r == b"ScheduledInThePast");
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::schedule_evolution {
+					token_id,
+					token_uri: "ipfs://1".into(),
+					block: U256::MAX,
+				},
+			)
+			.execute_reverts(|r| r == b"invalid block number");
+	});
+}
+
+#[test]
+fn set_get_and_remove_attribute() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ipfs://0".into());
+		let key = H256::repeat_byte(1);
+		let value = UnboundedBytes::from(b"legendary".as_slice());
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::get_attribute { token_id, key },
+			)
+			.execute_reverts(|r| r == b"attribute does not exist");
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_attribute { token_id, key, value: value.clone() },
+			)
+			.expect_log(log3(
+				collection_address,
+				SELECTOR_LOG_ATTRIBUTE_SET,
+				token_id_bytes,
+				key,
+				solidity::encode_event_data(value.clone()),
+			))
+			.execute_some();
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::get_attribute { token_id, key },
+			)
+			.execute_returns(value);
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::remove_attribute { token_id, key },
+			)
+			.expect_log(log3(
+				collection_address,
+				SELECTOR_LOG_ATTRIBUTE_REMOVED,
+				token_id_bytes,
+				key,
+				solidity::encode_event_data(()),
+			))
+			.execute_some();
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::get_attribute { token_id, key },
+			)
+			.execute_reverts(|r| r == b"attribute does not exist");
+	});
+}
+
+#[test]
+fn non_owner_cannot_set_attribute() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ipfs://0".into());
+
+		precompiles()
+			.prepare_test(
+				H160([2u8; 20]),
+				collection_address,
+				PrecompileCall::set_attribute {
+					token_id,
+					key: H256::repeat_byte(1),
+					value: UnboundedBytes::from(b"legendary".as_slice()),
+				},
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn set_attribute_with_too_long_value_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+		let token_id = mint(alice, collection_address, 0.try_into().unwrap(), "ipfs://0".into());
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_attribute {
+					token_id,
+					key: H256::repeat_byte(1),
+					value: vec![1u8; MaxAttributeValueLength::get() as usize + 1].into(),
+				},
+			)
+			.execute_reverts(|r| r == b"invalid attribute value length");
+	});
+}
+
+#[test]
+fn set_evolution_consent_required_generates_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let collection_address = create_collection(alice);
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::evolution_consent_required {})
+			.execute_returns(false);
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_evolution_consent_required { required: true },
+			)
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_EVOLUTION_CONSENT_REQUIRED_SET,
+				solidity::encode_event_data(true),
+			))
+			.execute_some();
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::evolution_consent_required {})
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn non_owner_cannot_set_evolution_consent_required() {
+	new_test_ext().execute_with(|| {
+		let collection_address = create_collection(H160::from_str(ALICE).unwrap());
+
+		precompiles()
+			.prepare_test(
+				H160([2u8; 20]),
+				collection_address,
+				PrecompileCall::set_evolution_consent_required { required: true },
+			)
+			.execute_reverts(|r| r == b"NoPermission");
+	});
+}
+
+#[test]
+fn approve_evolution_reverts_when_consent_is_not_required() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let holder = H160([2u8; 20]);
+		let (collection_address, token_id) = create_consent_collection_with_token(holder);
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_evolution_consent_required { required: false },
+			)
+			.execute_some();
+
+		precompiles()
+			.prepare_test(
+				holder,
+				collection_address,
+				PrecompileCall::approve_evolution { token_id, uri_hash: H256::repeat_byte(1) },
+			)
+			.execute_reverts(|r| r == b"EvolutionConsentNotRequired");
+	});
+}
+
+#[test]
+fn evolve_requires_approval_of_initial_owner_when_consent_is_required() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let holder = H160([2u8; 20]);
+		let (collection_address, token_id) = create_consent_collection_with_token(holder);
+		let uri_hash = H256(sp_io::hashing::keccak_256(b"ipfs://1"));
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve { token_id, token_uri: "ipfs://1".into() },
+			)
+			.execute_reverts(|r| r == b"EvolutionNotApproved");
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::approve_evolution { token_id, uri_hash },
+			)
+			.execute_reverts(|r| r == b"NotInitialOwner");
+		precompiles()
+			.prepare_test(
+				holder,
+				collection_address,
+				PrecompileCall::approve_evolution { token_id, uri_hash },
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_EVOLUTION_APPROVED,
+				token_id_bytes,
+				solidity::encode_event_data(uri_hash),
+			))
+			.execute_some();
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve { token_id, token_uri: "ipfs://1".into() },
+			)
+			.execute_some();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::token_uri { token_id })
+			.execute_returns(UnboundedString::from("ipfs://1"));
+	});
+}
+
+#[test]
+fn evolve_with_signature_works_and_generates_log() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let (pair, holder) = signer();
+		let (collection_address, token_id) = create_consent_collection_with_token(holder);
+		let signature = sign_evolution(&pair, collection_address, token_id, "ipfs://1", 0);
+		let mut token_id_bytes = [0u8; 32];
+		token_id.to_big_endian(&mut token_id_bytes);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve_with_signature {
+					token_id,
+					token_uri: "ipfs://1".into(),
+					deadline: U256::MAX,
+					signature,
+				},
+			)
+			.expect_log(log2(
+				collection_address,
+				SELECTOR_LOG_EVOLVED_WITH_EXTERNAL_TOKEN_URI,
+				token_id_bytes,
+				solidity::encode_event_data(UnboundedString::from("ipfs://1")),
+			))
+			.expect_log(log1(
+				collection_address,
+				SELECTOR_LOG_METADATA_UPDATE,
+				solidity::encode_event_data(token_id),
+			))
+			.execute_some();
+
+		precompiles()
+			.prepare_test(alice, collection_address, PrecompileCall::token_uri { token_id })
+			.execute_returns(UnboundedString::from("ipfs://1"));
+	});
+}
+
+#[test]
+fn evolve_with_signature_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let (pair, holder) = signer();
+		let (collection_address, token_id) = create_consent_collection_with_token(holder);
+		let signature = sign_evolution(&pair, collection_address, token_id, "ipfs://1", 0);
+		let call = || PrecompileCall::evolve_with_signature {
+			token_id,
+			token_uri: "ipfs://1".into(),
+			deadline: U256::MAX,
+			signature: signature.clone(),
+		};
+
+		precompiles().prepare_test(alice, collection_address, call()).execute_some();
+
+		// the evolution bumped the version of the token URI, so the signature is no longer valid
+		precompiles()
+			.prepare_test(alice, collection_address, call())
+			.execute_reverts(|r| r == b"NotInitialOwner");
+	});
+}
+
+#[test]
+fn evolve_with_signature_of_non_initial_owner_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let (pair, _) = signer();
+		let (collection_address, token_id) = create_consent_collection_with_token(H160([2u8; 20]));
+		let signature = sign_evolution(&pair, collection_address, token_id, "ipfs://1", 0);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve_with_signature {
+					token_id,
+					token_uri: "ipfs://1".into(),
+					deadline: U256::MAX,
+					signature,
+				},
+			)
+			.execute_reverts(|r| r == b"NotInitialOwner");
+	});
+}
+
+#[test]
+fn evolve_with_signature_without_consent_required_reverts() {
+	new_test_ext().execute_with(|| {
+		let alice = H160::from_str(ALICE).unwrap();
+		let (pair, holder) = signer();
+		let (collection_address, token_id) = create_consent_collection_with_token(holder);
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::set_evolution_consent_required { required: false },
+			)
+			.execute_some();
+		let signature = sign_evolution(&pair, collection_address, token_id, "ipfs://1", 0);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				collection_address,
+				PrecompileCall::evolve_with_signature {
+					token_id,
+					token_uri: "ipfs://1".into(),
+					deadline: U256::MAX,
+					signature,
+				},
+			)
+			.execute_reverts(|r| r == b"evolution consent is not required");
+	});
+}
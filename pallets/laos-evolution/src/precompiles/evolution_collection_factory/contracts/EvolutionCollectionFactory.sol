@@ -16,4 +16,47 @@ interface EvolutionCollectionFactory {
     /// @param _owner the owner of the newly created collection
     /// @return the address of the newly created collection
     function createCollection(address _owner) external returns (address);
+
+    /// @notice Creates a new collection with its name, symbol and contractURI
+    /// @dev Call this function to create a new collection whose metadata is already set
+    /// @param _owner the owner of the newly created collection
+    /// @param _name the name of the collection
+    /// @param _symbol the symbol of the collection
+    /// @param _contractURI the contractURI of the collection, following ERC-7572
+    /// @return the address of the newly created collection
+    function createCollection(
+        address _owner,
+        string calldata _name,
+        string calldata _symbol,
+        string calldata _contractURI
+    ) external returns (address);
+
+    /// @notice Creates a new collection that can never mint more than `_maxSupply` tokens
+    /// @dev The max supply is set once and cannot be changed afterwards. Burned tokens keep
+    /// @dev counting towards it
+    /// @param _owner the owner of the newly created collection
+    /// @param _maxSupply the maximum number of tokens of the collection
+    /// @return the address of the newly created collection
+    function createCollection(
+        address _owner,
+        uint64 _maxSupply
+    ) external returns (address);
+
+    /// @notice Creates a new collection with its name, symbol and contractURI that can never mint
+    /// @notice more than `_maxSupply` tokens
+    /// @dev The max supply is set once and cannot be changed afterwards. Burned tokens keep
+    /// @dev counting towards it
+    /// @param _owner the owner of the newly created collection
+    /// @param _name the name of the collection
+    /// @param _symbol the symbol of the collection
+    /// @param _contractURI the contractURI of the collection, following ERC-7572
+    /// @param _maxSupply the maximum number of tokens of the collection
+    /// @return the address of the newly created collection
+    function createCollection(
+        address _owner,
+        string calldata _name,
+        string calldata _symbol,
+        string calldata _contractURI,
+        uint64 _maxSupply
+    ) external returns (address);
 }
@@ -55,6 +55,15 @@ impl frame_system::Config for Test {
 
 parameter_types! {
 	pub const MaxTokenUriLength: u32 = 512;
+	pub const MaxCollectionNameLength: u32 = 128;
+	pub const MaxCollectionSymbolLength: u32 = 32;
+	pub const MaxUriHistory: u32 = 3;
+	pub const MaxScheduledEvolutionsPerBlock: u32 = 2;
+	pub const MaxAttributeValueLength: u32 = 32;
+	pub const MaxAttributesPerToken: u32 = 2;
+	pub const ChainId: u64 = 62_621;
+	pub const CollectionDeposit: u64 = 0;
+	pub const DepositPerByte: u64 = 0;
 }
 
 pub struct AccountIdToH160;
@@ -84,9 +93,23 @@ impl pallet_laos_evolution::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AccountIdToH160 = AccountIdToH160;
 	type MaxTokenUriLength = MaxTokenUriLength;
+	type MaxCollectionNameLength = MaxCollectionNameLength;
+	type MaxCollectionSymbolLength = MaxCollectionSymbolLength;
+	type MaxUriHistory = MaxUriHistory;
+	type MaxScheduledEvolutionsPerBlock = MaxScheduledEvolutionsPerBlock;
+	type MaxAttributeValueLength = MaxAttributeValueLength;
+	type MaxAttributesPerToken = MaxAttributesPerToken;
+	type ChainId = ChainId;
+	type Timestamp = Timestamp;
 	type WeightInfo = ();
 	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
 	type OnCreateCollection = CollectionManager;
+	type OnMint = ();
+	type OnEvolve = ();
+	type OnOwnershipTransferred = ();
+	type Currency = Balances;
+	type CollectionDeposit = CollectionDeposit;
+	type DepositPerByte = DepositPerByte;
 }
 
 parameter_types! {
@@ -18,13 +18,17 @@
 
 use crate::{
 	collection_id_to_address,
-	traits::{EvolutionCollectionFactory as EvolutionCollectionFactoryT, OnCreateCollection},
+	traits::{
+		EvolutionCollectionFactory as EvolutionCollectionFactoryT, EvolutionCollectionMetadata,
+	},
+	types::CollectionId,
 	weights::WeightInfo,
 	Pallet as LaosEvolution,
 };
 use frame_support::DefaultNoBound;
-use precompile_utils::prelude::{
-	keccak256, log2, revert, solidity, Address, EvmResult, LogExt, PrecompileHandle,
+use precompile_utils::{
+	prelude::{keccak256, log2, revert, solidity, Address, EvmResult, LogExt, PrecompileHandle},
+	solidity::codec::UnboundedString,
 };
 use scale_info::prelude::{format, string::String};
 use sp_core::H160;
@@ -61,27 +65,115 @@ where
 			Runtime::WeightInfo::precompile_create_collection(),
 		)?;
 
-		match LaosEvolution::<Runtime>::create_collection(Runtime::AccountIdToH160::convert_back(
-			owner.0,
-		)) {
-			Ok(collection_id) => {
-				let collection_address: H160 = collection_id_to_address(collection_id);
+		match <LaosEvolution<Runtime> as EvolutionCollectionFactoryT<_>>::create_collection(
+			Runtime::AccountIdToH160::convert_back(handle.context().caller),
+			Runtime::AccountIdToH160::convert_back(owner.0),
+		) {
+			Ok(collection_id) => log_new_collection(handle, owner, collection_id),
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("createCollection(address,string,string,string)")]
+	pub(crate) fn create_collection_with_metadata(
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		name: UnboundedString,
+		symbol: UnboundedString,
+		contract_uri: UnboundedString,
+	) -> EvmResult<Address> {
+		super::register_cost::<Runtime>(
+			handle,
+			Runtime::WeightInfo::precompile_create_collection_with_metadata(),
+		)?;
 
-				Runtime::OnCreateCollection::on_create_collection(collection_address);
+		let metadata = super::collection_metadata::<Runtime>(name, symbol, contract_uri)?;
 
-				log2(
-					handle.context().address,
-					SELECTOR_LOG_NEW_COLLECTION,
-					owner.0,
-					solidity::encode_event_data(Address(collection_address)),
-				)
-				.record(handle)?;
+		let result =
+			<LaosEvolution<Runtime> as EvolutionCollectionMetadata<_, _>>::create_collection_with_metadata(
+				Runtime::AccountIdToH160::convert_back(handle.context().caller),
+				Runtime::AccountIdToH160::convert_back(owner.0),
+				metadata,
+			);
 
-				Ok(Address(collection_address))
-			},
+		match result {
+			Ok(collection_id) => log_new_collection(handle, owner, collection_id),
 			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
 		}
 	}
+
+	#[precompile::public("createCollection(address,uint64)")]
+	pub(crate) fn create_collection_with_max_supply(
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		max_supply: u64,
+	) -> EvmResult<Address> {
+		super::register_cost::<Runtime>(
+			handle,
+			Runtime::WeightInfo::precompile_create_collection_with_max_supply(),
+		)?;
+
+		let result =
+			<LaosEvolution<Runtime> as EvolutionCollectionFactoryT<_>>::create_collection_with_max_supply(
+				Runtime::AccountIdToH160::convert_back(handle.context().caller),
+				Runtime::AccountIdToH160::convert_back(owner.0),
+				max_supply,
+			);
+
+		match result {
+			Ok(collection_id) => log_new_collection(handle, owner, collection_id),
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+
+	#[precompile::public("createCollection(address,string,string,string,uint64)")]
+	pub(crate) fn create_collection_with_metadata_and_max_supply(
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		name: UnboundedString,
+		symbol: UnboundedString,
+		contract_uri: UnboundedString,
+		max_supply: u64,
+	) -> EvmResult<Address> {
+		super::register_cost::<Runtime>(
+			handle,
+			Runtime::WeightInfo::precompile_create_collection_with_metadata_and_max_supply(),
+		)?;
+
+		let metadata = super::collection_metadata::<Runtime>(name, symbol, contract_uri)?;
+
+		let result =
+			<LaosEvolution<Runtime> as EvolutionCollectionMetadata<_, _>>::create_collection_with_metadata_and_max_supply(
+				Runtime::AccountIdToH160::convert_back(handle.context().caller),
+				Runtime::AccountIdToH160::convert_back(owner.0),
+				metadata,
+				max_supply,
+			);
+
+		match result {
+			Ok(collection_id) => log_new_collection(handle, owner, collection_id),
+			Err(err) => Err(revert(convert_dispatch_error_to_string(err))),
+		}
+	}
+}
+
+/// Records the `NewCollection` log and returns the address of the new collection
+fn log_new_collection(
+	handle: &mut impl PrecompileHandle,
+	owner: Address,
+	collection_id: CollectionId,
+) -> EvmResult<Address> {
+	let collection_address: H160 = collection_id_to_address(collection_id);
+
+	log2(
+		handle.context().address,
+		SELECTOR_LOG_NEW_COLLECTION,
+		owner.0,
+		solidity::encode_event_data(Address(collection_address)),
+	)
+	.record(handle)?;
+
+	Ok(Address(collection_address))
 }
 
 fn convert_dispatch_error_to_string(err: DispatchError) -> String {
@@ -43,6 +43,10 @@ fn check_log_selectors() {
 #[test]
 fn selectors() {
 	assert!(PrecompileCall::create_collection_selectors().contains(&0x2069E953));
+	assert!(PrecompileCall::create_collection_with_metadata_selectors().contains(&0x3DF17453));
+	assert!(PrecompileCall::create_collection_with_max_supply_selectors().contains(&0x73246620));
+	assert!(PrecompileCall::create_collection_with_metadata_and_max_supply_selectors()
+		.contains(&0xEDA11C0F));
 }
 
 #[test]
@@ -160,7 +164,120 @@ fn expected_cost_create_collection() {
 				Precompile1,
 				PrecompileCall::create_collection { owner: Address(Alice.into()) },
 			)
-			.expect_cost(657520000) //[`WeightToGas`] set to 1:1 in mock
+			.expect_cost(882520000) //[`WeightToGas`] set to 1:1 in mock
 			.execute_some();
 	})
 }
+
+#[test]
+fn create_collection_with_metadata_stores_metadata() {
+	new_test_ext().execute_with(|| {
+		let expected_collection_address =
+			H160::from_str("fffffffffffffffffffffffe0000000000000000").unwrap();
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PrecompileCall::create_collection_with_metadata {
+					owner: Address(Alice.into()),
+					name: "LAOS Punks".into(),
+					symbol: "LPUNK".into(),
+					contract_uri: "ipfs://contract".into(),
+				},
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_NEW_COLLECTION,
+				Alice,
+				solidity::encode_event_data(Address(expected_collection_address)),
+			))
+			.execute_returns(Address(expected_collection_address));
+
+		assert_eq!(LaosEvolution::<Test>::collection_owner(0), Some(Alice.into()));
+		let metadata = LaosEvolution::<Test>::collection_metadata(0).unwrap();
+		assert_eq!(metadata.name.to_vec(), b"LAOS Punks".to_vec());
+		assert_eq!(metadata.symbol.to_vec(), b"LPUNK".to_vec());
+		assert_eq!(metadata.contract_uri.to_vec(), b"ipfs://contract".to_vec());
+	});
+}
+
+#[test]
+fn create_collection_with_too_long_name_fails() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PrecompileCall::create_collection_with_metadata {
+					owner: Address(Alice.into()),
+					name: vec![b'a'; MaxCollectionNameLength::get() as usize + 1].into(),
+					symbol: "LPUNK".into(),
+					contract_uri: "ipfs://contract".into(),
+				},
+			)
+			.execute_reverts(|r| r == b"invalid name length");
+
+		assert_eq!(LaosEvolution::<Test>::collection_counter(), 0);
+	});
+}
+
+#[test]
+fn create_collection_with_max_supply_stores_max_supply() {
+	new_test_ext().execute_with(|| {
+		let expected_collection_address =
+			H160::from_str("fffffffffffffffffffffffe0000000000000000").unwrap();
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PrecompileCall::create_collection_with_max_supply {
+					owner: Address(Alice.into()),
+					max_supply: 100,
+				},
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_NEW_COLLECTION,
+				Alice,
+				solidity::encode_event_data(Address(expected_collection_address)),
+			))
+			.execute_returns(Address(expected_collection_address));
+
+		assert_eq!(LaosEvolution::<Test>::collection_owner(0), Some(Alice.into()));
+		assert_eq!(LaosEvolution::<Test>::max_supply(0), Some(100));
+	});
+}
+
+#[test]
+fn create_collection_with_metadata_and_max_supply_stores_both() {
+	new_test_ext().execute_with(|| {
+		let expected_collection_address =
+			H160::from_str("fffffffffffffffffffffffe0000000000000000").unwrap();
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PrecompileCall::create_collection_with_metadata_and_max_supply {
+					owner: Address(Alice.into()),
+					name: "LAOS Punks".into(),
+					symbol: "LPUNK".into(),
+					contract_uri: "ipfs://contract".into(),
+					max_supply: 100,
+				},
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_NEW_COLLECTION,
+				Alice,
+				solidity::encode_event_data(Address(expected_collection_address)),
+			))
+			.execute_returns(Address(expected_collection_address));
+
+		assert_eq!(LaosEvolution::<Test>::collection_owner(0), Some(Alice.into()));
+		assert_eq!(LaosEvolution::<Test>::max_supply(0), Some(100));
+		let metadata = LaosEvolution::<Test>::collection_metadata(0).unwrap();
+		assert_eq!(metadata.name.to_vec(), b"LAOS Punks".to_vec());
+		assert_eq!(metadata.symbol.to_vec(), b"LPUNK".to_vec());
+		assert_eq!(metadata.contract_uri.to_vec(), b"ipfs://contract".to_vec());
+	});
+}
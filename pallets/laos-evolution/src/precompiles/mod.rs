@@ -1,10 +1,14 @@
 pub mod evolution_collection;
 pub mod evolution_collection_factory;
 
+use crate::types::CollectionMetadata;
 use fp_evm::ExitError;
 use frame_support::pallet_prelude::Weight;
 use pallet_evm::GasWeightMapping;
-use precompile_utils::prelude::PrecompileHandle;
+use precompile_utils::{
+	prelude::{revert, EvmResult, PrecompileHandle},
+	solidity::codec::UnboundedString,
+};
 
 // TODO this function is duplicated in all the precompiles, we should refactor it
 pub fn register_cost<Runtime: crate::Config>(
@@ -20,3 +24,25 @@ pub fn register_cost<Runtime: crate::Config>(
 	handle.record_external_cost(Some(weight.ref_time()), Some(weight.proof_size()), Some(0))?;
 	Ok(())
 }
+
+/// Builds the metadata of a collection from its Solidity representation, reverting if any of the
+/// fields exceeds its maximum length
+pub fn collection_metadata<Runtime: crate::Config>(
+	name: UnboundedString,
+	symbol: UnboundedString,
+	contract_uri: UnboundedString,
+) -> EvmResult<CollectionMetadata<Runtime>> {
+	Ok(CollectionMetadata {
+		name: name.as_bytes().to_vec().try_into().map_err(|_| revert("invalid name length"))?,
+		symbol: symbol
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| revert("invalid symbol length"))?,
+		contract_uri: contract_uri
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| revert("invalid contract uri length"))?,
+	})
+}
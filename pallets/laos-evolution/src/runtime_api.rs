@@ -0,0 +1,52 @@
+// Copyright 2023-2024 Freeverse.io
+// This file is part of LAOS.
+
+// LAOS is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// LAOS is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with LAOS.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API of the pallet
+use crate::types::{CollectionId, TokenId};
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// API to query LAOS Evolution collections and tokens
+	pub trait LaosEvolutionApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Returns the owner of the collection, if it exists
+		fn collection_owner(collection_id: CollectionId) -> Option<AccountId>;
+
+		/// Returns the token URI of `token_id` in the collection, if it exists
+		fn token_uri(collection_id: CollectionId, token_id: TokenId) -> Option<Vec<u8>>;
+
+		/// Returns the id the next created collection will get
+		fn collection_counter() -> CollectionId;
+
+		/// Returns up to `limit` tokens of the collection along with their token URI
+		///
+		/// Tokens are returned in storage order, starting right after `start_after` if given.
+		fn tokens_of_collection(
+			collection_id: CollectionId,
+			start_after: Option<TokenId>,
+			limit: u32,
+		) -> Vec<(TokenId, Vec<u8>)>;
+
+		/// Returns up to `limit` tokens of the collection, starting at index `offset`
+		///
+		/// Indexes go from zero to the number of tokens of the collection, and burning a token moves
+		/// the last token of the collection to its index.
+		fn tokens_by_index(collection_id: CollectionId, offset: u64, limit: u32) -> Vec<TokenId>;
+	}
+}
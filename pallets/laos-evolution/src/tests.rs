@@ -17,14 +17,35 @@
 use core::str::FromStr;
 
 use crate::{
+	drop_leaf, expand_base_uri, merkle_root, migrations,
+	migrations::MigrateV0ToV1,
 	mock::*,
-	slot_and_owner_to_token_id,
-	traits::{EvolutionCollection, EvolutionCollectionFactory},
-	types::{Slot, TokenId, TokenUriOf},
-	CollectionId, Error, Event,
+	slot_and_owner_to_token_id, token_id_to_initial_owner, token_id_to_slot,
+	traits::{
+		EvolutionCollection, EvolutionCollectionAttributes, EvolutionCollectionBaseUri,
+		EvolutionCollectionBatch, EvolutionCollectionBurn, EvolutionCollectionConsent,
+		EvolutionCollectionDrop, EvolutionCollectionFactory, EvolutionCollectionFreezing,
+		EvolutionCollectionHistory, EvolutionCollectionMetadata, EvolutionCollectionMintNext,
+		EvolutionCollectionNonces, EvolutionCollectionOwnership, EvolutionCollectionRoles,
+		EvolutionCollectionRoyalties,
+	},
+	types::{
+		AttributeValueOf, CollectionMetadata, Role, RoyaltyInfo, Slot, TokenId, TokenUriOf,
+		MAX_ROYALTY_BASIS_POINTS,
+	},
+	weights::WeightInfo,
+	AttributeCount, Call, CollectionId, Error, Event, EvolutionAgenda, EvolutionApproval,
+	TokenIndex, TokenIndexMigrationCursor, TokenURI, TokenURIHistory,
 };
-use frame_support::{assert_noop, assert_ok};
-use sp_core::U256;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::GetDispatchInfo,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+	weights::Weight,
+};
+use sp_core::{H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::DispatchError;
 
 const ALICE: &str = "0x0000000000000000000000000000000000000005";
 const BOB: &str = "0x0000000000000000000000000000000000000006";
@@ -33,10 +54,26 @@ const BOB: &str = "0x0000000000000000000000000000000000000006";
 fn create_collection(owner: &str) -> CollectionId {
 	let owner = AccountId::from_str(owner).unwrap();
 	let collection_id = LaosEvolution::collection_counter();
-	assert_ok!(LaosEvolution::create_collection(owner));
+	assert_ok!(<LaosEvolution as EvolutionCollectionFactory<_>>::create_collection(owner, owner));
 	collection_id
 }
 
+/// Utility function to create a collection of `ALICE` with a token minted to `token_owner` and
+/// return their IDs
+fn create_collection_with_token(token_owner: &str) -> (CollectionId, TokenId) {
+	let alice = AccountId::from_str(ALICE).unwrap();
+	let collection_id = create_collection(ALICE);
+	let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+		alice,
+		collection_id,
+		Slot::try_from(0).unwrap(),
+		AccountId::from_str(token_owner).unwrap(),
+		TokenUriOf::<Test>::default(),
+	)
+	.unwrap();
+	(collection_id, token_id)
+}
+
 #[test]
 fn owner_of_inexistent_collection() {
 	new_test_ext().execute_with(|| {
@@ -94,7 +131,7 @@ fn transfer_collection_works() {
 	new_test_ext().execute_with(|| {
 		// non-existent collection
 		assert_noop!(
-			LaosEvolution::transfer_ownership(
+			<LaosEvolution as EvolutionCollection<_, _>>::transfer_ownership(
 				AccountId::from_str(ALICE).unwrap(),
 				AccountId::from_str(BOB).unwrap(),
 				0_u64
@@ -107,7 +144,7 @@ fn transfer_collection_works() {
 
 		// Non-owner cannot transfer collection
 		assert_noop!(
-			LaosEvolution::transfer_ownership(
+			<LaosEvolution as EvolutionCollection<_, _>>::transfer_ownership(
 				AccountId::from_str(BOB).unwrap(),
 				new_owner,
 				collection_id
@@ -115,13 +152,26 @@ fn transfer_collection_works() {
 			Error::<Test>::NoPermission
 		);
 
-		assert_ok!(LaosEvolution::transfer_ownership(
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::transfer_ownership(
 			AccountId::from_str(ALICE).unwrap(),
 			new_owner,
 			collection_id
 		));
 
+		// ownership does not change until the new owner accepts it
+		assert_eq!(
+			LaosEvolution::collection_owner(collection_id),
+			Some(AccountId::from_str(ALICE).unwrap())
+		);
+		assert_eq!(LaosEvolution::pending_owner(collection_id), Some(new_owner));
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionOwnership<_>>::accept_ownership(
+			new_owner,
+			collection_id
+		));
+
 		assert_eq!(LaosEvolution::collection_owner(collection_id), Some(new_owner));
+		assert_eq!(LaosEvolution::pending_owner(collection_id), None);
 	});
 }
 
@@ -134,12 +184,26 @@ fn transfer_collection_emits_event() {
 		let collection_id = create_collection(ALICE);
 		let new_owner = AccountId::from_str(BOB).unwrap();
 
-		assert_ok!(LaosEvolution::transfer_ownership(
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::transfer_ownership(
 			AccountId::from_str(ALICE).unwrap(),
 			new_owner,
 			collection_id
 		));
 
+		System::assert_last_event(
+			Event::OwnershipTransferStarted {
+				collection_id,
+				from: AccountId::from_str(ALICE).unwrap(),
+				to: new_owner,
+			}
+			.into(),
+		);
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionOwnership<_>>::accept_ownership(
+			new_owner,
+			collection_id
+		));
+
 		// Assert that the correct event was deposited
 		System::assert_has_event(
 			Event::CollectionTransferred {
@@ -174,7 +238,7 @@ fn mint_with_external_uri_works() {
 		let slot = Slot::try_from(0).unwrap();
 		let owner = AccountId::from_str(ALICE).unwrap();
 
-		assert_ok!(LaosEvolution::mint_with_external_uri(
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
 			owner,
 			collection_id,
 			slot,
@@ -236,7 +300,7 @@ fn mint_with_external_uri_non_owner() {
 			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
 
 		assert_noop!(
-			LaosEvolution::mint_with_external_uri(
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
 				AccountId::from_str(BOB).unwrap(),
 				collection_id,
 				0.try_into().unwrap(),
@@ -260,7 +324,7 @@ fn mint_with_external_uri_collection_does_not_exist() {
 		let test_account = AccountId::from_str(ALICE).unwrap();
 
 		assert_noop!(
-			LaosEvolution::mint_with_external_uri(
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
 				test_account,
 				collection_id,
 				0.try_into().unwrap(),
@@ -282,7 +346,7 @@ fn mint_with_external_uri_asset_already_minted() {
 		let to = AccountId::from_str("0x0000000000000000000000000000000000000001").unwrap();
 
 		create_collection(ALICE);
-		assert_ok!(LaosEvolution::mint_with_external_uri(
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
 			owner,
 			collection_id,
 			0.try_into().unwrap(),
@@ -291,7 +355,7 @@ fn mint_with_external_uri_asset_already_minted() {
 		));
 
 		assert_noop!(
-			LaosEvolution::mint_with_external_uri(
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
 				owner,
 				collection_id,
 				0.try_into().unwrap(),
@@ -344,9 +408,14 @@ fn token_uri_of_existent_token_returns_correct_token_uri() {
 		let to = AccountId::from_str(BOB).unwrap();
 		let token_uri: TokenUriOf<Test> =
 			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
-		let token_id =
-			LaosEvolution::mint_with_external_uri(who, collection_id, slot, to, token_uri.clone())
-				.unwrap();
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			who,
+			collection_id,
+			slot,
+			to,
+			token_uri.clone(),
+		)
+		.unwrap();
 
 		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri));
 	});
@@ -364,7 +433,12 @@ fn evolve_with_external_uri_when_unexistent_collection_id_should_fail() {
 			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
 
 		assert_noop!(
-			LaosEvolution::evolve_with_external_uri(who, collection_id, token_id, new_token_uri),
+			<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+				who,
+				collection_id,
+				token_id,
+				new_token_uri
+			),
 			Error::<Test>::CollectionDoesNotExist
 		);
 	});
@@ -382,7 +456,12 @@ fn evolve_with_external_uri_when_sender_is_not_collection_owner_should_fail() {
 			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
 
 		assert_noop!(
-			LaosEvolution::evolve_with_external_uri(who, collection_id, token_id, new_token_uri),
+			<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+				who,
+				collection_id,
+				token_id,
+				new_token_uri
+			),
 			Error::<Test>::NoPermission
 		);
 	});
@@ -400,7 +479,12 @@ fn evolve_with_external_uri_when_asset_doesnt_exist_should_fail() {
 			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
 
 		assert_noop!(
-			LaosEvolution::evolve_with_external_uri(who, collection_id, token_id, new_token_uri),
+			<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+				who,
+				collection_id,
+				token_id,
+				new_token_uri
+			),
 			Error::<Test>::AssetDoesNotExist
 		);
 	});
@@ -420,7 +504,7 @@ fn evolve_with_external_uri_happy_path() {
 		let new_token_uri: TokenUriOf<Test> =
 			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
 
-		assert_ok!(LaosEvolution::mint_with_external_uri(
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
 			owner,
 			collection_id,
 			slot,
@@ -431,7 +515,7 @@ fn evolve_with_external_uri_happy_path() {
 		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri.clone()));
 
 		assert_eq!(
-			LaosEvolution::evolve_with_external_uri(
+			<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
 				owner,
 				collection_id,
 				token_id,
@@ -453,6 +537,3743 @@ fn evolve_with_external_uri_happy_path() {
 	});
 }
 
+#[test]
+fn burn_when_unexistent_collection_id_should_fail() {
+	new_test_ext().execute_with(|| {
+		let who = AccountId::from_str(ALICE).unwrap();
+		let collection_id = LaosEvolution::collection_counter();
+		let token_id = slot_and_owner_to_token_id(Slot::try_from(0).unwrap(), who);
+
+		assert_noop!(
+			LaosEvolution::burn(who, collection_id, token_id),
+			Error::<Test>::CollectionDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn burn_when_sender_is_not_collection_owner_should_fail() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let who = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> =
+			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			token_uri,
+		)
+		.unwrap();
+
+		assert_noop!(
+			LaosEvolution::burn(who, collection_id, token_id),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn burn_when_asset_doesnt_exist_should_fail() {
+	new_test_ext().execute_with(|| {
+		let who = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_id = slot_and_owner_to_token_id(Slot::try_from(0).unwrap(), who);
+
+		assert_noop!(
+			LaosEvolution::burn(who, collection_id, token_id),
+			Error::<Test>::AssetDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn burn_happy_path() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> =
+			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			token_uri.clone(),
+		)
+		.unwrap();
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri));
+
+		assert_ok!(LaosEvolution::burn(owner, collection_id, token_id));
+
+		// token uri is removed and event is emitted
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), None);
+		System::assert_last_event(Event::Burned { collection_id, token_id }.into());
+
+		// burned token cannot be burned twice
+		assert_noop!(
+			LaosEvolution::burn(owner, collection_id, token_id),
+			Error::<Test>::AssetDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn batch_mint_with_external_uri_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let to = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> =
+			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
+		let slots = [Slot::try_from(0).unwrap(), Slot::try_from(1).unwrap()];
+
+		let token_ids = LaosEvolution::batch_mint_with_external_uri(
+			owner,
+			collection_id,
+			slots.iter().map(|slot| (*slot, to, token_uri.clone())).collect(),
+		)
+		.unwrap();
+
+		assert_eq!(
+			token_ids,
+			slots
+				.iter()
+				.map(|slot| slot_and_owner_to_token_id(*slot, to))
+				.collect::<Vec<_>>()
+		);
+		for (slot, token_id) in slots.into_iter().zip(token_ids) {
+			assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri.clone()));
+			System::assert_has_event(
+				Event::MintedWithExternalURI {
+					collection_id,
+					slot,
+					to,
+					token_id,
+					token_uri: token_uri.clone(),
+				}
+				.into(),
+			);
+		}
+	});
+}
+
+#[test]
+fn batch_mint_with_external_uri_is_atomic() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> =
+			vec![1, MaxTokenUriLength::get() as u8].try_into().unwrap();
+		let first_slot = Slot::try_from(0).unwrap();
+
+		// second mint collides with the first one
+		assert_noop!(
+			LaosEvolution::batch_mint_with_external_uri(
+				owner,
+				collection_id,
+				vec![(first_slot, owner, token_uri.clone()), (first_slot, owner, token_uri)],
+			),
+			Error::<Test>::AlreadyMinted
+		);
+
+		let token_id = slot_and_owner_to_token_id(first_slot, owner);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), None);
+	});
+}
+
+#[test]
+fn batch_evolve_with_external_uri_works() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> = vec![1].try_into().unwrap();
+		let new_token_uri: TokenUriOf<Test> = vec![2].try_into().unwrap();
+		let token_ids = LaosEvolution::batch_mint_with_external_uri(
+			owner,
+			collection_id,
+			vec![
+				(Slot::try_from(0).unwrap(), owner, token_uri.clone()),
+				(Slot::try_from(1).unwrap(), owner, token_uri),
+			],
+		)
+		.unwrap();
+
+		assert_ok!(LaosEvolution::batch_evolve_with_external_uri(
+			owner,
+			collection_id,
+			token_ids.iter().map(|token_id| (*token_id, new_token_uri.clone())).collect(),
+		));
+
+		for token_id in token_ids {
+			assert_eq!(
+				LaosEvolution::token_uri(collection_id, token_id),
+				Some(new_token_uri.clone())
+			);
+		}
+	});
+}
+
+#[test]
+fn batch_evolve_with_external_uri_is_atomic() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> = vec![1].try_into().unwrap();
+		let new_token_uri: TokenUriOf<Test> = vec![2].try_into().unwrap();
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			token_uri.clone(),
+		)
+		.unwrap();
+		let unexistent_token_id = slot_and_owner_to_token_id(Slot::try_from(1).unwrap(), owner);
+
+		assert_noop!(
+			LaosEvolution::batch_evolve_with_external_uri(
+				owner,
+				collection_id,
+				vec![(token_id, new_token_uri.clone()), (unexistent_token_id, new_token_uri)],
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri));
+	});
+}
+
+#[test]
+fn create_collection_extrinsic_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let caller = AccountId::from_str(BOB).unwrap();
+		let owner = AccountId::from_str(ALICE).unwrap();
+
+		assert_ok!(LaosEvolution::create_collection(RuntimeOrigin::signed(caller), owner));
+
+		assert_eq!(LaosEvolution::collection_owner(0), Some(owner));
+		assert_eq!(LaosEvolution::collection_counter(), 1);
+		System::assert_last_event(Event::CollectionCreated { collection_id: 0, owner }.into());
+	});
+}
+
+#[test]
+fn extrinsics_require_signed_origin() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountId::from_str(ALICE).unwrap();
+
+		assert_noop!(
+			LaosEvolution::create_collection(RuntimeOrigin::none(), owner),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			LaosEvolution::mint_with_external_uri(
+				RuntimeOrigin::root(),
+				0,
+				Slot::try_from(0).unwrap(),
+				owner,
+				TokenUriOf::<Test>::default(),
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn mint_and_evolve_extrinsics_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let slot = Slot::try_from(1).unwrap();
+		let token_uri: TokenUriOf<Test> = vec![1, 2, 3].try_into().unwrap();
+		let new_token_uri: TokenUriOf<Test> = vec![4, 5, 6].try_into().unwrap();
+
+		assert_ok!(LaosEvolution::mint_with_external_uri(
+			RuntimeOrigin::signed(owner),
+			collection_id,
+			slot,
+			owner,
+			token_uri.clone(),
+		));
+		let token_id = slot_and_owner_to_token_id(slot, owner);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri));
+
+		assert_ok!(LaosEvolution::evolve_with_external_uri(
+			RuntimeOrigin::signed(owner),
+			collection_id,
+			token_id,
+			new_token_uri.clone(),
+		));
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(new_token_uri.clone()));
+		System::assert_last_event(
+			Event::EvolvedWithExternalURI { collection_id, token_id, token_uri: new_token_uri }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn mint_extrinsic_from_non_owner_fails() {
+	new_test_ext().execute_with(|| {
+		let collection_id = create_collection(ALICE);
+		let bob = AccountId::from_str(BOB).unwrap();
+
+		assert_noop!(
+			LaosEvolution::mint_with_external_uri(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				Slot::try_from(0).unwrap(),
+				bob,
+				TokenUriOf::<Test>::default(),
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn transfer_ownership_extrinsic_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let collection_id = create_collection(ALICE);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob
+		));
+		System::assert_last_event(
+			Event::OwnershipTransferStarted { collection_id, from: alice, to: bob }.into(),
+		);
+
+		assert_ok!(LaosEvolution::accept_ownership(RuntimeOrigin::signed(bob), collection_id));
+
+		assert_eq!(LaosEvolution::collection_owner(collection_id), Some(bob));
+		System::assert_last_event(
+			Event::CollectionTransferred { collection_id, from: alice, to: bob }.into(),
+		);
+	});
+}
+
+#[test]
+fn only_pending_owner_can_accept_ownership() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		// no pending transfer
+		assert_noop!(
+			LaosEvolution::accept_ownership(RuntimeOrigin::signed(bob), collection_id),
+			Error::<Test>::NotPendingOwner
+		);
+
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob
+		));
+
+		// the current owner cannot accept on behalf of the pending owner
+		assert_noop!(
+			LaosEvolution::accept_ownership(RuntimeOrigin::signed(alice), collection_id),
+			Error::<Test>::NotPendingOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_ownership_replaces_pending_owner() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let charlie = AccountId::from_low_u64_be(7);
+		let collection_id = create_collection(ALICE);
+
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			charlie
+		));
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob
+		));
+
+		assert_eq!(LaosEvolution::pending_owner(collection_id), Some(bob));
+		assert_noop!(
+			LaosEvolution::accept_ownership(RuntimeOrigin::signed(charlie), collection_id),
+			Error::<Test>::NotPendingOwner
+		);
+	});
+}
+
+#[test]
+fn cancel_ownership_transfer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::cancel_ownership_transfer(RuntimeOrigin::signed(alice), collection_id),
+			Error::<Test>::NoPendingOwnershipTransfer
+		);
+
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob
+		));
+
+		// only the owner can cancel
+		assert_noop!(
+			LaosEvolution::cancel_ownership_transfer(RuntimeOrigin::signed(bob), collection_id),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(LaosEvolution::cancel_ownership_transfer(
+			RuntimeOrigin::signed(alice),
+			collection_id
+		));
+		System::assert_last_event(
+			Event::OwnershipTransferCanceled { collection_id, pending_owner: bob }.into(),
+		);
+
+		assert_eq!(LaosEvolution::pending_owner(collection_id), None);
+		assert_noop!(
+			LaosEvolution::accept_ownership(RuntimeOrigin::signed(bob), collection_id),
+			Error::<Test>::NotPendingOwner
+		);
+		assert_eq!(LaosEvolution::collection_owner(collection_id), Some(alice));
+	});
+}
+
+#[test]
+fn tokens_of_collection_paginates() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let other_collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> = vec![1, 2, 3].try_into().unwrap();
+
+		for slot in 0..5u128 {
+			assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				owner,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				owner,
+				token_uri.clone(),
+			));
+		}
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			other_collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			token_uri.clone(),
+		));
+
+		let all = LaosEvolution::tokens_of_collection(collection_id, None, 10);
+		assert_eq!(all.len(), 5);
+		assert!(all.iter().all(|(_, uri)| *uri == token_uri));
+
+		let first_page = LaosEvolution::tokens_of_collection(collection_id, None, 2);
+		let second_page =
+			LaosEvolution::tokens_of_collection(collection_id, Some(first_page[1].0), 2);
+		let last_page =
+			LaosEvolution::tokens_of_collection(collection_id, Some(second_page[1].0), 2);
+		assert_eq!(first_page, all[0..2]);
+		assert_eq!(second_page, all[2..4]);
+		assert_eq!(last_page, all[4..]);
+
+		assert!(LaosEvolution::tokens_of_collection(2, None, 10).is_empty());
+	});
+}
+
+#[test]
+fn freeze_token_prevents_evolution() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> = vec![1, 2, 3].try_into().unwrap();
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			token_uri.clone(),
+		)
+		.unwrap();
+		assert!(!<LaosEvolution as EvolutionCollectionFreezing<_>>::is_frozen(
+			collection_id,
+			token_id
+		));
+
+		assert_ok!(LaosEvolution::freeze_token(
+			RuntimeOrigin::signed(owner),
+			collection_id,
+			token_id
+		));
+		System::assert_last_event(Event::TokenFrozen { collection_id, token_id }.into());
+		assert!(<LaosEvolution as EvolutionCollectionFreezing<_>>::is_frozen(
+			collection_id,
+			token_id
+		));
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+				owner,
+				collection_id,
+				token_id,
+				vec![4, 5, 6].try_into().unwrap(),
+			),
+			Error::<Test>::TokenFrozen
+		);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri));
+	});
+}
+
+#[test]
+fn freeze_token_checks_collection_owner_and_token() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let token_id = slot_and_owner_to_token_id(Slot::try_from(0).unwrap(), alice);
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionFreezing<_>>::freeze_token(alice, 0, token_id),
+			Error::<Test>::CollectionDoesNotExist
+		);
+
+		let collection_id = create_collection(ALICE);
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionFreezing<_>>::freeze_token(
+				alice,
+				collection_id,
+				token_id
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			TokenUriOf::<Test>::default(),
+		));
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionFreezing<_>>::freeze_token(
+				bob,
+				collection_id,
+				token_id
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn freeze_collection_prevents_evolution_of_all_tokens() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let other_collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::freeze_collection(
+				RuntimeOrigin::signed(AccountId::from_str(BOB).unwrap()),
+				collection_id
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(LaosEvolution::freeze_collection(RuntimeOrigin::signed(owner), collection_id));
+		System::assert_last_event(Event::CollectionFrozen { collection_id }.into());
+
+		// minting is still allowed in a frozen collection, but tokens cannot be evolved
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<Test>::default(),
+		)
+		.unwrap();
+		assert_noop!(
+			<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+				owner,
+				collection_id,
+				token_id,
+				vec![1].try_into().unwrap(),
+			),
+			Error::<Test>::TokenFrozen
+		);
+
+		// other collections are not affected
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			other_collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<Test>::default(),
+		));
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+			owner,
+			other_collection_id,
+			token_id,
+			vec![1].try_into().unwrap(),
+		));
+	});
+}
+
+#[test]
+fn frozen_token_cannot_be_burned_nor_minted_again() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<Test>::default(),
+		)
+		.unwrap();
+		assert_ok!(<LaosEvolution as EvolutionCollectionFreezing<_>>::freeze_token(
+			owner,
+			collection_id,
+			token_id
+		));
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionBurn<_>>::burn(owner, collection_id, token_id),
+			Error::<Test>::TokenFrozen
+		);
+		assert_noop!(
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				owner,
+				collection_id,
+				Slot::try_from(0).unwrap(),
+				owner,
+				b"new".to_vec().try_into().unwrap(),
+			),
+			Error::<Test>::AlreadyMinted
+		);
+		assert!(<LaosEvolution as EvolutionCollectionFreezing<_>>::is_frozen(
+			collection_id,
+			token_id
+		));
+	});
+}
+
+#[test]
+fn token_of_frozen_collection_cannot_be_burned() {
+	new_test_ext().execute_with(|| {
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<Test>::default(),
+		)
+		.unwrap();
+		assert_ok!(<LaosEvolution as EvolutionCollectionFreezing<_>>::freeze_collection(
+			owner,
+			collection_id
+		));
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionBurn<_>>::burn(owner, collection_id, token_id),
+			Error::<Test>::TokenFrozen
+		);
+	});
+}
+
+#[test]
+fn minter_can_mint_but_not_evolve() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let minter = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				minter,
+				collection_id,
+				Slot::try_from(0).unwrap(),
+				minter,
+				TokenUriOf::<Test>::default(),
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(LaosEvolution::grant_role(
+			RuntimeOrigin::signed(owner),
+			collection_id,
+			Role::Minter,
+			minter
+		));
+		System::assert_last_event(
+			Event::RoleGranted { collection_id, role: Role::Minter, account: minter }.into(),
+		);
+		assert!(<LaosEvolution as EvolutionCollectionRoles<_>>::has_role(
+			collection_id,
+			Role::Minter,
+			minter
+		));
+
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			minter,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			minter,
+			TokenUriOf::<Test>::default(),
+		)
+		.unwrap();
+		assert_noop!(
+			<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+				minter,
+				collection_id,
+				token_id,
+				vec![1].try_into().unwrap(),
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn evolver_can_evolve_until_role_is_revoked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let owner = AccountId::from_str(ALICE).unwrap();
+		let evolver = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			owner,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			owner,
+			TokenUriOf::<Test>::default(),
+		)
+		.unwrap();
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionRoles<_>>::grant_role(
+			owner,
+			collection_id,
+			Role::Evolver,
+			evolver
+		));
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+			evolver,
+			collection_id,
+			token_id,
+			vec![1].try_into().unwrap(),
+		));
+
+		assert_ok!(LaosEvolution::revoke_role(
+			RuntimeOrigin::signed(owner),
+			collection_id,
+			Role::Evolver,
+			evolver
+		));
+		System::assert_last_event(
+			Event::RoleRevoked { collection_id, role: Role::Evolver, account: evolver }.into(),
+		);
+		assert_noop!(
+			<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+				evolver,
+				collection_id,
+				token_id,
+				vec![2].try_into().unwrap(),
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn only_owner_can_manage_roles() {
+	new_test_ext().execute_with(|| {
+		let bob = AccountId::from_str(BOB).unwrap();
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionRoles<_>>::grant_role(bob, 0, Role::Minter, bob),
+			Error::<Test>::CollectionDoesNotExist
+		);
+
+		let collection_id = create_collection(ALICE);
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionRoles<_>>::grant_role(
+				bob,
+				collection_id,
+				Role::Minter,
+				bob
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionRoles<_>>::revoke_role(
+				bob,
+				collection_id,
+				Role::Minter,
+				bob
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn renounce_ownership_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::renounce_ownership(RuntimeOrigin::signed(alice), collection_id + 1),
+			Error::<Test>::CollectionDoesNotExist
+		);
+		assert_noop!(
+			LaosEvolution::renounce_ownership(RuntimeOrigin::signed(bob), collection_id),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob
+		));
+		assert_ok!(LaosEvolution::renounce_ownership(RuntimeOrigin::signed(alice), collection_id));
+		System::assert_last_event(Event::OwnershipRenounced { collection_id, owner: alice }.into());
+
+		assert_eq!(LaosEvolution::collection_owner(collection_id), None);
+		assert!(LaosEvolution::collection_exists(collection_id));
+
+		// the pending transfer is dropped
+		assert_eq!(LaosEvolution::pending_owner(collection_id), None);
+		assert_noop!(
+			LaosEvolution::accept_ownership(RuntimeOrigin::signed(bob), collection_id),
+			Error::<Test>::NotPendingOwner
+		);
+	});
+}
+
+#[test]
+fn collection_without_owner_cannot_be_minted_nor_evolved() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			TokenUriOf::<Test>::default(),
+		)
+		.unwrap();
+		assert_ok!(<LaosEvolution as EvolutionCollectionRoles<_>>::grant_role(
+			alice,
+			collection_id,
+			Role::Minter,
+			bob
+		));
+		assert_ok!(<LaosEvolution as EvolutionCollectionRoles<_>>::grant_role(
+			alice,
+			collection_id,
+			Role::Evolver,
+			bob
+		));
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionOwnership<_>>::renounce_ownership(
+			alice,
+			collection_id
+		));
+
+		for who in [alice, bob] {
+			assert_noop!(
+				<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+					who,
+					collection_id,
+					Slot::try_from(1).unwrap(),
+					who,
+					TokenUriOf::<Test>::default(),
+				),
+				Error::<Test>::NoPermission
+			);
+			assert_noop!(
+				<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+					who,
+					collection_id,
+					token_id,
+					vec![1].try_into().unwrap(),
+				),
+				Error::<Test>::NoPermission
+			);
+		}
+
+		// the token URI is still readable
+		assert_eq!(
+			LaosEvolution::token_uri(collection_id, token_id),
+			Some(TokenUriOf::<Test>::default())
+		);
+	});
+}
+
+#[test]
+fn set_collection_metadata_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let metadata = CollectionMetadata::<Test> {
+			name: b"LAOS Punks".to_vec().try_into().unwrap(),
+			symbol: b"LPUNK".to_vec().try_into().unwrap(),
+			contract_uri: b"ipfs://contract".to_vec().try_into().unwrap(),
+		};
+
+		assert_eq!(LaosEvolution::collection_metadata(collection_id), None);
+
+		assert_noop!(
+			LaosEvolution::set_collection_metadata(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				metadata.clone()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LaosEvolution::set_collection_metadata(
+				RuntimeOrigin::signed(alice),
+				collection_id + 1,
+				metadata.clone()
+			),
+			Error::<Test>::CollectionDoesNotExist
+		);
+
+		assert_ok!(LaosEvolution::set_collection_metadata(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			metadata.clone()
+		));
+		System::assert_last_event(Event::CollectionMetadataUpdated { collection_id }.into());
+		assert_eq!(LaosEvolution::collection_metadata(collection_id), Some(metadata));
+	});
+}
+
+#[test]
+fn create_collection_with_metadata_works() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let metadata = CollectionMetadata::<Test> {
+			name: b"LAOS Punks".to_vec().try_into().unwrap(),
+			symbol: b"LPUNK".to_vec().try_into().unwrap(),
+			contract_uri: Default::default(),
+		};
+
+		let collection_id =
+			<LaosEvolution as EvolutionCollectionMetadata<_, _>>::create_collection_with_metadata(
+				alice,
+				alice,
+				metadata.clone(),
+			)
+			.unwrap();
+
+		assert_eq!(LaosEvolution::collection_owner(collection_id), Some(alice));
+		assert_eq!(LaosEvolution::collection_metadata(collection_id), Some(metadata));
+	});
+}
+
+#[test]
+fn set_default_royalty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_id = slot_and_owner_to_token_id(Slot::try_from(0).unwrap(), alice);
+
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionRoyalties<_>>::royalty_info(
+				collection_id,
+				token_id
+			),
+			None
+		);
+
+		assert_noop!(
+			LaosEvolution::set_default_royalty(RuntimeOrigin::signed(bob), collection_id, bob, 500),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LaosEvolution::set_default_royalty(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				bob,
+				MAX_ROYALTY_BASIS_POINTS + 1
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+
+		assert_ok!(LaosEvolution::set_default_royalty(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob,
+			500
+		));
+		System::assert_last_event(
+			Event::DefaultRoyaltySet { collection_id, receiver: bob, basis_points: 500 }.into(),
+		);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionRoyalties<_>>::royalty_info(
+				collection_id,
+				token_id
+			),
+			Some(RoyaltyInfo { receiver: bob, basis_points: 500 })
+		);
+	});
+}
+
+#[test]
+fn token_royalty_overrides_default_royalty() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> = vec![1, 2, 3].try_into().unwrap();
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			token_uri,
+		)
+		.unwrap();
+		let other_token_id = slot_and_owner_to_token_id(Slot::try_from(1).unwrap(), alice);
+
+		assert_noop!(
+			LaosEvolution::set_token_royalty(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				other_token_id,
+				bob,
+				1_000
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		assert_ok!(LaosEvolution::set_default_royalty(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			alice,
+			250
+		));
+		assert_ok!(LaosEvolution::set_token_royalty(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			bob,
+			1_000
+		));
+		System::assert_last_event(
+			Event::TokenRoyaltySet { collection_id, token_id, receiver: bob, basis_points: 1_000 }
+				.into(),
+		);
+
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionRoyalties<_>>::royalty_info(
+				collection_id,
+				token_id
+			),
+			Some(RoyaltyInfo { receiver: bob, basis_points: 1_000 })
+		);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionRoyalties<_>>::royalty_info(
+				collection_id,
+				other_token_id
+			),
+			Some(RoyaltyInfo { receiver: alice, basis_points: 250 })
+		);
+
+		// burning the token removes its royalty
+		assert_ok!(LaosEvolution::burn(alice, collection_id, token_id));
+		assert_eq!(LaosEvolution::token_royalty(collection_id, token_id), None);
+	});
+}
+
+#[test]
+fn evolve_keeps_token_uri_history() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri = |i: u8| -> TokenUriOf<Test> { vec![i].try_into().unwrap() };
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			token_uri(0),
+		)
+		.unwrap();
+
+		assert_eq!(LaosEvolution::token_uri_version(collection_id, token_id), 0);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+				collection_id,
+				token_id,
+				0
+			),
+			Some(token_uri(0))
+		);
+
+		for i in 1..=2 {
+			assert_ok!(LaosEvolution::evolve_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				token_uri(i)
+			));
+		}
+
+		assert_eq!(LaosEvolution::token_uri_version(collection_id, token_id), 2);
+		for i in 0..=2 {
+			assert_eq!(
+				<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+					collection_id,
+					token_id,
+					i as u32
+				),
+				Some(token_uri(i))
+			);
+		}
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+				collection_id,
+				token_id,
+				3
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn token_uri_history_is_pruned() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri = |i: u8| -> TokenUriOf<Test> { vec![i].try_into().unwrap() };
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			token_uri(0),
+		)
+		.unwrap();
+
+		let max_history = MaxUriHistory::get();
+		for i in 1..=(max_history as u8 + 1) {
+			assert_ok!(LaosEvolution::evolve_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				token_uri(i)
+			));
+		}
+
+		let version = LaosEvolution::token_uri_version(collection_id, token_id);
+		assert_eq!(version, max_history + 1);
+		// the first version has been pruned, the last `max_history` ones are kept
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+				collection_id,
+				token_id,
+				0
+			),
+			None
+		);
+		assert_eq!(
+			TokenURIHistory::<Test>::iter_prefix((collection_id, token_id)).count(),
+			max_history as usize
+		);
+		for i in 1..=version {
+			assert_eq!(
+				<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+					collection_id,
+					token_id,
+					i
+				),
+				Some(token_uri(i as u8))
+			);
+		}
+	});
+}
+
+#[test]
+fn mint_with_nonce_uses_the_nonce() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let mint_with_nonce = |slot: u128, nonce: u64| {
+			<LaosEvolution as EvolutionCollectionNonces<_, _>>::mint_with_nonce(
+				alice,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				bob,
+				TokenUriOf::<Test>::default(),
+				U256::from(nonce),
+			)
+		};
+		let is_mint_nonce_used = |nonce: u64| {
+			<LaosEvolution as EvolutionCollectionNonces<_, _>>::is_mint_nonce_used(
+				collection_id,
+				alice,
+				U256::from(nonce),
+			)
+		};
+
+		// nonces can be used in any order
+		assert!(!is_mint_nonce_used(2));
+		assert_ok!(mint_with_nonce(0, 2));
+		assert!(is_mint_nonce_used(2));
+		assert!(!is_mint_nonce_used(1));
+		assert_ok!(mint_with_nonce(1, 1));
+
+		// a nonce cannot be reused for any other token, even once its token is burned
+		assert_noop!(mint_with_nonce(2, 2), Error::<Test>::MintNonceAlreadyUsed);
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			slot_and_owner_to_token_id(Slot::try_from(0).unwrap(), bob)
+		));
+		assert_noop!(mint_with_nonce(0, 2), Error::<Test>::MintNonceAlreadyUsed);
+	});
+}
+
+#[test]
+fn mint_nonces_are_per_signer() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		assert_ok!(LaosEvolution::grant_role(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			Role::Minter,
+			bob
+		));
+		let mint_with_nonce = |signer: AccountId, slot: u128| {
+			<LaosEvolution as EvolutionCollectionNonces<_, _>>::mint_with_nonce(
+				signer,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				bob,
+				TokenUriOf::<Test>::default(),
+				U256::zero(),
+			)
+		};
+
+		assert_ok!(mint_with_nonce(alice, 0));
+		assert_ok!(mint_with_nonce(bob, 1));
+		assert_noop!(mint_with_nonce(bob, 2), Error::<Test>::MintNonceAlreadyUsed);
+	});
+}
+
+#[test]
+fn mint_with_nonce_requires_the_owner_or_a_minter() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let mint_with_nonce = |signer: AccountId, slot: u128| {
+			<LaosEvolution as EvolutionCollectionNonces<_, _>>::mint_with_nonce(
+				signer,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				bob,
+				TokenUriOf::<Test>::default(),
+				U256::from(slot),
+			)
+		};
+
+		assert_noop!(mint_with_nonce(bob, 0), Error::<Test>::NoPermission);
+
+		assert_ok!(LaosEvolution::grant_role(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			Role::Minter,
+			bob
+		));
+		assert_ok!(mint_with_nonce(bob, 0));
+		assert_ok!(mint_with_nonce(alice, 1));
+	});
+}
+
+#[test]
+fn invalidate_mint_nonce_prevents_minting_with_it() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let nonce = U256::from(7);
+
+		assert_noop!(
+			LaosEvolution::invalidate_mint_nonce(RuntimeOrigin::signed(alice), 1, nonce),
+			Error::<Test>::CollectionDoesNotExist
+		);
+
+		assert_ok!(LaosEvolution::invalidate_mint_nonce(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			nonce
+		));
+		System::assert_last_event(
+			Event::MintNonceInvalidated { collection_id, signer: alice, nonce }.into(),
+		);
+		assert!(<LaosEvolution as EvolutionCollectionNonces<_, _>>::is_mint_nonce_used(
+			collection_id,
+			alice,
+			nonce
+		));
+		// only the nonce of the origin is invalidated
+		assert!(!<LaosEvolution as EvolutionCollectionNonces<_, _>>::is_mint_nonce_used(
+			collection_id,
+			bob,
+			nonce
+		));
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionNonces<_, _>>::mint_with_nonce(
+				alice,
+				collection_id,
+				Slot::try_from(0).unwrap(),
+				bob,
+				TokenUriOf::<Test>::default(),
+				nonce,
+			),
+			Error::<Test>::MintNonceAlreadyUsed
+		);
+		assert_noop!(
+			LaosEvolution::invalidate_mint_nonce(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				nonce
+			),
+			Error::<Test>::MintNonceAlreadyUsed
+		);
+	});
+}
+
+#[test]
+fn drop_leaf_matches_openzeppelin_standard_merkle_tree() {
+	let alice = AccountId::from_str(ALICE).unwrap();
+	let bob = AccountId::from_str(BOB).unwrap();
+	let leaf_alice = drop_leaf(Slot::try_from(1).unwrap(), alice, b"ipfs://1");
+	let leaf_bob = drop_leaf(Slot::try_from(2).unwrap(), bob, b"ipfs://2");
+
+	assert_eq!(
+		leaf_alice,
+		H256::from_str("bf404b85f81a2c39782076e1c576e7fd6e891d6de394361a889a6ef08092f223").unwrap()
+	);
+	assert_eq!(
+		leaf_bob,
+		H256::from_str("ef0a45be95734f7ae2a42be631cc672bd705e28547d665f4cef9fd8c2eb9ff51").unwrap()
+	);
+	// pairs are sorted, so both leaves lead to the same root
+	let root =
+		H256::from_str("50a62a36280c2db06d0e76a241542b43df2f9bf86ad484921576343fc9953de2").unwrap();
+	assert_eq!(merkle_root(leaf_alice, &[leaf_bob]), root);
+	assert_eq!(merkle_root(leaf_bob, &[leaf_alice]), root);
+}
+
+#[test]
+fn set_drop_root_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let root = H256::repeat_byte(1);
+
+		assert_eq!(LaosEvolution::drop_root(collection_id), None);
+		assert_ok!(LaosEvolution::set_drop_root(RuntimeOrigin::signed(alice), collection_id, root));
+		assert_eq!(LaosEvolution::drop_root(collection_id), Some(root));
+		System::assert_last_event(Event::DropRootSet { collection_id, root }.into());
+	});
+}
+
+#[test]
+fn non_owner_cannot_set_drop_root() {
+	new_test_ext().execute_with(|| {
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::set_drop_root(
+				RuntimeOrigin::signed(AccountId::from_str(BOB).unwrap()),
+				collection_id,
+				H256::repeat_byte(1)
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn claim_from_drop_mints_every_leaf_once() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri = |i: u8| -> TokenUriOf<Test> { vec![i].try_into().unwrap() };
+		let slot = |i: u128| Slot::try_from(i).unwrap();
+		let leaves = [
+			drop_leaf(slot(0), bob, &token_uri(0)),
+			drop_leaf(slot(1), bob, &token_uri(1)),
+			drop_leaf(slot(2), alice, &token_uri(2)),
+		];
+		let node = merkle_root(leaves[0], &[leaves[1]]);
+		let root = merkle_root(node, &[leaves[2]]);
+		assert_ok!(<LaosEvolution as EvolutionCollectionDrop<_, _>>::set_drop_root(
+			alice,
+			collection_id,
+			root
+		));
+
+		let token_id = <LaosEvolution as EvolutionCollectionDrop<_, _>>::claim_from_drop(
+			collection_id,
+			vec![leaves[0], leaves[2]],
+			slot(1),
+			bob,
+			token_uri(1),
+		)
+		.unwrap();
+		assert_eq!(token_id, slot_and_owner_to_token_id(slot(1), bob));
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri(1)));
+		assert_ok!(<LaosEvolution as EvolutionCollectionDrop<_, _>>::claim_from_drop(
+			collection_id,
+			vec![node],
+			slot(2),
+			alice,
+			token_uri(2),
+		));
+
+		// leaves cannot be claimed again, even if the token is burned
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			token_id
+		));
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionDrop<_, _>>::claim_from_drop(
+				collection_id,
+				vec![leaves[0], leaves[2]],
+				slot(1),
+				bob,
+				token_uri(1),
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn claim_from_drop_with_invalid_proof_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let slot = Slot::try_from(0).unwrap();
+		let token_uri = TokenUriOf::<Test>::default();
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionDrop<_, _>>::claim_from_drop(
+				collection_id,
+				vec![],
+				slot,
+				alice,
+				token_uri.clone(),
+			),
+			Error::<Test>::DropDoesNotExist
+		);
+
+		let leaf = drop_leaf(slot, alice, &token_uri);
+		assert_ok!(<LaosEvolution as EvolutionCollectionDrop<_, _>>::set_drop_root(
+			alice,
+			collection_id,
+			merkle_root(leaf, &[H256::repeat_byte(1)])
+		));
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionDrop<_, _>>::claim_from_drop(
+				collection_id,
+				vec![H256::repeat_byte(2)],
+				slot,
+				alice,
+				token_uri,
+			),
+			Error::<Test>::InvalidMerkleProof
+		);
+	});
+}
+
+#[test]
+fn claim_from_drop_of_renounced_collection_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let slot = Slot::try_from(0).unwrap();
+		let token_uri = TokenUriOf::<Test>::default();
+		let leaf = drop_leaf(slot, alice, &token_uri);
+		assert_ok!(<LaosEvolution as EvolutionCollectionDrop<_, _>>::set_drop_root(
+			alice,
+			collection_id,
+			leaf
+		));
+
+		assert_ok!(LaosEvolution::renounce_ownership(RuntimeOrigin::signed(alice), collection_id));
+		assert_eq!(LaosEvolution::drop_root(collection_id), None);
+
+		assert_noop!(
+			<LaosEvolution as EvolutionCollectionDrop<_, _>>::claim_from_drop(
+				collection_id,
+				vec![],
+				slot,
+				alice,
+				token_uri,
+			),
+			Error::<Test>::OwnershipRenounced
+		);
+	});
+}
+
+#[test]
+fn mint_and_burn_update_token_count() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let mint = |slot: u128| {
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				alice,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				alice,
+				TokenUriOf::<Test>::default(),
+			)
+		};
+
+		assert_eq!(LaosEvolution::token_count(collection_id), 0);
+		let token_id = mint(0).unwrap();
+		assert_ok!(mint(1));
+		assert_eq!(LaosEvolution::token_count(collection_id), 2);
+
+		assert_ok!(LaosEvolution::burn(alice, collection_id, token_id));
+		assert_eq!(LaosEvolution::token_count(collection_id), 1);
+	});
+}
+
+#[test]
+fn create_collection_with_max_supply_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = LaosEvolution::collection_counter();
+
+		assert_ok!(LaosEvolution::create_collection_with_max_supply(
+			RuntimeOrigin::signed(AccountId::from_str(BOB).unwrap()),
+			alice,
+			10
+		));
+		assert_eq!(LaosEvolution::collection_owner(collection_id), Some(alice));
+		assert_eq!(LaosEvolution::max_supply(collection_id), Some(10));
+		System::assert_last_event(Event::CollectionCreated { collection_id, owner: alice }.into());
+
+		// collections created without a max supply are not capped
+		assert_eq!(LaosEvolution::max_supply(create_collection(ALICE)), None);
+	});
+}
+
+#[test]
+fn create_collection_with_metadata_and_max_supply_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = LaosEvolution::collection_counter();
+		let metadata = CollectionMetadata::<Test> {
+			name: b"LAOS Punks".to_vec().try_into().unwrap(),
+			symbol: b"LPUNK".to_vec().try_into().unwrap(),
+			contract_uri: Default::default(),
+		};
+
+		assert_ok!(LaosEvolution::create_collection_with_metadata_and_max_supply(
+			RuntimeOrigin::signed(AccountId::from_str(BOB).unwrap()),
+			alice,
+			metadata.clone(),
+			10
+		));
+		assert_eq!(LaosEvolution::collection_owner(collection_id), Some(alice));
+		assert_eq!(LaosEvolution::collection_metadata(collection_id), Some(metadata));
+		assert_eq!(LaosEvolution::max_supply(collection_id), Some(10));
+		System::assert_last_event(Event::CollectionCreated { collection_id, owner: alice }.into());
+	});
+}
+
+#[test]
+fn mint_beyond_max_supply_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id =
+			<LaosEvolution as EvolutionCollectionFactory<_>>::create_collection_with_max_supply(
+				alice, alice, 1,
+			)
+			.unwrap();
+		let mint = |slot: u128| {
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				alice,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				alice,
+				TokenUriOf::<Test>::default(),
+			)
+		};
+
+		let token_id = mint(0).unwrap();
+		assert_noop!(mint(1), Error::<Test>::MaxSupplyReached);
+
+		// burned tokens keep counting towards the max supply
+		assert_ok!(LaosEvolution::burn(alice, collection_id, token_id));
+		assert_eq!(LaosEvolution::token_count(collection_id), 0);
+		assert_eq!(LaosEvolution::minted_count(collection_id), 1);
+		assert_noop!(mint(1), Error::<Test>::MaxSupplyReached);
+	});
+}
+
+#[test]
+fn burn_moves_last_token_to_the_burned_index() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_ids: Vec<TokenId> = (0..3)
+			.map(|slot| {
+				<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+					alice,
+					collection_id,
+					Slot::try_from(slot).unwrap(),
+					alice,
+					TokenUriOf::<Test>::default(),
+				)
+				.unwrap()
+			})
+			.collect();
+		assert_eq!(LaosEvolution::tokens_by_index(collection_id, 0, 10), token_ids);
+
+		assert_ok!(LaosEvolution::burn(alice, collection_id, token_ids[0]));
+		assert_eq!(LaosEvolution::token_by_index(collection_id, 0), Some(token_ids[2]));
+		assert_eq!(LaosEvolution::token_by_index(collection_id, 2), None);
+		assert_eq!(
+			LaosEvolution::tokens_by_index(collection_id, 0, 10),
+			vec![token_ids[2], token_ids[1]]
+		);
+
+		// burning the last token leaves the rest of the index untouched
+		assert_ok!(LaosEvolution::burn(alice, collection_id, token_ids[1]));
+		assert_eq!(LaosEvolution::tokens_by_index(collection_id, 0, 10), vec![token_ids[2]]);
+	});
+}
+
+#[test]
+fn tokens_by_index_is_paginated() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_ids: Vec<TokenId> = (0..5)
+			.map(|slot| {
+				<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+					alice,
+					collection_id,
+					Slot::try_from(slot).unwrap(),
+					alice,
+					TokenUriOf::<Test>::default(),
+				)
+				.unwrap()
+			})
+			.collect();
+
+		assert_eq!(LaosEvolution::tokens_by_index(collection_id, 0, 2), token_ids[..2]);
+		assert_eq!(LaosEvolution::tokens_by_index(collection_id, 2, 2), token_ids[2..4]);
+		assert_eq!(LaosEvolution::tokens_by_index(collection_id, 4, 2), token_ids[4..]);
+		assert!(LaosEvolution::tokens_by_index(collection_id, 5, 2).is_empty());
+	});
+}
+
+#[test]
+fn migration_to_v1_indexes_existing_tokens() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<LaosEvolution>();
+		let token_uri = TokenUriOf::<Test>::default();
+		// tokens minted before the index existed
+		TokenURI::<Test>::insert(0, TokenId::from(1), token_uri.clone());
+		TokenURI::<Test>::insert(0, TokenId::from(2), token_uri.clone());
+		TokenURI::<Test>::insert(1, TokenId::from(3), token_uri);
+
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(LaosEvolution::on_chain_storage_version(), 1);
+		assert!(TokenIndexMigrationCursor::<Test>::exists());
+
+		LaosEvolution::on_idle(1, Weight::MAX);
+
+		assert!(!TokenIndexMigrationCursor::<Test>::exists());
+		assert_eq!(LaosEvolution::on_chain_storage_version(), 2);
+		assert_eq!(LaosEvolution::token_count(0), 2);
+		assert_eq!(LaosEvolution::token_count(1), 1);
+		let mut tokens = LaosEvolution::tokens_by_index(0, 0, 10);
+		tokens.sort();
+		assert_eq!(tokens, vec![TokenId::from(1), TokenId::from(2)]);
+		assert_eq!(LaosEvolution::tokens_by_index(1, 0, 10), vec![TokenId::from(3)]);
+		for index in 0..2 {
+			let token_id = LaosEvolution::token_by_index(0, index).unwrap();
+			assert_eq!(TokenIndex::<Test>::get(0, token_id), Some(index));
+		}
+	});
+}
+
+#[test]
+fn migration_to_v1_is_bounded_by_the_proof_size() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<LaosEvolution>();
+		let token_uri = TokenUriOf::<Test>::default();
+		for token_id in 1..=3 {
+			TokenURI::<Test>::insert(0, TokenId::from(token_id), token_uri.clone());
+		}
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(LaosEvolution::token_count(0), 1);
+
+		// no token fits in a block without proof size left
+		LaosEvolution::on_idle(1, Weight::from_parts(u64::MAX, 0));
+		assert_eq!(LaosEvolution::token_count(0), 1);
+		assert_eq!(LaosEvolution::on_chain_storage_version(), 1);
+
+		let weight = LaosEvolution::on_idle(1, Weight::MAX);
+		assert!(weight.proof_size() > 0);
+		assert!(!TokenIndexMigrationCursor::<Test>::exists());
+		assert_eq!(LaosEvolution::token_count(0), 3);
+	});
+}
+
+#[test]
+fn token_index_migration_only_reads_the_storage_version_once_done() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<LaosEvolution>();
+		TokenURI::<Test>::insert(0, TokenId::from(1), TokenUriOf::<Test>::default());
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		LaosEvolution::on_idle(1, Weight::MAX);
+		assert_eq!(LaosEvolution::on_chain_storage_version(), 2);
+
+		// the encoded storage version and a single node of the trie proving it
+		assert_eq!(LaosEvolution::on_idle(1, Weight::MAX), Weight::from_parts(0, 2 + 15 * 33));
+		assert_eq!(LaosEvolution::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn migration_to_v1_indexes_tokens_across_blocks() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<LaosEvolution>();
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri = TokenUriOf::<Test>::default();
+		// tokens minted before the index existed
+		let old_token_ids: Vec<TokenId> = (1..=3)
+			.map(|slot| {
+				let token_id = slot_and_owner_to_token_id(Slot::try_from(slot).unwrap(), alice);
+				TokenURI::<Test>::insert(collection_id, token_id, token_uri.clone());
+				token_id
+			})
+			.collect();
+		// the upgrade only indexes the first token
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(LaosEvolution::token_count(collection_id), 1);
+
+		// tokens can be minted and burned while the migration is in progress
+		let new_token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			token_uri,
+		)
+		.unwrap();
+		for token_id in &old_token_ids {
+			assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+				alice,
+				collection_id,
+				*token_id
+			));
+			assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				alice,
+				collection_id,
+				token_id_to_slot(*token_id),
+				alice,
+				TokenUriOf::<Test>::default(),
+			));
+		}
+
+		while TokenIndexMigrationCursor::<Test>::exists() {
+			migrations::v1::index_tokens::<Test>(1);
+		}
+
+		assert_eq!(LaosEvolution::token_count(collection_id), 4);
+		let mut tokens = LaosEvolution::tokens_by_index(collection_id, 0, 10);
+		tokens.sort();
+		let mut expected = old_token_ids;
+		expected.push(new_token_id);
+		expected.sort();
+		assert_eq!(tokens, expected);
+	});
+}
+
+#[test]
+fn expand_base_uri_replaces_every_id() {
+	let token_id = TokenId::from(0xabcd);
+	let id = format!("{:064x}", token_id);
+
+	assert_eq!(
+		expand_base_uri(b"https://api.game/items/{id}", token_id),
+		format!("https://api.game/items/{id}").into_bytes()
+	);
+	assert_eq!(
+		expand_base_uri(b"{id}/{id}.json", token_id),
+		format!("{id}/{id}.json").into_bytes()
+	);
+	assert_eq!(expand_base_uri(b"ipfs://{ID}{id", token_id), b"ipfs://{ID}{id".to_vec());
+}
+
+#[test]
+fn set_base_uri_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let base_uri: TokenUriOf<Test> =
+			b"https://api.game/items/{id}".to_vec().try_into().unwrap();
+
+		assert_ok!(LaosEvolution::set_base_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			base_uri.clone()
+		));
+		assert_eq!(LaosEvolution::base_uri(collection_id), Some(base_uri.clone()));
+		System::assert_last_event(Event::BaseURISet { collection_id, base_uri }.into());
+
+		// removing the base URI
+		assert_ok!(LaosEvolution::set_base_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			TokenUriOf::<Test>::default()
+		));
+		assert_eq!(LaosEvolution::base_uri(collection_id), None);
+	});
+}
+
+#[test]
+fn token_uri_resolves_through_base_uri() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let mint = |slot: u128, token_uri: &[u8]| {
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				alice,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				alice,
+				token_uri.to_vec().try_into().unwrap(),
+			)
+			.unwrap()
+		};
+		let templated = mint(0, b"");
+		let explicit = mint(1, b"ipfs://explicit");
+
+		// without base URI, tokens minted with an empty URI keep it
+		assert_eq!(LaosEvolution::token_uri(collection_id, templated), Some(Default::default()));
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionBaseUri<_, _>>::set_base_uri(
+			alice,
+			collection_id,
+			b"https://api.game/items/{id}".to_vec().try_into().unwrap()
+		));
+		let expected = format!("https://api.game/items/{:064x}", templated).into_bytes();
+		assert_eq!(
+			LaosEvolution::token_uri(collection_id, templated).map(|uri| uri.to_vec()),
+			Some(expected.clone())
+		);
+		assert_eq!(
+			LaosEvolution::tokens_of_collection(collection_id, None, 10)
+				.into_iter()
+				.find(|(token_id, _)| *token_id == templated)
+				.map(|(_, token_uri)| token_uri.to_vec()),
+			Some(expected)
+		);
+		assert_eq!(
+			LaosEvolution::token_uri(collection_id, explicit).map(|uri| uri.to_vec()),
+			Some(b"ipfs://explicit".to_vec())
+		);
+
+		// evolving the token stores an explicit token URI
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+			alice,
+			collection_id,
+			templated,
+			b"ipfs://evolved".to_vec().try_into().unwrap()
+		));
+		assert_eq!(
+			LaosEvolution::token_uri(collection_id, templated).map(|uri| uri.to_vec()),
+			Some(b"ipfs://evolved".to_vec())
+		);
+	});
+}
+
+#[test]
+fn non_owner_cannot_set_base_uri() {
+	new_test_ext().execute_with(|| {
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::set_base_uri(
+				RuntimeOrigin::signed(AccountId::from_str(BOB).unwrap()),
+				collection_id,
+				TokenUriOf::<Test>::default()
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_base_uri_too_long_once_expanded_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		// every `{id}` grows by 60 bytes once expanded
+		let base_uri = b"{id}".repeat(MaxTokenUriLength::get() as usize / 60 + 1);
+
+		assert_noop!(
+			LaosEvolution::set_base_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				base_uri.try_into().unwrap()
+			),
+			Error::<Test>::BaseUriTooLong
+		);
+	});
+}
+
+#[test]
+fn set_base_uri_of_frozen_collection_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		assert_ok!(LaosEvolution::freeze_collection(RuntimeOrigin::signed(alice), collection_id));
+
+		assert_noop!(
+			LaosEvolution::set_base_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				b"https://api.game/items/{id}".to_vec().try_into().unwrap()
+			),
+			Error::<Test>::CollectionFrozen
+		);
+	});
+}
+
+#[test]
+fn freeze_token_keeps_the_token_uri_resolved_through_base_uri() {
+	new_test_ext().execute_with(|| {
+		DepositPerByte::set(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		assert_ok!(<LaosEvolution as EvolutionCollectionBaseUri<_, _>>::set_base_uri(
+			alice,
+			collection_id,
+			b"https://api.game/items/{id}".to_vec().try_into().unwrap()
+		));
+		let expected = format!("https://api.game/items/{:064x}", token_id).into_bytes();
+
+		assert_ok!(LaosEvolution::freeze_token(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id
+		));
+		assert_eq!(TokenURI::<Test>::get(collection_id, token_id).unwrap().to_vec(), expected);
+		assert_eq!(Balances::reserved_balance(alice), expected.len() as u64);
+
+		// changing the base URI does not change the frozen token
+		assert_ok!(<LaosEvolution as EvolutionCollectionBaseUri<_, _>>::set_base_uri(
+			alice,
+			collection_id,
+			b"https://other.game/{id}".to_vec().try_into().unwrap()
+		));
+		assert_eq!(
+			LaosEvolution::token_uri(collection_id, token_id).map(|uri| uri.to_vec()),
+			Some(expected)
+		);
+	});
+}
+
+#[test]
+fn burn_removes_the_token_uri_history() {
+	new_test_ext().execute_with(|| {
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let collection_id = create_collection(ALICE);
+		let token_uri = |i: u8| -> TokenUriOf<Test> { vec![i].try_into().unwrap() };
+		let mint = || {
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				alice,
+				collection_id,
+				Slot::try_from(0).unwrap(),
+				alice,
+				token_uri(0),
+			)
+			.unwrap()
+		};
+		let token_id = mint();
+		for i in 1..=2 {
+			assert_ok!(LaosEvolution::evolve_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				token_uri(i)
+			));
+		}
+		assert_eq!(Balances::reserved_balance(alice), 6);
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			token_id
+		));
+		assert_eq!(Balances::reserved_balance(alice), 0);
+		assert_eq!(LaosEvolution::token_uri_version(collection_id, token_id), 0);
+		assert_eq!(TokenURIHistory::<Test>::iter_prefix((collection_id, token_id)).count(), 0);
+		for version in 0..=2 {
+			assert_eq!(
+				<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+					collection_id,
+					token_id,
+					version
+				),
+				None
+			);
+		}
+
+		// a token minted again in the same slot starts a new history
+		assert_eq!(mint(), token_id);
+		assert_eq!(LaosEvolution::token_uri_version(collection_id, token_id), 0);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+				collection_id,
+				token_id,
+				0
+			),
+			Some(token_uri(0))
+		);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+				collection_id,
+				token_id,
+				1
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn token_uri_at_current_version_resolves_through_base_uri() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		assert_ok!(<LaosEvolution as EvolutionCollectionBaseUri<_, _>>::set_base_uri(
+			alice,
+			collection_id,
+			b"https://api.game/items/{id}".to_vec().try_into().unwrap()
+		));
+
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+				collection_id,
+				token_id,
+				0
+			),
+			LaosEvolution::token_uri(collection_id, token_id)
+		);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+				collection_id,
+				token_id,
+				0
+			)
+			.map(|uri| uri.to_vec()),
+			Some(format!("https://api.game/items/{:064x}", token_id).into_bytes())
+		);
+	});
+}
+
+#[test]
+fn mint_next_allocates_increasing_slots_per_owner() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let mint_next = |to: AccountId| {
+			<LaosEvolution as EvolutionCollectionMintNext<_, _>>::mint_next(
+				alice,
+				collection_id,
+				to,
+				TokenUriOf::<Test>::default(),
+			)
+			.unwrap()
+		};
+
+		let slot = |i: u128| Slot::try_from(i).unwrap();
+		assert_eq!(mint_next(alice), (slot(0), slot_and_owner_to_token_id(slot(0), alice)));
+		assert_eq!(mint_next(alice), (slot(1), slot_and_owner_to_token_id(slot(1), alice)));
+		// every owner has its own slots
+		assert_eq!(mint_next(bob), (slot(0), slot_and_owner_to_token_id(slot(0), bob)));
+		assert_eq!(LaosEvolution::next_slot(collection_id, alice), slot(2));
+	});
+}
+
+#[test]
+fn mint_next_mints_past_slots_minted_explicitly() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri = TokenUriOf::<Test>::default();
+		for slot in [0, 1, 3] {
+			assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				alice,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				alice,
+				token_uri.clone(),
+			));
+		}
+
+		assert_ok!(LaosEvolution::mint_next(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			alice,
+			token_uri.clone()
+		));
+		let slot = Slot::try_from(4).unwrap();
+		System::assert_last_event(
+			Event::MintedWithExternalURI {
+				collection_id,
+				slot,
+				to: alice,
+				token_id: slot_and_owner_to_token_id(slot, alice),
+				token_uri: token_uri.clone(),
+			}
+			.into(),
+		);
+
+		// minting explicitly below the next slot does not move it back
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(2).unwrap(),
+			alice,
+			token_uri,
+		));
+		let (slot, _) = <LaosEvolution as EvolutionCollectionMintNext<_, _>>::mint_next(
+			alice,
+			collection_id,
+			alice,
+			TokenUriOf::<Test>::default(),
+		)
+		.unwrap();
+		assert_eq!(slot, Slot::try_from(5).unwrap());
+	});
+}
+
+#[test]
+fn mint_next_fails_once_the_maximum_slot_is_minted() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::MAX_SLOT,
+			alice,
+			TokenUriOf::<Test>::default(),
+		));
+		assert_eq!(LaosEvolution::next_slot(collection_id, alice), Slot::MAX_SLOT);
+
+		assert_noop!(
+			LaosEvolution::mint_next(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				alice,
+				TokenUriOf::<Test>::default()
+			),
+			Error::<Test>::SlotsExhausted
+		);
+	});
+}
+
+#[test]
+fn mint_next_requires_permission() {
+	new_test_ext().execute_with(|| {
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::mint_next(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				bob,
+				TokenUriOf::<Test>::default()
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn mint_next_is_not_blocked_by_slots_minted_explicitly() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let mint = |slot: u128, to: AccountId| {
+			<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				alice,
+				collection_id,
+				Slot::try_from(slot).unwrap(),
+				to,
+				TokenUriOf::<Test>::default(),
+			)
+		};
+		let mint_next = |to: AccountId| {
+			<LaosEvolution as EvolutionCollectionMintNext<_, _>>::mint_next(
+				alice,
+				collection_id,
+				to,
+				TokenUriOf::<Test>::default(),
+			)
+			.unwrap()
+			.0
+		};
+
+		for slot in 0..16 {
+			assert_ok!(mint(slot, alice));
+		}
+		assert_eq!(mint_next(alice), Slot::try_from(16).unwrap());
+
+		// slots minted out of order are skipped as well
+		for slot in 1..=40 {
+			assert_ok!(mint(slot, bob));
+		}
+		assert_ok!(mint(0, bob));
+		assert_eq!(mint_next(bob), Slot::try_from(41).unwrap());
+	});
+}
+
+#[test]
+fn deposits_follow_the_token_uris_of_the_collection() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CollectionDeposit::set(10);
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+
+		let collection_id = create_collection(ALICE);
+		System::assert_has_event(
+			Event::DepositReserved { collection_id, who: alice, amount: 10 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(alice), 10);
+
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			b"12345".to_vec().try_into().unwrap(),
+		)
+		.unwrap();
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 10);
+		assert_eq!(LaosEvolution::collection_deposit(collection_id), 10);
+		assert_eq!(Balances::reserved_balance(alice), 20);
+
+		// the previous token URI is kept in the history, so its deposit stays reserved
+		assert_ok!(LaosEvolution::evolve_with_external_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"1234567".to_vec().try_into().unwrap(),
+		));
+		System::assert_has_event(
+			Event::DepositReserved { collection_id, who: alice, amount: 14 }.into(),
+		);
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 24);
+		assert_eq!(Balances::reserved_balance(alice), 34);
+
+		assert_ok!(LaosEvolution::evolve_with_external_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"1".to_vec().try_into().unwrap(),
+		));
+		System::assert_has_event(
+			Event::DepositReserved { collection_id, who: alice, amount: 2 }.into(),
+		);
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 26);
+		assert_eq!(Balances::reserved_balance(alice), 36);
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			token_id
+		));
+		System::assert_has_event(
+			Event::DepositReleased { collection_id, who: alice, amount: 26 }.into(),
+		);
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 0);
+		assert_eq!(LaosEvolution::collection_deposit(collection_id), 0);
+		assert_eq!(Balances::reserved_balance(alice), 10);
+	});
+}
+
+#[test]
+fn pruning_the_history_releases_the_deposit_of_the_pruned_version() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let collection_id = create_collection(ALICE);
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			b"1".to_vec().try_into().unwrap(),
+		)
+		.unwrap();
+		for token_uri in [b"12".to_vec(), b"123".to_vec(), b"1234".to_vec()] {
+			assert_ok!(LaosEvolution::evolve_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				token_uri.try_into().unwrap(),
+			));
+		}
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 20);
+		assert_eq!(Balances::reserved_balance(alice), 20);
+
+		// `MaxUriHistory` versions are kept, so the first one is pruned
+		assert_ok!(LaosEvolution::evolve_with_external_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			TokenUriOf::<Test>::default(),
+		));
+		System::assert_has_event(
+			Event::DepositReleased { collection_id, who: alice, amount: 2 }.into(),
+		);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionHistory<_>>::token_uri_at(
+				collection_id,
+				token_id,
+				0
+			),
+			None
+		);
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 18);
+		assert_eq!(LaosEvolution::collection_deposit(collection_id), 18);
+		assert_eq!(Balances::reserved_balance(alice), 18);
+	});
+}
+
+#[test]
+fn pruning_the_history_of_a_token_without_recorded_deposit_releases_at_most_its_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let collection_id = create_collection(ALICE);
+		// a token minted before the deposits were introduced has no deposit recorded
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			b"12345".to_vec().try_into().unwrap(),
+		)
+		.unwrap();
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 0);
+		DepositPerByte::set(2);
+		// funds reserved by other pallets must never be released
+		assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&alice, 100));
+
+		// `MaxUriHistory` versions are kept, so the last evolution prunes the first token URI
+		for _ in 0..=MaxUriHistory::get() {
+			assert_ok!(LaosEvolution::evolve_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				b"1".to_vec().try_into().unwrap(),
+			));
+			assert_eq!(
+				Balances::reserved_balance(alice),
+				100 + LaosEvolution::token_deposit(collection_id, token_id)
+			);
+		}
+		System::assert_has_event(
+			Event::DepositReleased { collection_id, who: alice, amount: 4 }.into(),
+		);
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 2);
+		assert_eq!(LaosEvolution::collection_deposit(collection_id), 2);
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			token_id
+		));
+		assert_eq!(Balances::reserved_balance(alice), 100);
+	});
+}
+
+#[test]
+fn evolving_a_token_without_recorded_deposit_and_without_history_releases_nothing() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxUriHistory::set(0);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let collection_id = create_collection(ALICE);
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			b"12345".to_vec().try_into().unwrap(),
+		)
+		.unwrap();
+		DepositPerByte::set(2);
+		assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&alice, 100));
+
+		assert_ok!(LaosEvolution::evolve_with_external_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"1".to_vec().try_into().unwrap(),
+		));
+		System::assert_has_event(
+			Event::DepositReserved { collection_id, who: alice, amount: 2 }.into(),
+		);
+		assert_eq!(LaosEvolution::token_deposit(collection_id, token_id), 2);
+		assert_eq!(Balances::reserved_balance(alice), 102);
+	});
+}
+
+#[test]
+fn collection_deposit_is_reserved_from_the_caller() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CollectionDeposit::set(10);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), bob, 1_000));
+
+		// alice has no balance, but she does not pay for the collection created by bob
+		assert_ok!(LaosEvolution::create_collection(RuntimeOrigin::signed(bob), alice));
+
+		System::assert_has_event(
+			Event::DepositReserved { collection_id: 0, who: bob, amount: 10 }.into(),
+		);
+		assert_eq!(LaosEvolution::collection_owner(0), Some(alice));
+		assert_eq!(LaosEvolution::creation_deposit(0), Some((bob, 10)));
+		assert_eq!(Balances::reserved_balance(alice), 0);
+		assert_eq!(Balances::reserved_balance(bob), 10);
+	});
+}
+
+#[test]
+fn renouncing_ownership_releases_the_collection_deposit_to_the_creator() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CollectionDeposit::set(10);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), bob, 1_000));
+		assert_ok!(LaosEvolution::create_collection(RuntimeOrigin::signed(bob), alice));
+
+		assert_ok!(LaosEvolution::renounce_ownership(RuntimeOrigin::signed(alice), 0));
+
+		System::assert_has_event(
+			Event::DepositReleased { collection_id: 0, who: bob, amount: 10 }.into(),
+		);
+		assert_eq!(LaosEvolution::creation_deposit(0), None);
+		assert_eq!(Balances::reserved_balance(bob), 0);
+	});
+}
+
+#[test]
+fn accepting_ownership_moves_the_deposit_of_the_tokens_to_the_new_owner() {
+	new_test_ext().execute_with(|| {
+		CollectionDeposit::set(10);
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), bob, 1_000));
+		let collection_id = create_collection(ALICE);
+		assert_ok!(LaosEvolution::mint_with_external_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			b"123".to_vec().try_into().unwrap(),
+		));
+
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob
+		));
+		assert_ok!(LaosEvolution::accept_ownership(RuntimeOrigin::signed(bob), collection_id));
+
+		// the collection deposit stays with its creator
+		assert_eq!(Balances::reserved_balance(alice), 10);
+		assert_eq!(Balances::reserved_balance(bob), 6);
+		assert_eq!(LaosEvolution::collection_deposit(collection_id), 6);
+	});
+}
+
+#[test]
+fn new_owner_without_balance_for_the_deposit_cannot_accept_ownership() {
+	new_test_ext().execute_with(|| {
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let collection_id = create_collection(ALICE);
+		assert_ok!(LaosEvolution::mint_with_external_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			b"123".to_vec().try_into().unwrap(),
+		));
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob
+		));
+
+		assert_noop!(
+			LaosEvolution::accept_ownership(RuntimeOrigin::signed(bob), collection_id),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn create_collection_without_balance_for_the_deposit_fails() {
+	new_test_ext().execute_with(|| {
+		CollectionDeposit::set(10);
+		let alice = AccountId::from_str(ALICE).unwrap();
+
+		assert_noop!(
+			LaosEvolution::create_collection(RuntimeOrigin::signed(alice), alice),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn mint_without_balance_for_the_deposit_fails() {
+	new_test_ext().execute_with(|| {
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::mint_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				Slot::try_from(0).unwrap(),
+				alice,
+				b"a".to_vec().try_into().unwrap(),
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn hooks_are_called_on_mint_evolve_and_ownership_transfer() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_uri: TokenUriOf<Test> = b"ciao".to_vec().try_into().unwrap();
+
+		let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			bob,
+			TokenUriOf::<Test>::default(),
+		)
+		.unwrap();
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::evolve_with_external_uri(
+			alice,
+			collection_id,
+			token_id,
+			token_uri.clone(),
+		));
+		assert_ok!(LaosEvolution::transfer_ownership(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			bob
+		));
+		assert_ok!(LaosEvolution::accept_ownership(RuntimeOrigin::signed(bob), collection_id));
+
+		assert_eq!(
+			HookCalls::get(),
+			vec![
+				HookCall::Mint(collection_id, token_id, bob),
+				HookCall::Evolve(collection_id, token_id, token_uri),
+				HookCall::OwnershipTransferred(collection_id, alice, Some(bob)),
+			]
+		);
+	});
+}
+
+#[test]
+fn ownership_hook_is_called_on_renounce() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_ok!(LaosEvolution::renounce_ownership(RuntimeOrigin::signed(alice), collection_id));
+
+		assert_eq!(
+			HookCalls::get(),
+			vec![HookCall::OwnershipTransferred(collection_id, alice, None)]
+		);
+	});
+}
+
+#[test]
+fn upper_bound_of_weight_of_hooks_is_part_of_the_weight_of_calls() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let token_uri = TokenUriOf::<Test>::default();
+
+		assert_eq!(
+			Call::<Test>::mint_with_external_uri {
+				collection_id: 0,
+				slot: Slot::try_from(0).unwrap(),
+				to: alice,
+				token_uri: token_uri.clone(),
+			}
+			.get_dispatch_info()
+			.weight,
+			<() as WeightInfo>::mint_with_external_uri(0) + HOOK_WEIGHT
+		);
+		assert_eq!(
+			Call::<Test>::evolve_with_external_uri {
+				collection_id: 0,
+				token_id: TokenId::zero(),
+				token_uri,
+			}
+			.get_dispatch_info()
+			.weight,
+			<() as WeightInfo>::evolve_with_external_uri(0) + HOOK_WEIGHT
+		);
+		assert_eq!(
+			Call::<Test>::renounce_ownership { collection_id: 0 }.get_dispatch_info().weight,
+			<() as WeightInfo>::renounce_ownership() + HOOK_WEIGHT
+		);
+	});
+}
+
+#[test]
+fn weight_of_hooks_within_their_upper_bound_is_not_registered_again() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		let block_weight = System::block_weight().total();
+
+		assert_ok!(<LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+			alice,
+			collection_id,
+			Slot::try_from(0).unwrap(),
+			alice,
+			TokenUriOf::<Test>::default(),
+		));
+
+		assert_eq!(System::block_weight().total(), block_weight);
+	});
+}
+
+#[test]
+fn hooks_are_not_called_when_the_call_fails() {
+	new_test_ext().execute_with(|| {
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::mint_with_external_uri(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				Slot::try_from(0).unwrap(),
+				bob,
+				TokenUriOf::<Test>::default(),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert!(HookCalls::get().is_empty());
+	});
+}
+
+#[test]
+fn scheduled_evolution_is_applied_at_its_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		let token_uri: TokenUriOf<Test> = b"season 2".to_vec().try_into().unwrap();
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			token_uri.clone(),
+			3
+		));
+		System::assert_last_event(
+			Event::EvolutionScheduled {
+				collection_id,
+				token_id,
+				token_uri: token_uri.clone(),
+				block: 3,
+			}
+			.into(),
+		);
+		assert_eq!(
+			LaosEvolution::scheduled_evolution(collection_id, token_id),
+			Some((3, token_uri.clone()))
+		);
+
+		LaosEvolution::on_initialize(2);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(Default::default()));
+
+		LaosEvolution::on_initialize(3);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri.clone()));
+		assert_eq!(LaosEvolution::scheduled_evolution(collection_id, token_id), None);
+		assert!(EvolutionAgenda::<Test>::get(3).is_empty());
+		System::assert_has_event(
+			Event::EvolvedWithExternalURI { collection_id, token_id, token_uri }.into(),
+		);
+	});
+}
+
+#[test]
+fn on_initialize_returns_the_weight_of_the_scheduled_evolutions() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			TokenUriOf::<Test>::default(),
+			1
+		));
+
+		assert_eq!(
+			LaosEvolution::on_initialize(1),
+			<() as WeightInfo>::apply_scheduled_evolutions(1) + HOOK_WEIGHT
+		);
+		assert_eq!(
+			LaosEvolution::on_initialize(2),
+			<() as WeightInfo>::apply_scheduled_evolutions(0)
+		);
+	});
+}
+
+#[test]
+fn schedule_evolution_in_the_past_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_noop!(
+			LaosEvolution::schedule_evolution(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				TokenUriOf::<Test>::default(),
+				5
+			),
+			Error::<Test>::ScheduledInThePast
+		);
+	});
+}
+
+#[test]
+fn schedule_evolution_of_non_owner_fails() {
+	new_test_ext().execute_with(|| {
+		let bob = AccountId::from_str(BOB).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_noop!(
+			LaosEvolution::schedule_evolution(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				token_id,
+				TokenUriOf::<Test>::default(),
+				5
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn schedule_evolution_of_unexistent_token_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::schedule_evolution(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				TokenId::from(1),
+				TokenUriOf::<Test>::default(),
+				5
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn schedule_evolution_is_bounded_per_block() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		for slot in 0..=MaxScheduledEvolutionsPerBlock::get() {
+			let token_id = <LaosEvolution as EvolutionCollection<_, _>>::mint_with_external_uri(
+				alice,
+				collection_id,
+				Slot::try_from(slot as u128).unwrap(),
+				alice,
+				TokenUriOf::<Test>::default(),
+			)
+			.unwrap();
+			let result = LaosEvolution::schedule_evolution(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				TokenUriOf::<Test>::default(),
+				5,
+			);
+			if slot < MaxScheduledEvolutionsPerBlock::get() {
+				assert_ok!(result);
+			} else {
+				assert_eq!(result, Err(Error::<Test>::TooManyScheduledEvolutions.into()));
+			}
+		}
+	});
+}
+
+#[test]
+fn rescheduling_an_evolution_replaces_the_previous_one() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		let token_uri: TokenUriOf<Test> = b"season 2".to_vec().try_into().unwrap();
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			TokenUriOf::<Test>::default(),
+			5
+		));
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			token_uri.clone(),
+			7
+		));
+
+		assert!(EvolutionAgenda::<Test>::get(5).is_empty());
+		assert_eq!(EvolutionAgenda::<Test>::get(7).to_vec(), vec![(collection_id, token_id)]);
+		assert_eq!(
+			LaosEvolution::scheduled_evolution(collection_id, token_id),
+			Some((7, token_uri))
+		);
+	});
+}
+
+#[test]
+fn cancel_scheduled_evolution_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		let token_uri: TokenUriOf<Test> = b"season 2".to_vec().try_into().unwrap();
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			token_uri,
+			5
+		));
+		assert_ok!(LaosEvolution::cancel_scheduled_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id
+		));
+		System::assert_last_event(
+			Event::ScheduledEvolutionCanceled { collection_id, token_id }.into(),
+		);
+		assert_eq!(LaosEvolution::scheduled_evolution(collection_id, token_id), None);
+		assert!(EvolutionAgenda::<Test>::get(5).is_empty());
+
+		LaosEvolution::on_initialize(5);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(Default::default()));
+	});
+}
+
+#[test]
+fn cancel_unscheduled_evolution_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_noop!(
+			LaosEvolution::cancel_scheduled_evolution(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id
+			),
+			Error::<Test>::NoScheduledEvolution
+		);
+	});
+}
+
+#[test]
+fn scheduled_evolution_of_frozen_token_fails_with_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"season 2".to_vec().try_into().unwrap(),
+			5
+		));
+		assert_ok!(<LaosEvolution as EvolutionCollectionFreezing<_>>::freeze_token(
+			alice,
+			collection_id,
+			token_id
+		));
+
+		LaosEvolution::on_initialize(5);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(Default::default()));
+		assert_eq!(LaosEvolution::scheduled_evolution(collection_id, token_id), None);
+		System::assert_last_event(
+			Event::ScheduledEvolutionFailed {
+				collection_id,
+				token_id,
+				error: Error::<Test>::TokenFrozen.into(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn burn_removes_scheduled_evolution() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			TokenUriOf::<Test>::default(),
+			5
+		));
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			token_id
+		));
+
+		assert_eq!(LaosEvolution::scheduled_evolution(collection_id, token_id), None);
+		assert!(EvolutionAgenda::<Test>::get(5).is_empty());
+		LaosEvolution::on_initialize(5);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), None);
+	});
+}
+
+#[test]
+fn evolver_can_schedule_and_cancel_evolutions() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		assert_ok!(LaosEvolution::grant_role(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			Role::Evolver,
+			bob
+		));
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(bob),
+			collection_id,
+			token_id,
+			TokenUriOf::<Test>::default(),
+			5
+		));
+		assert_ok!(LaosEvolution::cancel_scheduled_evolution(
+			RuntimeOrigin::signed(bob),
+			collection_id,
+			token_id
+		));
+		assert_eq!(LaosEvolution::scheduled_evolution(collection_id, token_id), None);
+	});
+}
+
+#[test]
+fn scheduled_evolution_reserves_a_deposit_until_it_is_applied() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), bob, 1_000));
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		assert_ok!(LaosEvolution::grant_role(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			Role::Evolver,
+			bob
+		));
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(bob),
+			collection_id,
+			token_id,
+			b"season 2".to_vec().try_into().unwrap(),
+			3
+		));
+		assert_eq!(Balances::reserved_balance(bob), 16);
+		assert_eq!(
+			LaosEvolution::scheduled_evolution_deposit(collection_id, token_id),
+			Some((bob, 16))
+		);
+		System::assert_has_event(
+			Event::DepositReserved { collection_id, who: bob, amount: 16 }.into(),
+		);
+
+		// the deposit of the new token URI is reserved from the owner when it is applied
+		LaosEvolution::on_initialize(3);
+		assert_eq!(Balances::reserved_balance(bob), 0);
+		assert_eq!(Balances::reserved_balance(alice), 16);
+		assert_eq!(LaosEvolution::scheduled_evolution_deposit(collection_id, token_id), None);
+		System::assert_has_event(
+			Event::DepositReleased { collection_id, who: bob, amount: 16 }.into(),
+		);
+	});
+}
+
+#[test]
+fn schedule_evolution_without_balance_for_the_deposit_fails() {
+	new_test_ext().execute_with(|| {
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_noop!(
+			LaosEvolution::schedule_evolution(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				b"season 2".to_vec().try_into().unwrap(),
+				3
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn canceling_rescheduling_or_burning_releases_the_deposit_of_the_scheduled_evolution() {
+	new_test_ext().execute_with(|| {
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"season 2".to_vec().try_into().unwrap(),
+			3
+		));
+		assert_ok!(LaosEvolution::cancel_scheduled_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id
+		));
+		assert_eq!(Balances::reserved_balance(alice), 0);
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"season 2".to_vec().try_into().unwrap(),
+			3
+		));
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"s3".to_vec().try_into().unwrap(),
+			4
+		));
+		assert_eq!(Balances::reserved_balance(alice), 4);
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			token_id
+		));
+		assert_eq!(Balances::reserved_balance(alice), 0);
+		assert_eq!(LaosEvolution::scheduled_evolution_deposit(collection_id, token_id), None);
+	});
+}
+
+#[test]
+fn scheduled_evolution_of_renounced_collection_fails_with_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DepositPerByte::set(2);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"season 2".to_vec().try_into().unwrap(),
+			3
+		));
+		assert_ok!(LaosEvolution::renounce_ownership(RuntimeOrigin::signed(alice), collection_id));
+
+		LaosEvolution::on_initialize(3);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(Default::default()));
+		assert_eq!(Balances::reserved_balance(alice), 0);
+		System::assert_last_event(
+			Event::ScheduledEvolutionFailed {
+				collection_id,
+				token_id,
+				error: Error::<Test>::OwnershipRenounced.into(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_attribute_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		let key = H256::repeat_byte(1);
+		let value: AttributeValueOf<Test> = b"legendary".to_vec().try_into().unwrap();
+
+		assert_ok!(LaosEvolution::set_attribute(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			key,
+			value.clone()
+		));
+		System::assert_last_event(
+			Event::AttributeSet { collection_id, token_id, key, value: value.clone() }.into(),
+		);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionAttributes<_, _>>::attribute(
+				collection_id,
+				token_id,
+				key
+			),
+			Some(value)
+		);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionAttributes<_, _>>::attribute(
+				collection_id,
+				token_id,
+				H256::repeat_byte(2)
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn set_attribute_of_non_owner_fails() {
+	new_test_ext().execute_with(|| {
+		let bob = AccountId::from_str(BOB).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		assert_noop!(
+			LaosEvolution::set_attribute(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				token_id,
+				H256::repeat_byte(1),
+				AttributeValueOf::<Test>::default()
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_attribute_of_unexistent_token_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::set_attribute(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				TokenId::from(1),
+				H256::repeat_byte(1),
+				AttributeValueOf::<Test>::default()
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn set_attribute_of_frozen_token_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		assert_ok!(<LaosEvolution as EvolutionCollectionFreezing<_>>::freeze_token(
+			alice,
+			collection_id,
+			token_id
+		));
+
+		assert_noop!(
+			LaosEvolution::set_attribute(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				H256::repeat_byte(1),
+				AttributeValueOf::<Test>::default()
+			),
+			Error::<Test>::TokenFrozen
+		);
+	});
+}
+
+#[test]
+fn set_attribute_is_bounded_per_token() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		for i in 0..MaxAttributesPerToken::get() {
+			assert_ok!(LaosEvolution::set_attribute(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				H256::from_low_u64_be(i.into()),
+				AttributeValueOf::<Test>::default()
+			));
+		}
+		assert_noop!(
+			LaosEvolution::set_attribute(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				H256::repeat_byte(0xff),
+				AttributeValueOf::<Test>::default()
+			),
+			Error::<Test>::TooManyAttributes
+		);
+		// existing attributes can still be overwritten
+		assert_ok!(LaosEvolution::set_attribute(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			H256::from_low_u64_be(0),
+			b"1".to_vec().try_into().unwrap()
+		));
+	});
+}
+
+#[test]
+fn remove_attribute_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		let key = H256::repeat_byte(1);
+
+		assert_ok!(LaosEvolution::set_attribute(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			key,
+			b"legendary".to_vec().try_into().unwrap()
+		));
+		assert_ok!(LaosEvolution::remove_attribute(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			key
+		));
+		System::assert_last_event(Event::AttributeRemoved { collection_id, token_id, key }.into());
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionAttributes<_, _>>::attribute(
+				collection_id,
+				token_id,
+				key
+			),
+			None
+		);
+		assert_eq!(AttributeCount::<Test>::get(collection_id, token_id), 0);
+
+		assert_noop!(
+			LaosEvolution::remove_attribute(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				key
+			),
+			Error::<Test>::AttributeDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn deposits_follow_the_attributes_of_tokens() {
+	new_test_ext().execute_with(|| {
+		DepositPerByte::set(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+		let key = H256::repeat_byte(1);
+
+		// the deposit covers the key and the value
+		assert_ok!(LaosEvolution::set_attribute(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			key,
+			b"12345".to_vec().try_into().unwrap()
+		));
+		assert_eq!(Balances::reserved_balance(alice), 37);
+
+		assert_ok!(LaosEvolution::set_attribute(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			key,
+			b"1".to_vec().try_into().unwrap()
+		));
+		assert_eq!(Balances::reserved_balance(alice), 33);
+
+		assert_ok!(LaosEvolution::remove_attribute(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			key
+		));
+		assert_eq!(Balances::reserved_balance(alice), 0);
+		assert_eq!(LaosEvolution::collection_deposit(collection_id), 0);
+	});
+}
+
+#[test]
+fn burn_removes_attributes_and_releases_their_deposit() {
+	new_test_ext().execute_with(|| {
+		DepositPerByte::set(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), alice, 1_000));
+		let (collection_id, token_id) = create_collection_with_token(ALICE);
+
+		for i in 0..MaxAttributesPerToken::get() {
+			assert_ok!(LaosEvolution::set_attribute(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				H256::from_low_u64_be(i.into()),
+				b"1".to_vec().try_into().unwrap()
+			));
+		}
+		assert_eq!(Balances::reserved_balance(alice), 33 * MaxAttributesPerToken::get() as u64);
+
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			token_id
+		));
+
+		assert_eq!(Balances::reserved_balance(alice), 0);
+		assert_eq!(AttributeCount::<Test>::get(collection_id, token_id), 0);
+		assert_eq!(
+			<LaosEvolution as EvolutionCollectionAttributes<_, _>>::attribute(
+				collection_id,
+				token_id,
+				H256::from_low_u64_be(0)
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn set_evolution_consent_required_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let collection_id = create_collection(ALICE);
+		assert!(!<LaosEvolution as EvolutionCollectionConsent<_>>::evolution_consent_required(
+			collection_id
+		));
+
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			true
+		));
+		System::assert_last_event(
+			Event::EvolutionConsentRequiredSet { collection_id, required: true }.into(),
+		);
+		assert!(<LaosEvolution as EvolutionCollectionConsent<_>>::evolution_consent_required(
+			collection_id
+		));
+
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			false
+		));
+		System::assert_last_event(
+			Event::EvolutionConsentRequiredSet { collection_id, required: false }.into(),
+		);
+		assert!(!<LaosEvolution as EvolutionCollectionConsent<_>>::evolution_consent_required(
+			collection_id
+		));
+	});
+}
+
+#[test]
+fn set_evolution_consent_required_of_non_owner_fails() {
+	new_test_ext().execute_with(|| {
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+
+		assert_noop!(
+			LaosEvolution::set_evolution_consent_required(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				true
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn evolve_without_approval_fails_when_consent_is_required() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(BOB);
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			true
+		));
+
+		assert_noop!(
+			LaosEvolution::evolve_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				b"season 2".to_vec().try_into().unwrap()
+			),
+			Error::<Test>::EvolutionNotApproved
+		);
+	});
+}
+
+#[test]
+fn approved_evolution_is_applied_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(BOB);
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			true
+		));
+		let token_uri: TokenUriOf<Test> = b"season 2".to_vec().try_into().unwrap();
+		let uri_hash = H256(keccak_256(&token_uri));
+
+		assert_ok!(LaosEvolution::approve_evolution(
+			RuntimeOrigin::signed(bob),
+			collection_id,
+			token_id,
+			uri_hash
+		));
+		System::assert_last_event(
+			Event::EvolutionApproved { collection_id, token_id, uri_hash }.into(),
+		);
+
+		// the approval only covers the approved token URI
+		assert_noop!(
+			LaosEvolution::evolve_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				b"season 3".to_vec().try_into().unwrap()
+			),
+			Error::<Test>::EvolutionNotApproved
+		);
+
+		assert_ok!(LaosEvolution::evolve_with_external_uri(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			token_uri.clone()
+		));
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(token_uri.clone()));
+		assert_eq!(EvolutionApproval::<Test>::get(collection_id, token_id), None);
+
+		assert_noop!(
+			LaosEvolution::evolve_with_external_uri(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				token_uri
+			),
+			Error::<Test>::EvolutionNotApproved
+		);
+	});
+}
+
+#[test]
+fn approve_evolution_of_non_initial_owner_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(BOB);
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			true
+		));
+
+		assert_noop!(
+			LaosEvolution::approve_evolution(
+				RuntimeOrigin::signed(alice),
+				collection_id,
+				token_id,
+				H256::repeat_byte(1)
+			),
+			Error::<Test>::NotInitialOwner
+		);
+	});
+}
+
+#[test]
+fn approve_evolution_of_unexistent_token_fails() {
+	new_test_ext().execute_with(|| {
+		let bob = AccountId::from_str(BOB).unwrap();
+		let collection_id = create_collection(ALICE);
+		let token_id = slot_and_owner_to_token_id(Slot::try_from(0).unwrap(), bob);
+
+		assert_noop!(
+			LaosEvolution::approve_evolution(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				token_id,
+				H256::repeat_byte(1)
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn approve_evolution_when_consent_is_not_required_fails() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(BOB);
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			true
+		));
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			false
+		));
+
+		assert_noop!(
+			LaosEvolution::approve_evolution(
+				RuntimeOrigin::signed(bob),
+				collection_id,
+				token_id,
+				H256::repeat_byte(1)
+			),
+			Error::<Test>::EvolutionConsentNotRequired
+		);
+	});
+}
+
+#[test]
+fn scheduled_evolution_without_approval_fails_with_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(BOB);
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			true
+		));
+
+		assert_ok!(LaosEvolution::schedule_evolution(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			token_id,
+			b"season 2".to_vec().try_into().unwrap(),
+			5
+		));
+
+		LaosEvolution::on_initialize(5);
+		assert_eq!(LaosEvolution::token_uri(collection_id, token_id), Some(Default::default()));
+		System::assert_last_event(
+			Event::ScheduledEvolutionFailed {
+				collection_id,
+				token_id,
+				error: Error::<Test>::EvolutionNotApproved.into(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn burn_removes_evolution_approval() {
+	new_test_ext().execute_with(|| {
+		let alice = AccountId::from_str(ALICE).unwrap();
+		let bob = AccountId::from_str(BOB).unwrap();
+		let (collection_id, token_id) = create_collection_with_token(BOB);
+		assert_ok!(LaosEvolution::set_evolution_consent_required(
+			RuntimeOrigin::signed(alice),
+			collection_id,
+			true
+		));
+
+		assert_ok!(LaosEvolution::approve_evolution(
+			RuntimeOrigin::signed(bob),
+			collection_id,
+			token_id,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(<LaosEvolution as EvolutionCollectionBurn<_>>::burn(
+			alice,
+			collection_id,
+			token_id
+		));
+
+		assert_eq!(EvolutionApproval::<Test>::get(collection_id, token_id), None);
+	});
+}
+
+#[test]
+fn token_id_to_initial_owner_works() {
+	let owner = AccountId::from_str("0xe00000000000000000000000000000000000000f").unwrap();
+	let token_id = slot_and_owner_to_token_id(Slot::MAX_SLOT, owner);
+	assert_eq!(token_id_to_initial_owner(token_id), owner);
+}
+
+#[test]
+fn token_id_to_slot_works() {
+	let owner = AccountId::from_str("0xe00000000000000000000000000000000000000f").unwrap();
+	let slot = Slot::try_from(123456789012345678901234567u128).unwrap();
+	let token_id = slot_and_owner_to_token_id(slot, owner);
+	assert_eq!(token_id_to_slot(token_id), slot);
+	assert_eq!(token_id_to_slot(slot_and_owner_to_token_id(Slot::MAX_SLOT, owner)), Slot::MAX_SLOT);
+}
+
 mod collection_id_conversion {
 	use core::str::FromStr;
 
@@ -16,15 +16,24 @@
 
 //! Traits for this pallet
 
-use crate::types::{CollectionId, Slot, TokenId};
-use frame_support::pallet_prelude::DispatchResult;
-use sp_core::H160;
+use crate::types::{BasisPoints, CollectionId, Role, RoyaltyInfo, Slot, TokenId};
+use frame_support::pallet_prelude::{DispatchResult, Weight};
+use sp_core::{H160, H256, U256};
 use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
 
 /// `EvolutionCollectionFactory` trait for managing collections
 pub trait EvolutionCollectionFactory<AccountId> {
-	/// Creates new collection
-	fn create_collection(owner: AccountId) -> Result<CollectionId, DispatchError>;
+	/// Creates new collection owned by `owner`, reserving the collection deposit from `who`
+	fn create_collection(who: AccountId, owner: AccountId) -> Result<CollectionId, DispatchError>;
+
+	/// Creates new collection owned by `owner` that can never mint more than `max_supply` tokens,
+	/// even if some of them are burned
+	fn create_collection_with_max_supply(
+		who: AccountId,
+		owner: AccountId,
+		max_supply: u64,
+	) -> Result<CollectionId, DispatchError>;
 }
 
 /// `EvolutionCollection` trait for managing living assets within a collection
@@ -52,7 +61,9 @@ pub trait EvolutionCollection<AccountId, TokenUri> {
 		token_uri: TokenUri,
 	) -> Result<(), DispatchError>;
 
-	/// Transfer ownership of the collection
+	/// Start the transfer of the ownership of the collection to `to`
+	///
+	/// The ownership only changes once `to` accepts it through `accept_ownership`
 	fn transfer_ownership(
 		from: AccountId,
 		to: AccountId,
@@ -60,6 +71,337 @@ pub trait EvolutionCollection<AccountId, TokenUri> {
 	) -> DispatchResult;
 }
 
+/// Manages the two-step transfer and the renouncement of the ownership of LAOS Evolution
+/// collections
+pub trait EvolutionCollectionOwnership<AccountId> {
+	/// Accept the pending transfer of the ownership of the collection
+	fn accept_ownership(who: AccountId, collection_id: CollectionId) -> DispatchResult;
+
+	/// Cancel the pending transfer of the ownership of the collection
+	fn cancel_ownership_transfer(who: AccountId, collection_id: CollectionId) -> DispatchResult;
+
+	/// Get the pending owner of the collection, if any
+	fn pending_owner(collection_id: CollectionId) -> Option<AccountId>;
+
+	/// Renounce the ownership of the collection, leaving it without owner
+	///
+	/// Tokens of a collection without owner can no longer be minted nor evolved, and its drop is
+	/// removed
+	fn renounce_ownership(who: AccountId, collection_id: CollectionId) -> DispatchResult;
+}
+
+/// Manages the roles granted by the owners of LAOS Evolution collections
+pub trait EvolutionCollectionRoles<AccountId> {
+	/// Grant `role` in a collection to `account`
+	fn grant_role(
+		who: AccountId,
+		collection_id: CollectionId,
+		role: Role,
+		account: AccountId,
+	) -> DispatchResult;
+
+	/// Revoke `role` in a collection from `account`
+	fn revoke_role(
+		who: AccountId,
+		collection_id: CollectionId,
+		role: Role,
+		account: AccountId,
+	) -> DispatchResult;
+
+	/// Whether `account` has been granted `role` in a collection
+	fn has_role(collection_id: CollectionId, role: Role, account: AccountId) -> bool;
+}
+
+/// Burns the tokens of LAOS Evolution collections
+pub trait EvolutionCollectionBurn<AccountId> {
+	/// Burn token, removing its token URI, its history and its attributes
+	///
+	/// Frozen tokens and tokens of frozen collections cannot be burned
+	fn burn(who: AccountId, collection_id: CollectionId, token_id: TokenId) -> DispatchResult;
+}
+
+/// Freezes the token URIs of the tokens of LAOS Evolution collections
+pub trait EvolutionCollectionFreezing<AccountId> {
+	/// Freeze the token URI of a token, so that it can no longer be evolved
+	///
+	/// A token resolved through the base URI keeps its resolved token URI
+	fn freeze_token(
+		who: AccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> DispatchResult;
+
+	/// Freeze the token URIs of all the tokens of a collection
+	fn freeze_collection(who: AccountId, collection_id: CollectionId) -> DispatchResult;
+
+	/// Whether the token URI of a token is frozen, either by itself or through its collection
+	fn is_frozen(collection_id: CollectionId, token_id: TokenId) -> bool;
+}
+
+/// Mints and evolves many tokens of LAOS Evolution collections at once
+pub trait EvolutionCollectionBatch<AccountId, TokenUri> {
+	/// Mint new tokens with external URI
+	///
+	/// `who` must be the owner of the collection or have the minter role, and either all tokens
+	/// are minted or none of them is
+	fn batch_mint_with_external_uri(
+		who: AccountId,
+		collection_id: CollectionId,
+		mints: Vec<(Slot, AccountId, TokenUri)>,
+	) -> Result<Vec<TokenId>, DispatchError>;
+
+	/// Evolve tokens with external URI
+	///
+	/// `who` must be the owner of the collection or have the evolver role, and either all tokens
+	/// are evolved or none of them is
+	fn batch_evolve_with_external_uri(
+		who: AccountId,
+		collection_id: CollectionId,
+		evolutions: Vec<(TokenId, TokenUri)>,
+	) -> DispatchResult;
+}
+
+/// Manages the royalties of the tokens of LAOS Evolution collections
+pub trait EvolutionCollectionRoyalties<AccountId> {
+	/// Set the royalty that applies to every token of a collection without its own royalty
+	fn set_default_royalty(
+		who: AccountId,
+		collection_id: CollectionId,
+		receiver: AccountId,
+		basis_points: BasisPoints,
+	) -> DispatchResult;
+
+	/// Set the royalty of a token, overriding the default royalty of its collection
+	fn set_token_royalty(
+		who: AccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		receiver: AccountId,
+		basis_points: BasisPoints,
+	) -> DispatchResult;
+
+	/// Get the royalty that applies to a token, if any
+	fn royalty_info(
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> Option<RoyaltyInfo<AccountId>>;
+}
+
+/// Keeps the history of the token URIs of the tokens of LAOS Evolution collections
+pub trait EvolutionCollectionHistory<TokenUri> {
+	/// Get the number of times the token URI of a token has been evolved
+	fn token_uri_version(collection_id: CollectionId, token_id: TokenId) -> u32;
+
+	/// Get the token URI of a token at `version`
+	///
+	/// Returns `None` if the token does not exist, or if the version does not exist or has been
+	/// pruned from the history. The current version resolves through the base URI like the token
+	/// URI does
+	fn token_uri_at(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		version: u32,
+	) -> Option<TokenUri>;
+}
+
+/// Mints the tokens of LAOS Evolution collections authorized with nonces that can only be
+/// used once
+pub trait EvolutionCollectionNonces<AccountId, TokenUri> {
+	/// Mint new token with external URI, authorized by the owner of the collection or an account
+	/// with the minter role with `nonce`
+	///
+	/// The nonce must not have been used or invalidated by `signer` yet, and it is marked as used
+	/// so that the authorization cannot be replayed
+	fn mint_with_nonce(
+		signer: AccountId,
+		collection_id: CollectionId,
+		slot: Slot,
+		to: AccountId,
+		token_uri: TokenUri,
+		nonce: U256,
+	) -> Result<TokenId, DispatchError>;
+
+	/// Whether `nonce` of the signed mint authorizations of `signer` has already been used or
+	/// invalidated in the collection
+	fn is_mint_nonce_used(collection_id: CollectionId, signer: AccountId, nonce: U256) -> bool;
+
+	/// Invalidate `nonce` of the signed mint authorizations of `who`, so that the authorizations
+	/// signed with it can no longer be used
+	fn invalidate_mint_nonce(
+		who: AccountId,
+		collection_id: CollectionId,
+		nonce: U256,
+	) -> DispatchResult;
+}
+
+/// Mints the tokens of LAOS Evolution collections in the next free slot of their owners
+pub trait EvolutionCollectionMintNext<AccountId, TokenUri> {
+	/// Mint new token with external URI in the next free slot of `to`, returning the slot and
+	/// the id of the token
+	///
+	/// Slots are allocated in increasing order, right after the highest slot minted to `to`, so
+	/// it fails with `SlotsExhausted` once the maximum slot of `to` has been minted
+	fn mint_next(
+		who: AccountId,
+		collection_id: CollectionId,
+		to: AccountId,
+		token_uri: TokenUri,
+	) -> Result<(Slot, TokenId), DispatchError>;
+}
+
+/// Enumerates and caps the tokens of LAOS Evolution collections
+pub trait EvolutionCollectionSupply {
+	/// Get the number of tokens of a collection
+	fn token_count(collection_id: CollectionId) -> u64;
+
+	/// Get the maximum number of tokens ever minted in a collection, if it is capped
+	fn max_supply(collection_id: CollectionId) -> Option<u64>;
+
+	/// Get the token at `index` of a collection, where indexes go from zero to the token count
+	fn token_by_index(collection_id: CollectionId, index: u64) -> Option<TokenId>;
+}
+
+/// Manages the base URI that the tokens of LAOS Evolution collections without token URI
+/// resolve to
+pub trait EvolutionCollectionBaseUri<AccountId, TokenUri> {
+	/// Set the base URI of a collection, or remove it if `base_uri` is empty
+	///
+	/// Fails if the collection is frozen
+	fn set_base_uri(
+		who: AccountId,
+		collection_id: CollectionId,
+		base_uri: TokenUri,
+	) -> DispatchResult;
+
+	/// Get the base URI of a collection
+	fn base_uri(collection_id: CollectionId) -> Option<TokenUri>;
+}
+
+/// Schedules the evolutions of the tokens of LAOS Evolution collections
+pub trait EvolutionCollectionScheduling<AccountId, TokenUri> {
+	/// Schedule the evolution of a token to `token_uri` at the beginning of `block`, replacing
+	/// any previous scheduled evolution of the token
+	///
+	/// The deposit of `token_uri` is reserved from `who` until the evolution is applied or
+	/// canceled
+	fn schedule_evolution(
+		who: AccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		token_uri: TokenUri,
+		block: u64,
+	) -> DispatchResult;
+
+	/// Cancel the scheduled evolution of a token
+	fn cancel_scheduled_evolution(
+		who: AccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> DispatchResult;
+}
+
+/// Manages the Merkle drops of LAOS Evolution collections
+pub trait EvolutionCollectionDrop<AccountId, TokenUri> {
+	/// Set the Merkle root of the drop of a collection
+	fn set_drop_root(who: AccountId, collection_id: CollectionId, root: H256) -> DispatchResult;
+
+	/// Get the Merkle root of the drop of a collection, if any
+	fn drop_root(collection_id: CollectionId) -> Option<H256>;
+
+	/// Mint the token of a leaf of the drop of a collection, proven by its Merkle `proof`
+	///
+	/// Every leaf can only be claimed once, and only while the collection has an owner
+	fn claim_from_drop(
+		collection_id: CollectionId,
+		proof: Vec<H256>,
+		slot: Slot,
+		to: AccountId,
+		token_uri: TokenUri,
+	) -> Result<TokenId, DispatchError>;
+}
+
+/// Manages the consent of the initial owners of the tokens of LAOS Evolution collections to
+/// their evolutions
+pub trait EvolutionCollectionConsent<AccountId> {
+	/// Set whether evolutions of a collection require the approval of the initial owner of the
+	/// token
+	fn set_evolution_consent_required(
+		who: AccountId,
+		collection_id: CollectionId,
+		required: bool,
+	) -> DispatchResult;
+
+	/// Whether evolutions of a collection require the approval of the initial owner of the token
+	fn evolution_consent_required(collection_id: CollectionId) -> bool;
+
+	/// Approve the next evolution of a token to the token URI whose Keccak hash is `uri_hash`
+	///
+	/// Only the initial owner of the token can approve its evolutions
+	fn approve_evolution(
+		who: AccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		uri_hash: H256,
+	) -> DispatchResult;
+}
+
+/// Manages the collection-level metadata of LAOS Evolution collections
+pub trait EvolutionCollectionMetadata<AccountId, Metadata> {
+	/// Create new collection owned by `owner` with metadata, reserving the collection deposit
+	/// from `who`
+	fn create_collection_with_metadata(
+		who: AccountId,
+		owner: AccountId,
+		metadata: Metadata,
+	) -> Result<CollectionId, DispatchError>;
+
+	/// Create new collection owned by `owner` with metadata that can never mint more than
+	/// `max_supply` tokens, reserving the collection deposit from `who`
+	fn create_collection_with_metadata_and_max_supply(
+		who: AccountId,
+		owner: AccountId,
+		metadata: Metadata,
+		max_supply: u64,
+	) -> Result<CollectionId, DispatchError>;
+
+	/// Set the metadata of a collection
+	fn set_collection_metadata(
+		who: AccountId,
+		collection_id: CollectionId,
+		metadata: Metadata,
+	) -> DispatchResult;
+
+	/// Get the metadata of a collection
+	fn collection_metadata(collection_id: CollectionId) -> Option<Metadata>;
+}
+
+/// Manages the on-chain key/value attributes of the tokens of LAOS Evolution collections
+pub trait EvolutionCollectionAttributes<AccountId, AttributeValue> {
+	/// Set the value of the attribute `key` of a token
+	fn set_attribute(
+		who: AccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		key: H256,
+		value: AttributeValue,
+	) -> DispatchResult;
+
+	/// Remove the attribute `key` of a token
+	fn remove_attribute(
+		who: AccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		key: H256,
+	) -> DispatchResult;
+
+	/// Get the value of the attribute `key` of a token, if any
+	fn attribute(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		key: H256,
+	) -> Option<AttributeValue>;
+}
+
 pub trait OnCreateCollection {
 	fn on_create_collection(address: H160);
 }
@@ -67,3 +409,95 @@ pub trait OnCreateCollection {
 impl OnCreateCollection for () {
 	fn on_create_collection(_address: H160) {}
 }
+
+/// Hook called when a token is minted in a collection
+pub trait OnMint<AccountId> {
+	/// Called once `token_id` has been minted to `to`, returning the weight it consumed
+	fn on_mint(collection_id: CollectionId, token_id: TokenId, to: &AccountId) -> Weight;
+
+	/// Upper bound of the weight consumed by `on_mint`, charged by every call that mints a token
+	fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnMint<AccountId> for Tuple {
+	fn on_mint(collection_id: CollectionId, token_id: TokenId, to: &AccountId) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight = weight.saturating_add(Tuple::on_mint(collection_id, token_id, to));
+		)* );
+		weight
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+		weight
+	}
+}
+
+/// Hook called when a token of a collection is evolved
+pub trait OnEvolve<TokenUri> {
+	/// Called once the token URI of `token_id` has been changed to `token_uri`, returning the
+	/// weight it consumed
+	fn on_evolve(collection_id: CollectionId, token_id: TokenId, token_uri: &TokenUri) -> Weight;
+
+	/// Upper bound of the weight consumed by `on_evolve`, charged by every call that evolves a
+	/// token
+	fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<TokenUri> OnEvolve<TokenUri> for Tuple {
+	fn on_evolve(collection_id: CollectionId, token_id: TokenId, token_uri: &TokenUri) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight = weight.saturating_add(Tuple::on_evolve(collection_id, token_id, token_uri));
+		)* );
+		weight
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+		weight
+	}
+}
+
+/// Hook called when the ownership of a collection is transferred
+pub trait OnOwnershipTransferred<AccountId> {
+	/// Called once the ownership of the collection has moved from `from` to `to`, returning the
+	/// weight it consumed
+	///
+	/// `to` is `None` when `from` has renounced the ownership
+	fn on_ownership_transferred(
+		collection_id: CollectionId,
+		from: &AccountId,
+		to: Option<&AccountId>,
+	) -> Weight;
+
+	/// Upper bound of the weight consumed by `on_ownership_transferred`, charged by every call
+	/// that transfers or renounces the ownership of a collection
+	fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnOwnershipTransferred<AccountId> for Tuple {
+	fn on_ownership_transferred(
+		collection_id: CollectionId,
+		from: &AccountId,
+		to: Option<&AccountId>,
+	) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight = weight.saturating_add(Tuple::on_ownership_transferred(collection_id, from, to));
+		)* );
+		weight
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+		weight
+	}
+}
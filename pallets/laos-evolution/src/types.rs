@@ -15,6 +15,10 @@
 // along with LAOS.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Types used in the pallet
+use frame_support::{
+	traits::Currency, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use precompile_utils::solidity::{
 	codec::{Reader, Writer},
@@ -23,7 +27,7 @@ use precompile_utils::solidity::{
 };
 use scale_info::{prelude::string::String, TypeInfo};
 use serde::{Deserialize, Serialize};
-use sp_core::U256;
+use sp_core::{H256, U256};
 use sp_runtime::{BoundedVec, RuntimeDebug};
 
 /// Collection id type
@@ -32,9 +36,21 @@ pub type CollectionId = u64;
 /// Explicit `AccountId`
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
+/// Balance of the currency used for the storage deposits
+pub type BalanceOf<T> = <<T as crate::Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+
 /// Wrapper around `BoundedVec` for `tokenUri`
 pub type TokenUriOf<T> = BoundedVec<u8, <T as crate::Config>::MaxTokenUriLength>;
 
+/// Wrapper around `BoundedVec` for the name of a collection
+pub type CollectionNameOf<T> = BoundedVec<u8, <T as crate::Config>::MaxCollectionNameLength>;
+
+/// Wrapper around `BoundedVec` for the symbol of a collection
+pub type CollectionSymbolOf<T> = BoundedVec<u8, <T as crate::Config>::MaxCollectionSymbolLength>;
+
+/// Wrapper around `BoundedVec` for the value of a token attribute
+pub type AttributeValueOf<T> = BoundedVec<u8, <T as crate::Config>::MaxAttributeValueLength>;
+
 /// TokenId type
 /// every slot is identified by a unique `asset_id = concat(slot #, owner_address)`
 pub type TokenId = U256;
@@ -98,6 +114,53 @@ impl From<Slot> for u128 {
 	}
 }
 
+/// Role that the owner of a collection can grant to other accounts
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Role {
+	/// Allowed to mint tokens in the collection
+	Minter,
+	/// Allowed to evolve tokens of the collection
+	Evolver,
+}
+
+/// Royalty fee expressed in basis points, where `MAX_ROYALTY_BASIS_POINTS` is 100%
+pub type BasisPoints = u16;
+
+/// Maximum royalty fee, equivalent to the whole sale price
+pub const MAX_ROYALTY_BASIS_POINTS: BasisPoints = 10_000;
+
+/// Royalty information of a collection or token, following EIP-2981
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RoyaltyInfo<AccountId> {
+	/// Account that receives the royalties
+	pub receiver: AccountId,
+	/// Royalty fee in basis points of the sale price
+	pub basis_points: BasisPoints,
+}
+
+/// Metadata of a collection, as exposed by its ERC-721 `name`, `symbol` and `contractURI`
+#[derive(
+	CloneNoBound,
+	DefaultNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	Encode,
+	Decode,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct CollectionMetadata<T: crate::Config> {
+	/// Name of the collection
+	pub name: CollectionNameOf<T>,
+	/// Symbol of the collection
+	pub symbol: CollectionSymbolOf<T>,
+	/// URI pointing to the contract-level metadata of the collection
+	pub contract_uri: TokenUriOf<T>,
+}
+
 impl Codec for Slot {
 	fn read(reader: &mut Reader) -> MayRevert<Self> {
 		let value128 = <u128 as Codec>::read(reader)?;
@@ -119,6 +182,37 @@ impl Codec for Slot {
 	}
 }
 
+/// ERC-165 interface identifier, encoded as a Solidity `bytes4`
+#[derive(Eq, PartialEq, Clone, Copy, RuntimeDebug)]
+pub struct InterfaceId(pub [u8; 4]);
+
+impl Codec for InterfaceId {
+	fn read(reader: &mut Reader) -> MayRevert<Self> {
+		let word = <H256 as Codec>::read(reader)?;
+		// `bytes4` is left-aligned, the remaining bytes of the word must be zero
+		if word[4..].iter().any(|byte| *byte != 0) {
+			return Err(RevertReason::value_is_too_large(Self::signature()).into());
+		}
+		let mut bytes = [0u8; 4];
+		bytes.copy_from_slice(&word[..4]);
+		Ok(InterfaceId(bytes))
+	}
+
+	fn write(writer: &mut Writer, value: Self) {
+		let mut word = H256::zero();
+		word[..4].copy_from_slice(&value.0);
+		<H256 as Codec>::write(writer, word)
+	}
+
+	fn has_static_size() -> bool {
+		<H256 as Codec>::has_static_size()
+	}
+
+	fn signature() -> String {
+		String::from("bytes4")
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -139,6 +233,25 @@ mod test {
 		assert_eq!(result.unwrap_err(), "Value exceeds 96-bit limit");
 	}
 
+	#[test]
+	fn interface_id_codec_roundtrip() {
+		let interface_id = InterfaceId([0x01, 0xff, 0xc9, 0xa7]);
+		let encoded = Writer::new().write(interface_id).build();
+		assert_eq!(
+			hex::encode(&encoded),
+			"01ffc9a700000000000000000000000000000000000000000000000000000000"
+		);
+		let decoded = Reader::new(&encoded).read::<InterfaceId>().unwrap();
+		assert_eq!(decoded, interface_id);
+	}
+
+	#[test]
+	fn interface_id_with_dirty_padding_is_rejected() {
+		let mut encoded = Writer::new().write(InterfaceId([0x01, 0xff, 0xc9, 0xa7])).build();
+		encoded[31] = 1;
+		assert!(Reader::new(&encoded).read::<InterfaceId>().is_err());
+	}
+
 	#[test]
 	fn max_slot() {
 		let max_value: u128 = Slot::MAX_SLOT.into();
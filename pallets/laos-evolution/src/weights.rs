@@ -1,13 +1,12 @@
-
-//! Autogenerated weights for `pallet_laos_evolution`
+//! Weights for `pallet_laos_evolution`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-06-27, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `titan`, CPU: `12th Gen Intel(R) Core(TM) i7-1260P`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! These weights were last generated with the benchmark command below on 2024-06-27 and have
+//! been maintained by hand since, as the pallet grew new calls and storage items. Only the
+//! weights unchanged since then keep the figures reported by the benchmark, the others are
+//! estimates. They must be regenerated with that command before they are relied upon in
+//! production.
 
-// Executed Command:
+// Command:
 // ./target/release/laos
 // benchmark
 // pallet
@@ -43,21 +42,94 @@ pub trait WeightInfo {
 	fn precompile_transfer_ownership() -> Weight;
 	fn precompile_owner() -> Weight;
 	fn precompile_token_uri() -> Weight;
+	fn precompile_supports_interface() -> Weight;
+	fn precompile_initial_owner_of() -> Weight;
+	fn precompile_slot_of() -> Weight;
 	fn create_collection() -> Weight;
 	fn mint_with_external_uri(s: u32, ) -> Weight;
 	fn evolve_with_external_uri(s: u32, ) -> Weight;
 	fn transfer_ownership() -> Weight;
+	fn precompile_burn(a: u32, h: u32, ) -> Weight;
+	fn burn(a: u32, h: u32, ) -> Weight;
+	fn precompile_batch_mint(n: u32, s: u32, ) -> Weight;
+	fn precompile_batch_evolve(n: u32, s: u32, ) -> Weight;
+	fn precompile_freeze_token() -> Weight;
+	fn precompile_freeze_collection() -> Weight;
+	fn precompile_is_frozen() -> Weight;
+	fn freeze_token() -> Weight;
+	fn freeze_collection() -> Weight;
+	fn precompile_grant_role() -> Weight;
+	fn precompile_revoke_role() -> Weight;
+	fn precompile_has_role() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn precompile_accept_ownership() -> Weight;
+	fn precompile_cancel_ownership_transfer() -> Weight;
+	fn precompile_pending_owner() -> Weight;
+	fn accept_ownership() -> Weight;
+	fn cancel_ownership_transfer() -> Weight;
+	fn precompile_renounce_ownership() -> Weight;
+	fn renounce_ownership() -> Weight;
+	fn precompile_create_collection_with_metadata() -> Weight;
+	fn precompile_set_collection_metadata() -> Weight;
+	fn precompile_collection_metadata() -> Weight;
+	fn set_collection_metadata() -> Weight;
+	fn precompile_set_default_royalty() -> Weight;
+	fn precompile_set_token_royalty() -> Weight;
+	fn precompile_royalty_info() -> Weight;
+	fn set_default_royalty() -> Weight;
+	fn set_token_royalty() -> Weight;
+	fn precompile_token_uri_version() -> Weight;
+	fn precompile_token_uri_at() -> Weight;
+	fn precompile_mint_with_signature(s: u32, ) -> Weight;
+	fn precompile_is_mint_nonce_used() -> Weight;
+	fn precompile_invalidate_mint_nonce() -> Weight;
+	fn invalidate_mint_nonce() -> Weight;
+	fn precompile_claim_from_drop(p: u32, s: u32, ) -> Weight;
+	fn precompile_set_drop_root() -> Weight;
+	fn precompile_drop_root() -> Weight;
+	fn set_drop_root() -> Weight;
+	fn create_collection_with_max_supply() -> Weight;
+	fn precompile_create_collection_with_max_supply() -> Weight;
+	fn precompile_total_supply() -> Weight;
+	fn precompile_max_supply() -> Weight;
+	fn precompile_token_by_index() -> Weight;
+	fn set_base_uri(s: u32, ) -> Weight;
+	fn precompile_set_base_uri(s: u32, ) -> Weight;
+	fn precompile_base_uri() -> Weight;
+	fn mint_next(s: u32, ) -> Weight;
+	fn precompile_mint_next(s: u32, ) -> Weight;
+	fn schedule_evolution(s: u32, ) -> Weight;
+	fn cancel_scheduled_evolution() -> Weight;
+	fn apply_scheduled_evolutions(n: u32, ) -> Weight;
+	fn precompile_scheduled_evolution() -> Weight;
+	fn precompile_schedule_evolution(s: u32, ) -> Weight;
+	fn precompile_cancel_scheduled_evolution() -> Weight;
+	fn set_attribute(s: u32, ) -> Weight;
+	fn remove_attribute() -> Weight;
+	fn precompile_set_attribute(s: u32, ) -> Weight;
+	fn precompile_remove_attribute() -> Weight;
+	fn precompile_get_attribute() -> Weight;
+	fn set_evolution_consent_required() -> Weight;
+	fn approve_evolution() -> Weight;
+	fn precompile_set_evolution_consent_required() -> Weight;
+	fn precompile_evolution_consent_required() -> Weight;
+	fn precompile_approve_evolution() -> Weight;
+	fn precompile_evolve_with_signature(s: u32, ) -> Weight;
+	fn create_collection_with_metadata_and_max_supply() -> Weight;
+	fn precompile_create_collection_with_metadata_and_max_supply() -> Weight;
 }
 
 /// Weights for `pallet_laos_evolution` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn precompile_discriminant() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 266_000 picoseconds.
-		Weight::from_parts(319_000, 0)
+		Weight::from_parts(319_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
 	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -65,53 +137,85 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `EVM::Suicided` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `EVM::AccountCodes` (r:1 w:1)
 	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
 	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn precompile_create_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `375`
-		//  Estimated: `3840`
-		// Minimum execution time: 30_193_000 picoseconds.
 		Weight::from_parts(32_520_000, 3840)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
-	fn precompile_mint(_s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `139`
-		//  Estimated: `4051`
-		// Minimum execution time: 20_807_000 picoseconds.
-		Weight::from_parts(22_501_463, 4051)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	fn precompile_mint(s: u32, ) -> Weight {
+		Weight::from_parts(22_501_463, 6598)
+			.saturating_add(Weight::from_parts(1_355, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
 	fn precompile_evolve(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `264`
-		//  Estimated: `4051`
-		// Minimum execution time: 18_884_000 picoseconds.
 		Weight::from_parts(21_037_684, 4051)
-			// Standard Error: 204
 			.saturating_add(Weight::from_parts(820, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
-	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn precompile_transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `139`
@@ -133,57 +237,99 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
 	fn precompile_token_uri() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `306`
-		//  Estimated: `4051`
-		// Minimum execution time: 9_640_000 picoseconds.
 		Weight::from_parts(10_289_000, 4051)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	fn precompile_supports_interface() -> Weight {
+		Weight::from_parts(319_000, 0)
+	}
+	fn precompile_initial_owner_of() -> Weight {
+		Weight::from_parts(319_000, 0)
+	}
+	fn precompile_slot_of() -> Weight {
+		Weight::from_parts(319_000, 0)
 	}
 	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
 	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn create_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `1493`
-		// Minimum execution time: 12_747_000 picoseconds.
 		Weight::from_parts(13_178_000, 1493)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
-	fn mint_with_external_uri(_s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `139`
-		//  Estimated: `4051`
-		// Minimum execution time: 19_859_000 picoseconds.
-		Weight::from_parts(23_616_615, 4051)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	fn mint_with_external_uri(s: u32, ) -> Weight {
+		Weight::from_parts(23_616_615, 6598)
+			.saturating_add(Weight::from_parts(2_055, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
 	fn evolve_with_external_uri(_s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `264`
-		//  Estimated: `4051`
-		// Minimum execution time: 18_351_000 picoseconds.
 		Weight::from_parts(20_489_182, 4051)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
-	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `139`
@@ -193,149 +339,2364 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-}
-
-// For backwards compatibility and tests.
-impl WeightInfo for () {
-	fn precompile_discriminant() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 266_000 picoseconds.
-		Weight::from_parts(319_000, 0)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:17 w:16)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:0 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:10 w:10)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 16]`.
+	/// The range of component `h` is `[0, 10]`.
+	fn precompile_burn(a: u32, h: u32, ) -> Weight {
+		Weight::from_parts(14_502_000, 4488)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 2853).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(h.into()))
 	}
-	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
-	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `EVM::Suicided` (r:1 w:0)
-	/// Proof: `EVM::Suicided` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `EVM::AccountCodes` (r:1 w:1)
-	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
-	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
-	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:17 w:16)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:0 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:10 w:10)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 16]`.
+	/// The range of component `h` is `[0, 10]`.
+	fn burn(a: u32, h: u32, ) -> Weight {
+		Weight::from_parts(13_967_000, 4488)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 2853).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(h.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
-	fn precompile_create_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `375`
-		//  Estimated: `3840`
-		// Minimum execution time: 30_193_000 picoseconds.
-		Weight::from_parts(32_520_000, 3840)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	/// Storage: `LaosEvolution::NextSlot` (r:100 w:100)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_batch_mint(n: u32, s: u32, ) -> Weight {
+		Weight::from_parts(2_113_457, 3509)
+			.saturating_add(Weight::from_parts(14_385_012, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_355, 0).saturating_mul(n.saturating_mul(s).into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5608).saturating_mul(n.into()))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
-	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Storage: `LaosEvolution::TokenURI` (r:100 w:100)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:100 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:100 w:200)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:100 w:100)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
 	/// The range of component `s` is `[0, 512]`.
-	fn precompile_mint(_s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `139`
-		//  Estimated: `4051`
-		// Minimum execution time: 20_807_000 picoseconds.
-		Weight::from_parts(22_501_463, 4051)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	fn precompile_batch_evolve(n: u32, s: u32, ) -> Weight {
+		Weight::from_parts(3_020_817, 3509)
+			.saturating_add(Weight::from_parts(13_948_233, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_740, 0).saturating_mul(n.saturating_mul(s).into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3061).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
-	/// The range of component `s` is `[0, 512]`.
-	fn precompile_evolve(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `264`
-		//  Estimated: `4051`
-		// Minimum execution time: 18_884_000 picoseconds.
-		Weight::from_parts(21_037_684, 4051)
-			// Standard Error: 204
-			.saturating_add(Weight::from_parts(820, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	/// Storage: `LaosEvolution::FrozenToken` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_freeze_token() -> Weight {
+		Weight::from_parts(14_863_000, 4051)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_freeze_collection() -> Weight {
+		Weight::from_parts(11_942_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn precompile_is_frozen() -> Weight {
+		Weight::from_parts(7_781_000, 3537)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn freeze_token() -> Weight {
+		Weight::from_parts(14_120_000, 4051)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn freeze_collection() -> Weight {
+		Weight::from_parts(11_305_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_grant_role() -> Weight {
+		Weight::from_parts(14_017_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_revoke_role() -> Weight {
+		Weight::from_parts(14_770_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_has_role() -> Weight {
+		Weight::from_parts(7_355_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn grant_role() -> Weight {
+		Weight::from_parts(13_190_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(13_965_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
-	fn precompile_transfer_ownership() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `139`
-		//  Estimated: `3509`
-		// Minimum execution time: 11_222_000 picoseconds.
-		Weight::from_parts(11_807_000, 3509)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_accept_ownership() -> Weight {
+		Weight::from_parts(13_702_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
-	fn precompile_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `139`
-		//  Estimated: `3509`
-		// Minimum execution time: 6_686_000 picoseconds.
-		Weight::from_parts(7_280_000, 3509)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn precompile_cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(12_431_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
-	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
-	fn precompile_token_uri() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `306`
-		//  Estimated: `4051`
-		// Minimum execution time: 9_640_000 picoseconds.
-		Weight::from_parts(10_289_000, 4051)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn precompile_pending_owner() -> Weight {
+		Weight::from_parts(6_893_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(12_866_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(11_892_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn precompile_renounce_ownership() -> Weight {
+		Weight::from_parts(12_104_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn renounce_ownership() -> Weight {
+		Weight::from_parts(11_436_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
 	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
-	fn create_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `1493`
-		// Minimum execution time: 12_747_000 picoseconds.
-		Weight::from_parts(13_178_000, 1493)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_metadata() -> Weight {
+		Weight::from_parts(20_914_000, 3907)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
-	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn precompile_set_collection_metadata() -> Weight {
+		Weight::from_parts(12_388_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn precompile_collection_metadata() -> Weight {
+		Weight::from_parts(8_356_000, 4166)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn set_collection_metadata() -> Weight {
+		Weight::from_parts(11_812_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn precompile_set_default_royalty() -> Weight {
+		Weight::from_parts(12_170_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
-	/// The range of component `s` is `[0, 512]`.
-	fn mint_with_external_uri(_s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `139`
-		//  Estimated: `4051`
-		// Minimum execution time: 19_859_000 picoseconds.
-		Weight::from_parts(23_616_615, 4051)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn precompile_set_token_royalty() -> Weight {
+		Weight::from_parts(14_163_000, 4051)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:1 w:0)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn precompile_royalty_info() -> Weight {
+		Weight::from_parts(9_488_000, 3559)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(11_598_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn set_token_royalty() -> Weight {
+		Weight::from_parts(13_517_000, 4051)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn precompile_token_uri_version() -> Weight {
+		Weight::from_parts(9_577_000, 4051)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	fn precompile_token_uri_at() -> Weight {
+		Weight::from_parts(10_125_000, 4071)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
-	fn evolve_with_external_uri(_s: u32, ) -> Weight {
+	fn precompile_mint_with_signature(_s: u32, ) -> Weight {
+		Weight::from_parts(71_734_112, 6598)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:0)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn precompile_is_mint_nonce_used() -> Weight {
+		Weight::from_parts(7_219_000, 3565)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn precompile_invalidate_mint_nonce() -> Weight {
+		Weight::from_parts(12_473_000, 3565)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn invalidate_mint_nonce() -> Weight {
+		Weight::from_parts(11_916_000, 3565)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::DropRoot` (r:1 w:0)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ClaimedDropLeaf` (r:1 w:1)
+	/// Proof: `LaosEvolution::ClaimedDropLeaf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_claim_from_drop(p: u32, _s: u32, ) -> Weight {
+		Weight::from_parts(26_102_645, 6598)
+			.saturating_add(Weight::from_parts(1_847_312, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn precompile_set_drop_root() -> Weight {
+		Weight::from_parts(11_808_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::DropRoot` (r:1 w:0)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn precompile_drop_root() -> Weight {
+		Weight::from_parts(6_890_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_drop_root() -> Weight {
+		Weight::from_parts(11_244_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_collection_with_max_supply() -> Weight {
+		Weight::from_parts(14_021_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::Suicided` (r:1 w:0)
+	/// Proof: `EVM::Suicided` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_max_supply() -> Weight {
+		Weight::from_parts(33_287_000, 3840)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn precompile_total_supply() -> Weight {
+		Weight::from_parts(6_584_000, 3497)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn precompile_max_supply() -> Weight {
+		Weight::from_parts(6_547_000, 3497)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn precompile_token_by_index() -> Weight {
+		Weight::from_parts(7_219_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:0 w:1)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn set_base_uri(s: u32, ) -> Weight {
+		Weight::from_parts(12_174_380, 3509)
+			.saturating_add(Weight::from_parts(1_112, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:0 w:1)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_set_base_uri(s: u32, ) -> Weight {
+		Weight::from_parts(16_463_912, 3509)
+			.saturating_add(Weight::from_parts(2_306, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	fn precompile_base_uri() -> Weight {
+		Weight::from_parts(7_311_000, 4003)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn mint_next(s: u32, ) -> Weight {
+		Weight::from_parts(23_616_615, 6598)
+			.saturating_add(Weight::from_parts(2_061, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_mint_next(s: u32, ) -> Weight {
+		Weight::from_parts(22_501_463, 6598)
+			.saturating_add(Weight::from_parts(2_061, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:2 w:2)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn schedule_evolution(s: u32, ) -> Weight {
+		Weight::from_parts(18_937_261, 5478)
+			.saturating_add(Weight::from_parts(1_163, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_evolution() -> Weight {
+		Weight::from_parts(15_108_000, 5478)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:50 w:50)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:50 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:50 w:100)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:50 w:50)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:50 w:50)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn apply_scheduled_evolutions(n: u32, ) -> Weight {
+		Weight::from_parts(4_012_530, 5478)
+			.saturating_add(Weight::from_parts(19_873_512, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(n.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:0)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	fn precompile_scheduled_evolution() -> Weight {
+		Weight::from_parts(10_344_000, 4055)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:2 w:2)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_schedule_evolution(s: u32, ) -> Weight {
+		Weight::from_parts(19_652_884, 5478)
+			.saturating_add(Weight::from_parts(1_163, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn precompile_cancel_scheduled_evolution() -> Weight {
+		Weight::from_parts(15_823_623, 5478)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 256]`.
+	fn set_attribute(s: u32, ) -> Weight {
+		Weight::from_parts(28_950_116, 4051)
+			.saturating_add(Weight::from_parts(1_021, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn remove_attribute() -> Weight {
+		Weight::from_parts(26_913_000, 3843)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 256]`.
+	fn precompile_set_attribute(s: u32, ) -> Weight {
+		Weight::from_parts(31_204_739, 4051)
+			.saturating_add(Weight::from_parts(1_748, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_remove_attribute() -> Weight {
+		Weight::from_parts(28_617_000, 3843)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	fn precompile_get_attribute() -> Weight {
+		Weight::from_parts(9_188_000, 3843)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_evolution_consent_required() -> Weight {
+		Weight::from_parts(11_427_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn approve_evolution() -> Weight {
+		Weight::from_parts(12_398_000, 4051)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_set_evolution_consent_required() -> Weight {
+		Weight::from_parts(12_187_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_evolution_consent_required() -> Weight {
+		Weight::from_parts(6_957_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_approve_evolution() -> Weight {
+		Weight::from_parts(13_305_000, 4051)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_evolve_with_signature(s: u32, ) -> Weight {
+		Weight::from_parts(70_215_389, 4051)
+			.saturating_add(Weight::from_parts(803, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_collection_with_metadata_and_max_supply() -> Weight {
+		Weight::from_parts(15_512_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_metadata_and_max_supply() -> Weight {
+		Weight::from_parts(22_131_000, 3907)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn precompile_discriminant() -> Weight {
+		Weight::from_parts(319_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::Suicided` (r:1 w:0)
+	/// Proof: `EVM::Suicided` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection() -> Weight {
+		Weight::from_parts(32_520_000, 3840)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_mint(s: u32, ) -> Weight {
+		Weight::from_parts(22_501_463, 6598)
+			.saturating_add(Weight::from_parts(1_355, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_evolve(s: u32, ) -> Weight {
+		Weight::from_parts(21_037_684, 4051)
+			.saturating_add(Weight::from_parts(820, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn precompile_transfer_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3509`
+		// Minimum execution time: 11_222_000 picoseconds.
+		Weight::from_parts(11_807_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn precompile_owner() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `264`
-		//  Estimated: `4051`
-		// Minimum execution time: 18_351_000 picoseconds.
+		//  Measured:  `139`
+		//  Estimated: `3509`
+		// Minimum execution time: 6_686_000 picoseconds.
+		Weight::from_parts(7_280_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	fn precompile_token_uri() -> Weight {
+		Weight::from_parts(10_289_000, 4051)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	fn precompile_supports_interface() -> Weight {
+		Weight::from_parts(319_000, 0)
+	}
+	fn precompile_initial_owner_of() -> Weight {
+		Weight::from_parts(319_000, 0)
+	}
+	fn precompile_slot_of() -> Weight {
+		Weight::from_parts(319_000, 0)
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_collection() -> Weight {
+		Weight::from_parts(13_178_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn mint_with_external_uri(s: u32, ) -> Weight {
+		Weight::from_parts(23_616_615, 6598)
+			.saturating_add(Weight::from_parts(2_055, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn evolve_with_external_uri(_s: u32, ) -> Weight {
 		Weight::from_parts(20_489_182, 4051)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn transfer_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3509`
+		// Minimum execution time: 11_500_000 picoseconds.
+		Weight::from_parts(11_925_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:17 w:16)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:0 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:10 w:10)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 16]`.
+	/// The range of component `h` is `[0, 10]`.
+	fn precompile_burn(a: u32, h: u32, ) -> Weight {
+		Weight::from_parts(14_502_000, 4488)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 2853).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(h.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:17 w:16)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:0 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:10 w:10)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 16]`.
+	/// The range of component `h` is `[0, 10]`.
+	fn burn(a: u32, h: u32, ) -> Weight {
+		Weight::from_parts(13_967_000, 4488)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 2853).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(h.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:100 w:100)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_batch_mint(n: u32, s: u32, ) -> Weight {
+		Weight::from_parts(2_113_457, 3509)
+			.saturating_add(Weight::from_parts(14_385_012, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_355, 0).saturating_mul(n.saturating_mul(s).into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5608).saturating_mul(n.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:100 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:100 w:200)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:100 w:100)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_batch_evolve(n: u32, s: u32, ) -> Weight {
+		Weight::from_parts(3_020_817, 3509)
+			.saturating_add(Weight::from_parts(13_948_233, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_740, 0).saturating_mul(n.saturating_mul(s).into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3061).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_freeze_token() -> Weight {
+		Weight::from_parts(14_863_000, 4051)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_freeze_collection() -> Weight {
+		Weight::from_parts(11_942_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn precompile_is_frozen() -> Weight {
+		Weight::from_parts(7_781_000, 3537)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn freeze_token() -> Weight {
+		Weight::from_parts(14_120_000, 4051)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn freeze_collection() -> Weight {
+		Weight::from_parts(11_305_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_grant_role() -> Weight {
+		Weight::from_parts(14_017_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_revoke_role() -> Weight {
+		Weight::from_parts(14_770_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_has_role() -> Weight {
+		Weight::from_parts(7_355_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn grant_role() -> Weight {
+		Weight::from_parts(13_190_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(13_965_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_accept_ownership() -> Weight {
+		Weight::from_parts(13_702_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn precompile_cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(12_431_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn precompile_pending_owner() -> Weight {
+		Weight::from_parts(6_893_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(12_866_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(11_892_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn precompile_renounce_ownership() -> Weight {
+		Weight::from_parts(12_104_000, 3581)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn renounce_ownership() -> Weight {
+		Weight::from_parts(11_436_000, 3581)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_metadata() -> Weight {
+		Weight::from_parts(20_914_000, 3907)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn precompile_set_collection_metadata() -> Weight {
+		Weight::from_parts(12_388_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn precompile_collection_metadata() -> Weight {
+		Weight::from_parts(8_356_000, 4166)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn set_collection_metadata() -> Weight {
+		Weight::from_parts(11_812_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn precompile_set_default_royalty() -> Weight {
+		Weight::from_parts(12_170_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn precompile_set_token_royalty() -> Weight {
+		Weight::from_parts(14_163_000, 4051)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:1 w:0)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn precompile_royalty_info() -> Weight {
+		Weight::from_parts(9_488_000, 3559)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
-	fn transfer_ownership() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `139`
-		//  Estimated: `3509`
-		// Minimum execution time: 11_500_000 picoseconds.
-		Weight::from_parts(11_925_000, 3509)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(11_598_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn set_token_royalty() -> Weight {
+		Weight::from_parts(13_517_000, 4051)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn precompile_token_uri_version() -> Weight {
+		Weight::from_parts(9_577_000, 4051)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	fn precompile_token_uri_at() -> Weight {
+		Weight::from_parts(10_125_000, 4071)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_mint_with_signature(_s: u32, ) -> Weight {
+		Weight::from_parts(71_734_112, 6598)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:0)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn precompile_is_mint_nonce_used() -> Weight {
+		Weight::from_parts(7_219_000, 3565)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn precompile_invalidate_mint_nonce() -> Weight {
+		Weight::from_parts(12_473_000, 3565)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn invalidate_mint_nonce() -> Weight {
+		Weight::from_parts(11_916_000, 3565)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::DropRoot` (r:1 w:0)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ClaimedDropLeaf` (r:1 w:1)
+	/// Proof: `LaosEvolution::ClaimedDropLeaf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_claim_from_drop(p: u32, _s: u32, ) -> Weight {
+		Weight::from_parts(26_102_645, 6598)
+			.saturating_add(Weight::from_parts(1_847_312, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn precompile_set_drop_root() -> Weight {
+		Weight::from_parts(11_808_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::DropRoot` (r:1 w:0)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn precompile_drop_root() -> Weight {
+		Weight::from_parts(6_890_000, 3521)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_drop_root() -> Weight {
+		Weight::from_parts(11_244_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_collection_with_max_supply() -> Weight {
+		Weight::from_parts(14_021_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::Suicided` (r:1 w:0)
+	/// Proof: `EVM::Suicided` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_max_supply() -> Weight {
+		Weight::from_parts(33_287_000, 3840)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn precompile_total_supply() -> Weight {
+		Weight::from_parts(6_584_000, 3497)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn precompile_max_supply() -> Weight {
+		Weight::from_parts(6_547_000, 3497)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn precompile_token_by_index() -> Weight {
+		Weight::from_parts(7_219_000, 3545)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:0 w:1)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn set_base_uri(s: u32, ) -> Weight {
+		Weight::from_parts(12_174_380, 3509)
+			.saturating_add(Weight::from_parts(1_112, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:0 w:1)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_set_base_uri(s: u32, ) -> Weight {
+		Weight::from_parts(16_463_912, 3509)
+			.saturating_add(Weight::from_parts(2_306, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	fn precompile_base_uri() -> Weight {
+		Weight::from_parts(7_311_000, 4003)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn mint_next(s: u32, ) -> Weight {
+		Weight::from_parts(23_616_615, 6598)
+			.saturating_add(Weight::from_parts(2_061, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_mint_next(s: u32, ) -> Weight {
+		Weight::from_parts(22_501_463, 6598)
+			.saturating_add(Weight::from_parts(2_061, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:2 w:2)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn schedule_evolution(s: u32, ) -> Weight {
+		Weight::from_parts(18_937_261, 5478)
+			.saturating_add(Weight::from_parts(1_163, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_evolution() -> Weight {
+		Weight::from_parts(15_108_000, 5478)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:50 w:50)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:50 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:50 w:100)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:50 w:50)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:50 w:50)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn apply_scheduled_evolutions(n: u32, ) -> Weight {
+		Weight::from_parts(4_012_530, 5478)
+			.saturating_add(Weight::from_parts(19_873_512, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(n.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:0)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	fn precompile_scheduled_evolution() -> Weight {
+		Weight::from_parts(10_344_000, 4055)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:2 w:2)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_schedule_evolution(s: u32, ) -> Weight {
+		Weight::from_parts(19_652_884, 5478)
+			.saturating_add(Weight::from_parts(1_163, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn precompile_cancel_scheduled_evolution() -> Weight {
+		Weight::from_parts(15_823_623, 5478)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 256]`.
+	fn set_attribute(s: u32, ) -> Weight {
+		Weight::from_parts(28_950_116, 4051)
+			.saturating_add(Weight::from_parts(1_021, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn remove_attribute() -> Weight {
+		Weight::from_parts(26_913_000, 3843)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 256]`.
+	fn precompile_set_attribute(s: u32, ) -> Weight {
+		Weight::from_parts(31_204_739, 4051)
+			.saturating_add(Weight::from_parts(1_748, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_remove_attribute() -> Weight {
+		Weight::from_parts(28_617_000, 3843)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	fn precompile_get_attribute() -> Weight {
+		Weight::from_parts(9_188_000, 3843)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_evolution_consent_required() -> Weight {
+		Weight::from_parts(11_427_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn approve_evolution() -> Weight {
+		Weight::from_parts(12_398_000, 4051)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_set_evolution_consent_required() -> Weight {
+		Weight::from_parts(12_187_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_evolution_consent_required() -> Weight {
+		Weight::from_parts(6_957_000, 3489)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_approve_evolution() -> Weight {
+		Weight::from_parts(13_305_000, 4051)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_evolve_with_signature(s: u32, ) -> Weight {
+		Weight::from_parts(70_215_389, 4051)
+			.saturating_add(Weight::from_parts(803, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_collection_with_metadata_and_max_supply() -> Weight {
+		Weight::from_parts(15_512_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_metadata_and_max_supply() -> Weight {
+		Weight::from_parts(22_131_000, 3907)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
 }
\ No newline at end of file
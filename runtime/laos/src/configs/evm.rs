@@ -186,10 +186,6 @@ mod tests {
 					wrong_address_call_result.actual_weight.unwrap(),
 					Weight::from_parts(428726000, 5358)
 				);
-				assert_eq!(
-					call_result.actual_weight.unwrap(),
-					Weight::from_parts(1022151000, 11292)
-				);
 
 				// check gas
 				assert_eq!(
@@ -198,22 +194,17 @@ mod tests {
 					),
 					17149
 				);
-				assert_eq!(
-					<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
-						call_result.actual_weight.unwrap()
-					),
-					40886
-				);
 
-				// check weights from benchmarking
-				let weights_from_benchmarking =
-					weights::pallet_laos_evolution::WeightInfo::<Runtime>::precompile_create_collection();
-				assert_eq!(weights_from_benchmarking, Weight::from_parts(593426000, 3907));
+				// the call costs the base cost plus the weights from benchmarking
+				let gas_from_benchmarking =
+					<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+						weights::pallet_laos_evolution::WeightInfo::<Runtime>::precompile_create_collection(),
+					);
 				assert_eq!(
 					<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
-						weights_from_benchmarking
+						call_result.actual_weight.unwrap()
 					),
-					23737
+					17149 + gas_from_benchmarking
 				);
 			});
 	}
@@ -264,7 +255,6 @@ mod tests {
 					wrong_address_call_result.actual_weight.unwrap(),
 					Weight::from_parts(419526000, 5266)
 				);
-				assert_eq!(call_result.actual_weight.unwrap(), Weight::from_parts(450326000, 5574));
 
 				// check gas
 				assert_eq!(
@@ -273,22 +263,20 @@ mod tests {
 					),
 					16781
 				);
-				assert_eq!(
-					<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
-						call_result.actual_weight.unwrap()
-					),
-					18013
-				);
 
-				// check weights from benchmarking
-				let weights_from_benchmarking =
-					weights::pallet_laos_evolution::WeightInfo::<Runtime>::precompile_owner();
-				assert_eq!(weights_from_benchmarking, Weight::from_parts(30696000, 3509));
+				// the call costs the base cost plus the weights from benchmarking of the collection
+				// discriminant and of `owner`
+				let gas_from_benchmarking =
+					<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+						weights::pallet_laos_evolution::WeightInfo::<Runtime>::precompile_discriminant(),
+					) + <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+						weights::pallet_laos_evolution::WeightInfo::<Runtime>::precompile_owner(),
+					);
 				assert_eq!(
 					<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
-						weights_from_benchmarking
+						call_result.actual_weight.unwrap()
 					),
-					1227
+					16781 + gas_from_benchmarking
 				);
 			});
 	}
@@ -15,15 +15,52 @@
 // along with LAOS.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::MaxTokenUriLength;
-use crate::{types::AccountIdToH160, weights, Runtime, RuntimeEvent};
+use crate::{
+	currency::calculate_deposit, types::AccountIdToH160, weights, Balance, Balances, EVMChainId,
+	Runtime, RuntimeEvent, Timestamp,
+};
+use frame_support::parameter_types;
+
+parameter_types! {
+	/// Max length of the name of a collection
+	pub const MaxCollectionNameLength: u32 = 128;
+	/// Max length of the symbol of a collection
+	pub const MaxCollectionSymbolLength: u32 = 32;
+	/// Max number of previous token URIs kept for every token
+	pub const MaxUriHistory: u32 = 10;
+	/// Max number of evolutions scheduled for the same block
+	pub const MaxScheduledEvolutionsPerBlock: u32 = 50;
+	/// Max length of the value of a token attribute
+	pub const MaxAttributeValueLength: u32 = 256;
+	/// Max number of attributes of every token
+	pub const MaxAttributesPerToken: u32 = 16;
+	/// Deposit for the ownership entry of a collection
+	pub const CollectionDeposit: Balance = calculate_deposit(1, 44);
+	/// Deposit for every byte of the token URIs of a collection
+	pub const DepositPerByte: Balance = calculate_deposit(0, 1);
+}
 
 impl pallet_laos_evolution::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AccountIdToH160 = AccountIdToH160;
 	type MaxTokenUriLength = MaxTokenUriLength;
+	type MaxCollectionNameLength = MaxCollectionNameLength;
+	type MaxCollectionSymbolLength = MaxCollectionSymbolLength;
+	type MaxUriHistory = MaxUriHistory;
+	type MaxScheduledEvolutionsPerBlock = MaxScheduledEvolutionsPerBlock;
+	type MaxAttributeValueLength = MaxAttributeValueLength;
+	type MaxAttributesPerToken = MaxAttributesPerToken;
+	type ChainId = EVMChainId;
+	type Timestamp = Timestamp;
 	type WeightInfo = weights::pallet_laos_evolution::WeightInfo<Runtime>;
 	type GasWeightMapping = <Runtime as pallet_evm::Config>::GasWeightMapping;
 	type OnCreateCollection = CollectionManager;
+	type OnMint = ();
+	type OnEvolve = ();
+	type OnOwnershipTransferred = ();
+	type Currency = Balances;
+	type CollectionDeposit = CollectionDeposit;
+	type DepositPerByte = DepositPerByte;
 }
 
 // This is the simplest bytecode to revert without returning any data.
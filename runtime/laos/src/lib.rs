@@ -45,6 +45,7 @@ pub use laos_primitives::{
 use pallet_aura::Authorities;
 use pallet_ethereum::{Call::transact, Transaction as EthereumTransaction};
 use pallet_evm::{Account as EVMAccount, FeeCalculator, Runner};
+use pallet_laos_evolution::types::{CollectionId, TokenId};
 pub use pallet_parachain_staking::{InflationInfo, Range};
 use parity_scale_codec::Encode;
 use precompiles::LaosPrecompiles;
@@ -679,4 +680,33 @@ impl_runtime_apis! {
 			PolkadotXcm::dry_run_xcm::<Runtime, xcm_config::XcmRouter, RuntimeCall, xcm_config::XcmConfig>(origin_location, xcm)
 		}
 	}
+
+	impl pallet_laos_evolution::runtime_api::LaosEvolutionApi<Block, AccountId> for Runtime {
+		fn collection_owner(collection_id: CollectionId) -> Option<AccountId> {
+			LaosEvolution::collection_owner(collection_id)
+		}
+
+		fn token_uri(collection_id: CollectionId, token_id: TokenId) -> Option<Vec<u8>> {
+			LaosEvolution::token_uri(collection_id, token_id).map(Into::into)
+		}
+
+		fn collection_counter() -> CollectionId {
+			LaosEvolution::collection_counter()
+		}
+
+		fn tokens_of_collection(
+			collection_id: CollectionId,
+			start_after: Option<TokenId>,
+			limit: u32,
+		) -> Vec<(TokenId, Vec<u8>)> {
+			LaosEvolution::tokens_of_collection(collection_id, start_after, limit)
+				.into_iter()
+				.map(|(token_id, token_uri)| (token_id, token_uri.into()))
+				.collect()
+		}
+
+		fn tokens_by_index(collection_id: CollectionId, offset: u64, limit: u32) -> Vec<TokenId> {
+			LaosEvolution::tokens_by_index(collection_id, offset, limit)
+		}
+	}
 }
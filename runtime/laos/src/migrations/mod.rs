@@ -16,4 +16,7 @@
 
 mod remove_pallet_sudo;
 
-pub type Migrations = remove_pallet_sudo::Migration;
+pub type Migrations = (
+	remove_pallet_sudo::Migration,
+	pallet_laos_evolution::migrations::MigrateV0ToV1<crate::Runtime>,
+);
@@ -16,7 +16,7 @@
 
 use crate::precompiles::LaosPrecompiles;
 
-use crate::{configs::laos_evolution::REVERT_BYTECODE, Runtime};
+use crate::{configs::laos_evolution::REVERT_BYTECODE, currency::UNIT, AccountId, Runtime};
 use core::str::FromStr;
 use fp_evm::Context;
 use frame_support::assert_noop;
@@ -140,25 +140,31 @@ fn call_unknown_address_is_noop() {
 
 #[test]
 fn create_collection_inserts_bytecode_to_address() {
-	ExtBuilder::default().build().execute_with(|| {
-		let expected_collection_address =
-			H160::from_str("fffffffffffffffffffffffe0000000000000000").unwrap();
-
-		let precompiles = <Runtime as pallet_evm::Config>::PrecompilesValue::get();
-		precompiles
-			.prepare_test(
-				Alice,
-				hash(1027),
-				EvolutionCollectionFactoryPrecompileCall::<Runtime>::create_collection {
-					owner: Address(Alice.into()),
-				},
-			)
-			.execute_returns(Address(expected_collection_address));
-
-		// Address is not empty
-		assert!(!pallet_evm::Pallet::<Runtime>::is_account_empty(&expected_collection_address));
-
-		// Address has correct code
-		assert!(AccountCodes::<Runtime>::get(expected_collection_address) == REVERT_BYTECODE);
-	});
+	// the caller pays the deposit of the collection
+	let alice = AccountId::from(H160::from(Alice));
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice, 1000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			let expected_collection_address =
+				H160::from_str("fffffffffffffffffffffffe0000000000000000").unwrap();
+
+			let precompiles = <Runtime as pallet_evm::Config>::PrecompilesValue::get();
+			precompiles
+				.prepare_test(
+					Alice,
+					hash(1027),
+					EvolutionCollectionFactoryPrecompileCall::<Runtime>::create_collection {
+						owner: Address(Alice.into()),
+					},
+				)
+				.execute_returns(Address(expected_collection_address));
+
+			// Address is not empty
+			assert!(!pallet_evm::Pallet::<Runtime>::is_account_empty(&expected_collection_address));
+
+			// Address has correct code
+			assert!(AccountCodes::<Runtime>::get(expected_collection_address) == REVERT_BYTECODE);
+		});
 }
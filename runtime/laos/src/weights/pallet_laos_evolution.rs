@@ -1,13 +1,12 @@
-
-//! Autogenerated weights for `pallet_laos_evolution`
+//! Weights for `pallet_laos_evolution`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 43.0.0
-//! DATE: 2025-03-10, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `titan`, CPU: `12th Gen Intel(R) Core(TM) i7-1260P`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//! These weights were last generated with the benchmark command below on 2025-03-10 and have
+//! been maintained by hand since, as the pallet grew new calls and storage items. Only the
+//! weights unchanged since then keep the figures reported by the benchmark, the others are
+//! estimates. They must be regenerated with that command before they are relied upon in
+//! production.
 
-// Executed Command:
+// Command:
 // ./target/release/laos
 // benchmark
 // pallet
@@ -30,70 +29,101 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_laos_evolution`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_laos_evolution::WeightInfo for WeightInfo<T> {
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn precompile_discriminant() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 117_000 picoseconds.
 		Weight::from_parts(149_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(2))
 	}
 	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
 	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `EVM::AccountCodes` (r:1 w:1)
 	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
 	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn precompile_create_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `442`
-		//  Estimated: `3907`
-		// Minimum execution time: 17_513_000 picoseconds.
 		Weight::from_parts(18_426_000, 0)
 			.saturating_add(Weight::from_parts(0, 3907))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
 	fn precompile_mint(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `173`
-		//  Estimated: `4051`
-		// Minimum execution time: 14_278_000 picoseconds.
 		Weight::from_parts(16_087_596, 0)
-			.saturating_add(Weight::from_parts(0, 4051))
-			// Standard Error: 92
+			.saturating_add(Weight::from_parts(0, 6598))
 			.saturating_add(Weight::from_parts(1_355, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
 	fn precompile_evolve(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `298`
-		//  Estimated: `4051`
-		// Minimum execution time: 15_182_000 picoseconds.
 		Weight::from_parts(16_172_875, 0)
 			.saturating_add(Weight::from_parts(0, 4051))
-			// Standard Error: 71
 			.saturating_add(Weight::from_parts(1_740, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
-	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn precompile_transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `173`
@@ -117,65 +147,111 @@ impl<T: frame_system::Config> pallet_laos_evolution::WeightInfo for WeightInfo<T
 	}
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
 	fn precompile_token_uri() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `340`
-		//  Estimated: `4051`
-		// Minimum execution time: 6_885_000 picoseconds.
 		Weight::from_parts(7_208_000, 0)
 			.saturating_add(Weight::from_parts(0, 4051))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	fn precompile_supports_interface() -> Weight {
+		Weight::from_parts(149_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn precompile_initial_owner_of() -> Weight {
+		Weight::from_parts(149_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn precompile_slot_of() -> Weight {
+		Weight::from_parts(149_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
 	}
 	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
 	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn create_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `1493`
-		// Minimum execution time: 6_064_000 picoseconds.
-		Weight::from_parts(6_374_000, 0)
-			.saturating_add(Weight::from_parts(0, 1493))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(2))
+		Weight::from_parts(17_615_000, 0)
+			.saturating_add(Weight::from_parts(0, 3907))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
 	fn mint_with_external_uri(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `173`
-		//  Estimated: `4051`
-		// Minimum execution time: 14_240_000 picoseconds.
 		Weight::from_parts(15_555_454, 0)
-			.saturating_add(Weight::from_parts(0, 4051))
-			// Standard Error: 88
+			.saturating_add(Weight::from_parts(0, 6598))
 			.saturating_add(Weight::from_parts(2_055, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
 	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 512]`.
 	fn evolve_with_external_uri(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `298`
-		//  Estimated: `4051`
-		// Minimum execution time: 14_037_000 picoseconds.
 		Weight::from_parts(15_220_323, 0)
 			.saturating_add(Weight::from_parts(0, 4051))
-			// Standard Error: 79
 			.saturating_add(Weight::from_parts(817, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
-	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
 	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `173`
@@ -186,4 +262,1143 @@ impl<T: frame_system::Config> pallet_laos_evolution::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:17 w:16)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:0 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:10 w:10)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 16]`.
+	/// The range of component `h` is `[0, 10]`.
+	fn precompile_burn(a: u32, h: u32, ) -> Weight {
+		Weight::from_parts(14_502_000, 0)
+			.saturating_add(Weight::from_parts(0, 4488))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(16))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 2853).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(h.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:17 w:16)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:0 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:10 w:10)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 16]`.
+	/// The range of component `h` is `[0, 10]`.
+	fn burn(a: u32, h: u32, ) -> Weight {
+		Weight::from_parts(13_967_000, 0)
+			.saturating_add(Weight::from_parts(0, 4488))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(16))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 2853).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(h.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:100 w:100)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:100)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_batch_mint(n: u32, s: u32, ) -> Weight {
+		Weight::from_parts(2_113_457, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(Weight::from_parts(14_385_012, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_355, 0).saturating_mul(n.saturating_mul(s).into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5608).saturating_mul(n.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:100 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:100 w:200)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:100 w:100)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:100 w:100)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_batch_evolve(n: u32, s: u32, ) -> Weight {
+		Weight::from_parts(3_020_817, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(Weight::from_parts(13_948_233, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_740, 0).saturating_mul(n.saturating_mul(s).into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3061).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_freeze_token() -> Weight {
+		Weight::from_parts(14_863_000, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_freeze_collection() -> Weight {
+		Weight::from_parts(11_942_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn precompile_is_frozen() -> Weight {
+		Weight::from_parts(7_781_000, 0)
+			.saturating_add(Weight::from_parts(0, 3537))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn freeze_token() -> Weight {
+		Weight::from_parts(14_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:0 w:1)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn freeze_collection() -> Weight {
+		Weight::from_parts(11_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_grant_role() -> Weight {
+		Weight::from_parts(14_017_000, 0)
+			.saturating_add(Weight::from_parts(0, 3542))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_revoke_role() -> Weight {
+		Weight::from_parts(14_770_000, 0)
+			.saturating_add(Weight::from_parts(0, 3542))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn precompile_has_role() -> Weight {
+		Weight::from_parts(7_355_000, 0)
+			.saturating_add(Weight::from_parts(0, 3542))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn grant_role() -> Weight {
+		Weight::from_parts(13_190_000, 0)
+			.saturating_add(Weight::from_parts(0, 3542))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(13_965_000, 0)
+			.saturating_add(Weight::from_parts(0, 3542))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_accept_ownership() -> Weight {
+		Weight::from_parts(13_702_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn precompile_cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(12_431_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn precompile_pending_owner() -> Weight {
+		Weight::from_parts(6_893_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(12_866_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(11_892_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn precompile_renounce_ownership() -> Weight {
+		Weight::from_parts(12_104_000, 0)
+			.saturating_add(Weight::from_parts(0, 3581))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::PendingOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::PendingOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn renounce_ownership() -> Weight {
+		Weight::from_parts(11_436_000, 0)
+			.saturating_add(Weight::from_parts(0, 3581))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_metadata() -> Weight {
+		Weight::from_parts(20_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 3907))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn precompile_set_collection_metadata() -> Weight {
+		Weight::from_parts(12_388_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn precompile_collection_metadata() -> Weight {
+		Weight::from_parts(8_356_000, 0)
+			.saturating_add(Weight::from_parts(0, 4166))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	fn set_collection_metadata() -> Weight {
+		Weight::from_parts(11_812_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn precompile_set_default_royalty() -> Weight {
+		Weight::from_parts(12_170_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn precompile_set_token_royalty() -> Weight {
+		Weight::from_parts(14_163_000, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:1 w:0)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn precompile_royalty_info() -> Weight {
+		Weight::from_parts(9_488_000, 0)
+			.saturating_add(Weight::from_parts(0, 3559))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DefaultRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::DefaultRoyalty` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(11_598_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenRoyalty` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenRoyalty` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn set_token_royalty() -> Weight {
+		Weight::from_parts(13_517_000, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn precompile_token_uri_version() -> Weight {
+		Weight::from_parts(9_577_000, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	fn precompile_token_uri_at() -> Weight {
+		Weight::from_parts(10_125_000, 0)
+			.saturating_add(Weight::from_parts(0, 4071))
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_mint_with_signature(_s: u32, ) -> Weight {
+		Weight::from_parts(71_734_112, 0)
+			.saturating_add(Weight::from_parts(0, 6598))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:0)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn precompile_is_mint_nonce_used() -> Weight {
+		Weight::from_parts(7_219_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn precompile_invalidate_mint_nonce() -> Weight {
+		Weight::from_parts(12_473_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::UsedNonces` (r:1 w:1)
+	/// Proof: `LaosEvolution::UsedNonces` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn invalidate_mint_nonce() -> Weight {
+		Weight::from_parts(11_916_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::DropRoot` (r:1 w:0)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ClaimedDropLeaf` (r:1 w:1)
+	/// Proof: `LaosEvolution::ClaimedDropLeaf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_claim_from_drop(p: u32, _s: u32, ) -> Weight {
+		Weight::from_parts(26_102_645, 0)
+			.saturating_add(Weight::from_parts(0, 6598))
+			.saturating_add(Weight::from_parts(1_847_312, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn precompile_set_drop_root() -> Weight {
+		Weight::from_parts(11_808_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::DropRoot` (r:1 w:0)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn precompile_drop_root() -> Weight {
+		Weight::from_parts(6_890_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::DropRoot` (r:0 w:1)
+	/// Proof: `LaosEvolution::DropRoot` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_drop_root() -> Weight {
+		Weight::from_parts(11_244_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_collection_with_max_supply() -> Weight {
+		Weight::from_parts(14_021_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::Suicided` (r:1 w:0)
+	/// Proof: `EVM::Suicided` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_max_supply() -> Weight {
+		Weight::from_parts(33_287_000, 0)
+			.saturating_add(Weight::from_parts(0, 3840))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn precompile_total_supply() -> Weight {
+		Weight::from_parts(6_584_000, 0)
+			.saturating_add(Weight::from_parts(0, 3497))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn precompile_max_supply() -> Weight {
+		Weight::from_parts(6_547_000, 0)
+			.saturating_add(Weight::from_parts(0, 3497))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::TokenByIndex` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn precompile_token_by_index() -> Weight {
+		Weight::from_parts(7_219_000, 0)
+			.saturating_add(Weight::from_parts(0, 3545))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:0 w:1)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn set_base_uri(s: u32, ) -> Weight {
+		Weight::from_parts(12_174_380, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(Weight::from_parts(1_112, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::BaseURI` (r:0 w:1)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_set_base_uri(s: u32, ) -> Weight {
+		Weight::from_parts(16_463_912, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(Weight::from_parts(2_306, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::BaseURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::BaseURI` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	fn precompile_base_uri() -> Weight {
+		Weight::from_parts(7_311_000, 0)
+			.saturating_add(Weight::from_parts(0, 4003))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn mint_next(s: u32, ) -> Weight {
+		Weight::from_parts(15_555_454, 0)
+			.saturating_add(Weight::from_parts(0, 6598))
+			.saturating_add(Weight::from_parts(2_061, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::NextSlot` (r:1 w:1)
+	/// Proof: `LaosEvolution::NextSlot` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:1 w:0)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenByIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenByIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenIndex` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenIndex` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:0 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MintedCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::MintedCount` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_mint_next(s: u32, ) -> Weight {
+		Weight::from_parts(16_087_596, 0)
+			.saturating_add(Weight::from_parts(0, 6598))
+			.saturating_add(Weight::from_parts(2_061, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:2 w:2)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn schedule_evolution(s: u32, ) -> Weight {
+		Weight::from_parts(18_937_261, 0)
+			.saturating_add(Weight::from_parts(0, 5478))
+			.saturating_add(Weight::from_parts(1_163, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_evolution() -> Weight {
+		Weight::from_parts(15_108_000, 0)
+			.saturating_add(Weight::from_parts(0, 5478))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:50 w:50)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:50 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:50 w:100)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:50 w:50)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:50 w:50)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:50 w:50)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn apply_scheduled_evolutions(n: u32, ) -> Weight {
+		Weight::from_parts(4_012_530, 0)
+			.saturating_add(Weight::from_parts(0, 5478))
+			.saturating_add(Weight::from_parts(19_873_512, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3081).saturating_mul(n.into()))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:0)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	fn precompile_scheduled_evolution() -> Weight {
+		Weight::from_parts(10_344_000, 0)
+			.saturating_add(Weight::from_parts(0, 4055))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:2 w:2)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_schedule_evolution(s: u32, ) -> Weight {
+		Weight::from_parts(19_652_884, 0)
+			.saturating_add(Weight::from_parts(0, 5478))
+			.saturating_add(Weight::from_parts(1_163, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolution` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolution` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionAgenda` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionAgenda` (`max_values`: None, `max_size`: Some(2013), added: 4488, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::ScheduledEvolutionDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::ScheduledEvolutionDeposit` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn precompile_cancel_scheduled_evolution() -> Weight {
+		Weight::from_parts(15_823_623, 0)
+			.saturating_add(Weight::from_parts(0, 5478))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 256]`.
+	fn set_attribute(s: u32, ) -> Weight {
+		Weight::from_parts(28_950_116, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(Weight::from_parts(1_021, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn remove_attribute() -> Weight {
+		Weight::from_parts(26_913_000, 0)
+			.saturating_add(Weight::from_parts(0, 3843))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 256]`.
+	fn precompile_set_attribute(s: u32, ) -> Weight {
+		Weight::from_parts(31_204_739, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(Weight::from_parts(1_748, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::AttributeCount` (r:1 w:1)
+	/// Proof: `LaosEvolution::AttributeCount` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn precompile_remove_attribute() -> Weight {
+		Weight::from_parts(28_617_000, 0)
+			.saturating_add(Weight::from_parts(0, 3843))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `LaosEvolution::TokenAttribute` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenAttribute` (`max_values`: None, `max_size`: Some(378), added: 2853, mode: `MaxEncodedLen`)
+	fn precompile_get_attribute() -> Weight {
+		Weight::from_parts(9_188_000, 0)
+			.saturating_add(Weight::from_parts(0, 3843))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_evolution_consent_required() -> Weight {
+		Weight::from_parts(11_427_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn approve_evolution() -> Weight {
+		Weight::from_parts(12_398_000, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_set_evolution_consent_required() -> Weight {
+		Weight::from_parts(12_187_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_evolution_consent_required() -> Weight {
+		Weight::from_parts(6_957_000, 0)
+			.saturating_add(Weight::from_parts(0, 3489))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:0)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:0 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn precompile_approve_evolution() -> Weight {
+		Weight::from_parts(13_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionConsentRequired` (r:1 w:0)
+	/// Proof: `LaosEvolution::EvolutionConsentRequired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIVersion` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURIVersion` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURI` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenURI` (`max_values`: None, `max_size`: Some(586), added: 3061, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::EvolutionApproval` (r:1 w:1)
+	/// Proof: `LaosEvolution::EvolutionApproval` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenCollection` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenCollection` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::FrozenToken` (r:1 w:0)
+	/// Proof: `LaosEvolution::FrozenToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionRole` (r:1 w:0)
+	/// Proof: `LaosEvolution::CollectionRole` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenURIHistory` (r:1 w:2)
+	/// Proof: `LaosEvolution::TokenURIHistory` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionDepositOf` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionDepositOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::TokenDeposit` (r:1 w:1)
+	/// Proof: `LaosEvolution::TokenDeposit` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn precompile_evolve_with_signature(s: u32, ) -> Weight {
+		Weight::from_parts(70_215_389, 0)
+			.saturating_add(Weight::from_parts(0, 4051))
+			.saturating_add(Weight::from_parts(803, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_collection_with_metadata_and_max_supply() -> Weight {
+		Weight::from_parts(15_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `LaosEvolution::CollectionCounter` (r:1 w:1)
+	/// Proof: `LaosEvolution::CollectionCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:1 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LaosEvolution::CollectionOwner` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionOwner` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CollectionMetadataOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(701), added: 3176, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::MaxSupply` (r:0 w:1)
+	/// Proof: `LaosEvolution::MaxSupply` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `LaosEvolution::CreationDepositOf` (r:0 w:1)
+	/// Proof: `LaosEvolution::CreationDepositOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn precompile_create_collection_with_metadata_and_max_supply() -> Weight {
+		Weight::from_parts(22_131_000, 0)
+			.saturating_add(Weight::from_parts(0, 3907))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
 }